@@ -0,0 +1,101 @@
+//! Domain-separated keccak256 for audit commitments.
+//!
+//! Every hash the program commits to is `keccak(tag ++ data...)`, where the tag
+//! names its purpose, so a digest made for one purpose can never be replayed as
+//! another. Clients reproduce a hash by prefixing the same tag bytes.
+
+use anchor_lang::prelude::Pubkey;
+use solana_keccak_hasher as keccak;
+
+/// Chant account snapshot (export_root seed)
+pub const CHANT_TAG: &[u8] = b"UC:CHANT";
+/// Idea text / idea account leaf
+pub const IDEA_TAG: &[u8] = b"UC:IDEA";
+/// Cell account leaf
+pub const CELL_TAG: &[u8] = b"UC:CELL";
+/// Vote account leaf
+pub const VOTE_TAG: &[u8] = b"UC:VOTE";
+/// Tier result leaf
+pub const TIER_TAG: &[u8] = b"UC:TIER";
+/// Champion leaf
+pub const CHAMPION_TAG: &[u8] = b"UC:CHAMPION";
+/// Secret-ballot voter commitment: keccak(tag ++ cell ++ voter_id ++ salt)
+pub const VOTER_TAG: &[u8] = b"UC:VOTER";
+/// Generic audited account leaf (export_root)
+pub const LEAF_TAG: &[u8] = b"UC:LEAF";
+/// Interior node / running-root step: combines two digests
+pub const NODE_TAG: &[u8] = b"UC:NODE";
+
+/// keccak(tag ++ parts[0] ++ parts[1] ++ ...)
+pub fn tagged_hash(tag: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut input: Vec<&[u8]> = Vec::with_capacity(parts.len() + 1);
+    input.push(tag);
+    input.extend_from_slice(parts);
+    keccak::hashv(&input).to_bytes()
+}
+
+/// Combine two digests: keccak(NODE_TAG ++ left ++ right).
+pub fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    tagged_hash(NODE_TAG, &[left, right])
+}
+
+/// Secret-ballot commitment: keccak(VOTER_TAG ++ cell ++ voter_id ++ salt).
+/// The cell binds the commitment, so the same voter and salt don't link cells.
+pub fn voter_commitment(cell: &Pubkey, voter_id: &str, salt: &[u8; 32]) -> [u8; 32] {
+    tagged_hash(VOTER_TAG, &[cell.as_ref(), voter_id.as_bytes(), salt])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(digest: [u8; 32]) -> String {
+        digest.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    // Known vectors, computed off-chain as keccak256(tag ++ data). A client
+    // that reproduces these agrees with the program byte for byte.
+    #[test]
+    fn tagged_hash_vectors() {
+        let cases: [(&[u8], &str); 9] = [
+            (CHANT_TAG, "91b0c9f91202a2e3b4e1728923a22cc30da168b172db9cca3f8228647e05adc4"),
+            (IDEA_TAG, "1a43559ba015d48b24703206a40bc365a36cc630659db9e277f81caed5a8a7d0"),
+            (CELL_TAG, "ffa543e0b76c19767f3b8422f3acc27d472451073229f9222323b02835167395"),
+            (VOTE_TAG, "31ff54a032b132348cc65b580f4f4bb603cb79290346feaac9cf15529a3448f5"),
+            (TIER_TAG, "6440de7ed877e37314f346f9cdb3d50f2ec883a22b5a8d6df96d3173b9f46946"),
+            (CHAMPION_TAG, "fb170eb0c67a82f58f9af744e33acb99ec7a78cb4ee75f2a9cec8ab4da09ea76"),
+            (VOTER_TAG, "12fbdecc46af386acba779362924718c025f12bd0e21926335441750d74d7903"),
+            (LEAF_TAG, "3c628d3323d02e4c1fe37e97cb997943f0be4715f47384a37c4f39d660c518de"),
+            (NODE_TAG, "03dd1a530d6cd90585bd821d6f7438975a83dd52fe18f71bd0dcc633637d7e17"),
+        ];
+        for (tag, expected) in cases {
+            assert_eq!(hex(tagged_hash(tag, &[b"unionchant"])), expected);
+        }
+    }
+
+    #[test]
+    fn tagged_hash_is_plain_concatenation() {
+        assert_eq!(
+            tagged_hash(IDEA_TAG, &[b"union", b"chant"]),
+            tagged_hash(IDEA_TAG, &[b"unionchant"])
+        );
+    }
+
+    #[test]
+    fn node_hash_vector() {
+        assert_eq!(
+            hex(node_hash(&[1; 32], &[2; 32])),
+            "8571c2df069b30ea0707e8eee433bf0ef5e7915eaa1b0c98522715777ad78637"
+        );
+        assert_ne!(node_hash(&[1; 32], &[2; 32]), node_hash(&[2; 32], &[1; 32]));
+    }
+
+    #[test]
+    fn voter_commitment_vector() {
+        let cell = Pubkey::new_from_array([7; 32]);
+        assert_eq!(
+            hex(voter_commitment(&cell, "voter-1", &[9; 32])),
+            "cfaa8d4444f14a4594c828432e212b9e1ab72c20f34c52c843c74d4eecbb366f"
+        );
+    }
+}
@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
 
+pub mod hash;
+pub mod pda;
+
+use pda::create_pda;
+
 declare_id!("CyjjTdnnVKgqKjnjRnz9g8wgc1LBWs2d1QEjqzbCCJUh");
 
 /// Maximum lengths for variable-size fields
@@ -7,9 +12,26 @@ const MAX_CHANT_ID: usize = 32;
 const MAX_QUESTION: usize = 500;
 const MAX_IDEA_TEXT: usize = 1000;
 const MAX_AUTHOR_ID: usize = 32;
-const MAX_IDEAS_PER_CELL: usize = 10;
+const MAX_IDEAS_PER_CELL: usize = MAX_CELL_SIZE as usize;
 const MAX_ALLOCATIONS: usize = 10;
 const MAX_ADVANCING: usize = 200;
+const MAX_LIVE_RANGES: usize = 200;
+/// XP entries carried in a TierCompleted event (highest first)
+const MAX_EVENT_XP: usize = 32;
+
+/// Cell size bounds: pairs up to 12-member panels
+const MIN_CELL_SIZE: u8 = 2;
+const MAX_CELL_SIZE: u8 = 12;
+
+/// Tiers with a per-tier cell count on the chant. Even pairwise cells over
+/// u16::MAX ideas are down to one well before this.
+const MAX_TIERS: usize = 32;
+
+/// Most ranked places a Podium can hold
+const MAX_PODIUM_SIZE: usize = 10;
+
+/// Points each voter allocates when a chant doesn't set its own budget
+const DEFAULT_VOTE_BUDGET: u16 = 10;
 
 #[program]
 pub mod chant_audit {
@@ -25,10 +47,11 @@ pub mod chant_audit {
         question: String,
         cell_size: u8,
         continuous_flow: bool,
+        config: ChantConfig,
     ) -> Result<()> {
         require!(chant_id.len() <= MAX_CHANT_ID, AuditError::StringTooLong);
         require!(question.len() <= MAX_QUESTION, AuditError::StringTooLong);
-        require!(cell_size >= 3 && cell_size <= 7, AuditError::InvalidCellSize);
+        Chant::check_cell_size(cell_size)?;
 
         let chant = &mut ctx.accounts.chant;
         chant.authority = ctx.accounts.authority.key();
@@ -40,6 +63,25 @@ pub mod chant_audit {
         chant.current_tier = 0;
         chant.idea_count = 0;
         chant.cell_count = 0;
+        chant.submission_deadline = config.submission_deadline;
+        chant.paused = false;
+        chant.vote_budget = if config.vote_budget == 0 {
+            DEFAULT_VOTE_BUDGET
+        } else {
+            config.vote_budget
+        };
+        chant.max_ideas_per_author = config.max_ideas_per_author;
+        chant.max_ideas = config.max_ideas;
+        chant.transcript_hash = [0u8; 32];
+        chant.live_idea_count = 0;
+        chant.disqualified_indices = Vec::new();
+        chant.open_tier = 0;
+        chant.tier_in_progress = false;
+        chant.tier_last_batch = [0; MAX_TIERS];
+        chant.tier_closed = [false; MAX_TIERS];
+        chant.secret_ballot = config.secret_ballot;
+        chant.tier_cell_counts = [0; MAX_TIERS];
+        chant.tier_started_at = [0; MAX_TIERS];
         chant.created_at = Clock::get()?.unix_timestamp;
         chant.bump = ctx.bumps.chant;
 
@@ -70,25 +112,123 @@ pub mod chant_audit {
             ctx.accounts.authority.key() == chant.authority,
             AuditError::Unauthorized
         );
-        require!(idea_index == chant.idea_count, AuditError::IndexMismatch);
+        require!(!chant.paused, AuditError::ChantPaused);
+        // Stale index (e.g. two submitters raced): fail cleanly so the client can retry
+        if idea_index != chant.idea_count {
+            msg!("Expected idea_index {} (got {})", chant.idea_count, idea_index);
+            return err!(AuditError::IndexMismatch);
+        }
+
+        // Chant-wide cap (0 = unlimited); disqualified ideas still count
+        require!(
+            chant.max_ideas == 0 || chant.idea_count < chant.max_ideas,
+            AuditError::MaxIdeasReached
+        );
+        let idea_count = chant.idea_count.checked_add(1).ok_or(AuditError::MathOverflow)?;
+        let live_idea_count =
+            chant.live_idea_count.checked_add(1).ok_or(AuditError::MathOverflow)?;
 
-        let idea = &mut ctx.accounts.idea;
-        idea.chant = chant.key();
-        idea.index = idea_index;
-        idea.text = text;
-        idea.author_id = author_id;
-        idea.status = IdeaStatus::Submitted as u8;
-        idea.tier = 0;
-        idea.total_xp = 0;
-        idea.created_at = Clock::get()?.unix_timestamp;
-        idea.bump = ctx.bumps.idea;
-
-        chant.idea_count = chant.idea_count.checked_add(1).unwrap();
+        // Submission window (0 = no deadline)
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            chant.submission_deadline == 0 || now <= chant.submission_deadline,
+            AuditError::SubmissionClosed
+        );
+
+        // Optional co-signing author: proves authorship beyond the author_id string
+        let author_pubkey = ctx
+            .accounts
+            .author
+            .as_ref()
+            .map_or(Pubkey::default(), |author| author.key());
+
+        // Per-author cap (0 = unlimited)
+        let stats = &mut ctx.accounts.author_stats;
+        require!(
+            chant.max_ideas_per_author == 0 || stats.idea_count < chant.max_ideas_per_author,
+            AuditError::AuthorIdeaLimitReached
+        );
+        if stats.idea_count == 0 {
+            stats.chant = chant.key();
+            stats.author_id = author_id.clone();
+            stats.bump = ctx.bumps.author_stats;
+        }
+        stats.idea_count = stats.idea_count.checked_add(1).ok_or(AuditError::MathOverflow)?;
+
+        // Created here rather than via `init` so the index check above runs first
+        let space = Idea::space(&text, &author_id);
+        let chant_key = chant.key();
+        let index_bytes = idea_index.to_le_bytes();
+        create_pda(
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.idea.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &crate::ID,
+            space,
+            &[b"idea", chant_key.as_ref(), &index_bytes, &[ctx.bumps.idea]],
+        )?;
+
+        let idea = Idea {
+            chant: chant_key,
+            index: idea_index,
+            text,
+            author_id,
+            status: IdeaStatus::Submitted as u8,
+            tier: 0,
+            total_xp: 0,
+            created_at: now,
+            author_pubkey,
+            last_xp_tier: None,
+            cell_appearances: 0,
+            bump: ctx.bumps.idea,
+        };
+        idea.try_serialize(&mut &mut ctx.accounts.idea.try_borrow_mut_data()?[..])?;
+
+        chant.idea_count = idea_count;
+        chant.live_idea_count = live_idea_count;
 
         emit!(IdeaRecorded {
             chant: chant.key(),
             idea_index,
             author_id: idea.author_id.clone(),
+            author_pubkey,
+        });
+
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Disqualify an idea — it stays on-chain but is no longer live
+    // ═══════════════════════════════════════════════════
+
+    pub fn disqualify_idea(ctx: Context<DisqualifyIdea>, idea_index: u16) -> Result<()> {
+        let chant = &mut ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+
+        let idea = &mut ctx.accounts.idea;
+        require!(
+            idea.status != IdeaStatus::Disqualified as u8,
+            AuditError::AlreadyDisqualified
+        );
+        idea.status = IdeaStatus::Disqualified as u8;
+
+        // Kept sorted so live ranges can be walked in one pass
+        let pos = chant
+            .disqualified_indices
+            .binary_search(&idea_index)
+            .unwrap_err();
+        chant.disqualified_indices.insert(pos, idea_index);
+        chant.live_idea_count =
+            chant.live_idea_count.checked_sub(1).ok_or(AuditError::MathOverflow)?;
+
+        emit!(IdeaDisqualified {
+            chant: chant.key(),
+            idea_index,
+            live_idea_count: chant.live_idea_count,
         });
 
         Ok(())
@@ -98,12 +238,15 @@ pub mod chant_audit {
     // Record a cell creation
     // ═══════════════════════════════════════════════════
 
-    pub fn record_cell(
-        ctx: Context<RecordCell>,
+    /// Each member's Idea account is passed via `remaining_accounts`, in
+    /// `idea_indices` order, and has its cell_appearances bumped.
+    pub fn record_cell<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecordCell<'info>>,
         cell_index: u16,
         tier: u8,
         batch: u8,
         idea_indices: Vec<u16>,
+        voting_deadline: i64,
     ) -> Result<()> {
         require!(
             idea_indices.len() <= MAX_IDEAS_PER_CELL,
@@ -115,8 +258,23 @@ pub mod chant_audit {
             ctx.accounts.authority.key() == chant.authority,
             AuditError::Unauthorized
         );
+        require!(!chant.paused, AuditError::ChantPaused);
+        // Cell indices stay strictly sequential in both modes
         require!(cell_index == chant.cell_count, AuditError::IndexMismatch);
 
+        chant.check_cell_ideas(&idea_indices)?;
+        require!(
+            ctx.remaining_accounts.len() == idea_indices.len(),
+            AuditError::IndexMismatch
+        );
+        for (info, idx) in ctx.remaining_accounts.iter().zip(idea_indices.iter()) {
+            let mut idea = Account::<Idea>::try_from(info)?;
+            idea.join_cell(chant.key(), *idx)?;
+            idea.exit(&crate::ID)?;
+        }
+
+        chant.open_cell(tier, batch)?;
+
         let cell = &mut ctx.accounts.cell;
         cell.chant = chant.key();
         cell.index = cell_index;
@@ -125,16 +283,21 @@ pub mod chant_audit {
         cell.status = CellStatus::Voting as u8;
         cell.idea_indices = idea_indices;
         cell.voter_count = 0;
+        cell.vote_count = 0;
+        cell.total_points = 0;
+        cell.voting_deadline = voting_deadline;
         cell.created_at = Clock::get()?.unix_timestamp;
         cell.bump = ctx.bumps.cell;
 
-        chant.cell_count = chant.cell_count.checked_add(1).unwrap();
+        chant.count_cell(tier, cell.created_at)?;
 
         emit!(CellRecorded {
             chant: chant.key(),
             cell_index,
             tier,
             batch,
+            idea_indices: cell.idea_indices.clone(),
+            voting_deadline,
         });
 
         Ok(())
@@ -160,21 +323,27 @@ pub mod chant_audit {
             ctx.accounts.authority.key() == chant.authority,
             AuditError::Unauthorized
         );
+        require!(!chant.paused, AuditError::ChantPaused);
+        require!(!chant.secret_ballot, AuditError::BallotModeMismatch);
 
-        // Validate total points = 10
-        let total: u16 = allocations.iter().map(|a| a.points as u16).sum();
-        require!(total == 10, AuditError::InvalidPointTotal);
+        let total = chant.check_vote_budget(&allocations)?;
 
         let cell = &mut ctx.accounts.cell;
-        let vote = &mut ctx.accounts.vote;
+        cell.require_accepting_votes(Clock::get()?.unix_timestamp)?;
+        cell.add_points(chant, total)?;
 
+        let vote = &mut ctx.accounts.vote;
         vote.cell = cell.key();
         vote.voter_id = voter_id;
         vote.allocations = allocations;
         vote.voted_at = Clock::get()?.unix_timestamp;
+        vote.version = 0;
+        vote.superseded = false;
+        vote.voter_commitment = [0u8; 32];
         vote.bump = ctx.bumps.vote;
 
-        cell.voter_count = cell.voter_count.checked_add(1).unwrap();
+        cell.voter_count = cell.voter_count.checked_add(1).ok_or(AuditError::MathOverflow)?;
+        cell.vote_count = cell.vote_count.checked_add(1).ok_or(AuditError::MathOverflow)?;
 
         emit!(VoteRecorded {
             chant: chant.key(),
@@ -185,12 +354,156 @@ pub mod chant_audit {
         Ok(())
     }
 
+    /// Secret-ballot variant of `record_vote`: the vote PDA is seeded by
+    /// `voter_commitment` (see `hash::voter_commitment`) and voter_id stays empty
+    /// until `reveal_voter`. A reused commitment fails at the PDA.
+    pub fn record_committed_vote(
+        ctx: Context<RecordCommittedVote>,
+        voter_commitment: [u8; 32],
+        allocations: Vec<Allocation>,
+    ) -> Result<()> {
+        require!(
+            allocations.len() <= MAX_ALLOCATIONS,
+            AuditError::TooManyItems
+        );
+        require!(voter_commitment != [0u8; 32], AuditError::InvalidCommitment);
+
+        let chant = &ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+        require!(chant.secret_ballot, AuditError::BallotModeMismatch);
+
+        let total = chant.check_vote_budget(&allocations)?;
+
+        let cell = &mut ctx.accounts.cell;
+        cell.require_accepting_votes(Clock::get()?.unix_timestamp)?;
+        cell.add_points(chant, total)?;
+
+        let vote = &mut ctx.accounts.vote;
+        vote.cell = cell.key();
+        vote.voter_id = String::new();
+        vote.allocations = allocations;
+        vote.voted_at = Clock::get()?.unix_timestamp;
+        vote.version = 0;
+        vote.superseded = false;
+        vote.voter_commitment = voter_commitment;
+        vote.bump = ctx.bumps.vote;
+
+        cell.voter_count = cell.voter_count.checked_add(1).ok_or(AuditError::MathOverflow)?;
+        cell.vote_count = cell.vote_count.checked_add(1).ok_or(AuditError::MathOverflow)?;
+
+        emit!(CommittedVoteRecorded {
+            chant: chant.key(),
+            cell: cell.key(),
+            voter_commitment,
+        });
+
+        Ok(())
+    }
+
+    /// Open a committed vote: checks `hash::voter_commitment(cell, voter_id, salt)`
+    /// against the stored commitment and writes voter_id (realloc).
+    pub fn reveal_voter(
+        ctx: Context<RevealVoter>,
+        voter_id: String,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        require!(voter_id.len() <= MAX_AUTHOR_ID, AuditError::StringTooLong);
+
+        let chant = &ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+
+        let vote = &mut ctx.accounts.vote;
+        require!(vote.voter_commitment != [0u8; 32], AuditError::InvalidCommitment);
+        require!(vote.voter_id.is_empty(), AuditError::VoterAlreadyRevealed);
+        require!(
+            hash::voter_commitment(&vote.cell, &voter_id, &salt) == vote.voter_commitment,
+            AuditError::InvalidCommitment
+        );
+
+        vote.voter_id = voter_id;
+
+        emit!(VoterRevealed {
+            chant: chant.key(),
+            cell: vote.cell,
+            voter_commitment: vote.voter_commitment,
+            voter_id: vote.voter_id.clone(),
+        });
+
+        Ok(())
+    }
+
+    /// Append-only correction: writes version `prior.version + 1` at
+    /// `[b"vote", cell, voter_id, version]` and marks the prior record superseded.
+    /// Only the latest (non-superseded) version counts toward the cell's tally.
+    pub fn revise_vote(
+        ctx: Context<ReviseVote>,
+        voter_id: String,
+        version: u16,
+        allocations: Vec<Allocation>,
+    ) -> Result<()> {
+        require!(
+            allocations.len() <= MAX_ALLOCATIONS,
+            AuditError::TooManyItems
+        );
+
+        let chant = &ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+        // Revision PDAs are keyed by voter_id, which committed votes don't expose
+        require!(!chant.secret_ballot, AuditError::BallotModeMismatch);
+        let cell = &mut ctx.accounts.cell;
+        cell.require_accepting_votes(Clock::get()?.unix_timestamp)?;
+
+        let prior = &mut ctx.accounts.prior;
+        prior.supersede(version)?;
+
+        chant.check_vote_budget(&allocations)?;
+
+        let vote = &mut ctx.accounts.vote;
+        vote.cell = cell.key();
+        vote.voter_id = voter_id;
+        vote.allocations = allocations;
+        vote.voted_at = Clock::get()?.unix_timestamp;
+        vote.version = version;
+        vote.superseded = false;
+        vote.voter_commitment = [0u8; 32];
+        vote.bump = ctx.bumps.vote;
+
+        // Same voter, same budget — voter_count and total_points are unchanged,
+        // but the new record is one more for the export to cover
+        cell.vote_count = cell.vote_count.checked_add(1).ok_or(AuditError::MathOverflow)?;
+
+        emit!(VoteRevised {
+            chant: chant.key(),
+            cell: vote.cell,
+            voter_id: vote.voter_id.clone(),
+            version,
+            prior: prior.key(),
+        });
+
+        Ok(())
+    }
+
     // ═══════════════════════════════════════════════════
     // Record tier completion results
     // ═══════════════════════════════════════════════════
 
-    pub fn record_tier_result(
-        ctx: Context<RecordTierResult>,
+    /// `remaining_accounts`: every live Cell account of the tier, then one Idea
+    /// account per entry of `advancing_indices`, then one writable Idea account
+    /// per entry of `xp_totals` (each list in its own order).
+    pub fn record_tier_result<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RecordTierResult<'info>>,
         tier: u8,
         advancing_indices: Vec<u16>,
         xp_totals: Vec<XpEntry>,
@@ -199,27 +512,133 @@ pub mod chant_audit {
             advancing_indices.len() <= MAX_ADVANCING,
             AuditError::TooManyItems
         );
+        require!((tier as usize) < MAX_TIERS, AuditError::TierLimitExceeded);
 
         let chant = &mut ctx.accounts.chant;
         require!(
             ctx.accounts.authority.key() == chant.authority,
             AuditError::Unauthorized
         );
+        require!(!chant.paused, AuditError::ChantPaused);
+
+        // Every advancing idea must have sat in a cell of this tier and not be eliminated
+        let remaining = ctx.remaining_accounts;
+        require!(
+            remaining.len() >= advancing_indices.len() + xp_totals.len(),
+            AuditError::InvalidAdvancement
+        );
+        let (remaining, xp_infos) = remaining.split_at(remaining.len() - xp_totals.len());
+        let (cell_infos, idea_infos) = remaining.split_at(remaining.len() - advancing_indices.len());
+        let mut cells = Vec::with_capacity(cell_infos.len());
+        for (i, info) in cell_infos.iter().enumerate() {
+            require!(
+                !cell_infos[..i].iter().any(|c| c.key == info.key),
+                AuditError::InvalidAdvancement
+            );
+            cells.push(Account::<Cell>::try_from(info)?.into_inner());
+        }
+        let ideas = idea_infos
+            .iter()
+            .map(|info| Account::<Idea>::try_from(info).map(Account::into_inner))
+            .collect::<Result<Vec<_>>>()?;
+        chant.check_advancement(chant.key(), tier, &cells, &ideas, &advancing_indices)?;
+
+        // Fold this tier's XP into each idea's running cross-tier total
+        for (info, entry) in xp_infos.iter().zip(xp_totals.iter()) {
+            let mut idea = Account::<Idea>::try_from(info)?;
+            require!(
+                idea.chant == chant.key() && idea.index == entry.idea_index,
+                AuditError::IndexMismatch
+            );
+            idea.apply_tier_xp(tier, entry.total_xp)?;
+            // Written back now so a duplicate entry sees the update
+            idea.exit(&crate::ID)?;
+        }
 
         let result = &mut ctx.accounts.tier_result;
         result.chant = chant.key();
         result.tier = tier;
         result.advancing_indices = advancing_indices;
         result.xp_totals = xp_totals;
+        result.cell_count = chant.tier_cell_counts[tier as usize];
+        result.started_at = chant.tier_started_at[tier as usize];
         result.completed_at = Clock::get()?.unix_timestamp;
         result.bump = ctx.bumps.tier_result;
 
-        chant.current_tier = tier;
+        // A late result for an earlier tier doesn't move the chant back; the
+        // champion is declared against current_tier's result
+        chant.current_tier = chant.current_tier.max(tier);
+        chant.tier_closed[tier as usize] = true;
+        if tier == chant.open_tier {
+            chant.tier_in_progress = false;
+        }
+
+        let top_xp = result.top_xp();
 
         emit!(TierCompleted {
             chant: chant.key(),
             tier,
             advancing_count: result.advancing_indices.len() as u16,
+            xp_count: result.xp_totals.len() as u16,
+            cell_count: result.cell_count,
+            top_xp,
+            started_at: result.started_at,
+            timestamp: result.completed_at,
+        });
+
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Sync idea statuses with a recorded tier result
+    // ═══════════════════════════════════════════════════
+
+    /// Ideas that competed in `tier` are passed via `remaining_accounts`. Those in
+    /// the TierResult's advancing_indices become Advancing and move to tier + 1;
+    /// the rest become Eliminated. Only ideas currently at `tier` qualify, so
+    /// tiers are applied in order; an idea at any other tier, eliminated or
+    /// disqualified fails with IdeaTierMismatch, so no idea is applied twice.
+    pub fn apply_idea_statuses<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ApplyIdeaStatuses<'info>>,
+        tier: u8,
+    ) -> Result<()> {
+        let chant = &ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+
+        let result = &ctx.accounts.tier_result;
+        let mut advancing: u16 = 0;
+        let mut eliminated: u16 = 0;
+        for info in ctx.remaining_accounts.iter() {
+            let mut idea = Account::<Idea>::try_from(info)?;
+            require!(idea.chant == chant.key(), AuditError::IndexMismatch);
+            require!(
+                idea.tier == tier
+                    && idea.status != IdeaStatus::Eliminated as u8
+                    && idea.status != IdeaStatus::Disqualified as u8,
+                AuditError::IdeaTierMismatch
+            );
+            if result.advancing_indices.contains(&idea.index) {
+                idea.status = IdeaStatus::Advancing as u8;
+                idea.tier = tier.saturating_add(1);
+                advancing += 1;
+            } else {
+                idea.status = IdeaStatus::Eliminated as u8;
+                idea.tier = tier;
+                eliminated += 1;
+            }
+            // Written back now so a duplicate account sees the update
+            idea.exit(&crate::ID)?;
+        }
+
+        emit!(IdeaStatusesApplied {
+            chant: chant.key(),
+            tier,
+            advancing,
+            eliminated,
         });
 
         Ok(())
@@ -235,25 +654,77 @@ pub mod chant_audit {
         text_hash: [u8; 32],
         total_tiers: u8,
         total_voters: u16,
+        winner_wallet: Pubkey,
+        vote_root: [u8; 32],
     ) -> Result<()> {
         let chant = &mut ctx.accounts.chant;
         require!(
             ctx.accounts.authority.key() == chant.authority,
             AuditError::Unauthorized
         );
+        require!(!chant.paused, AuditError::ChantPaused);
         require!(
             idea_index < chant.idea_count,
             AuditError::IndexMismatch
         );
+        require!(
+            chant.disqualified_indices.binary_search(&idea_index).is_err(),
+            AuditError::AlreadyDisqualified
+        );
 
-        let champion = &mut ctx.accounts.champion;
-        champion.chant = chant.key();
-        champion.idea_index = idea_index;
-        champion.text_hash = text_hash;
-        champion.total_tiers = total_tiers;
-        champion.total_voters = total_voters;
-        champion.declared_at = Clock::get()?.unix_timestamp;
-        champion.bump = ctx.bumps.champion;
+        // A declared podium's first place is the champion; the two can't disagree
+        let podium_info = ctx.accounts.podium.to_account_info();
+        if !podium_info.data_is_empty() {
+            let podium = Podium::try_deserialize(&mut &podium_info.try_borrow_data()?[..])?;
+            require!(
+                podium.places[0].idea_index == idea_index,
+                AuditError::PodiumChampionMismatch
+            );
+        }
+
+        // Margin of victory, from the final tier's recorded XP: the champion
+        // must be there and must not trail anyone
+        let xp_totals = &ctx.accounts.tier_result.xp_totals;
+        let winning_xp = xp_totals
+            .iter()
+            .find(|e| e.idea_index == idea_index)
+            .map(|e| e.total_xp)
+            .ok_or(AuditError::MarginDataMismatch)?;
+        let runner_up_xp = xp_totals
+            .iter()
+            .filter(|e| e.idea_index != idea_index)
+            .map(|e| e.total_xp)
+            .max()
+            .unwrap_or(0);
+        require!(winning_xp >= runner_up_xp, AuditError::MarginDataMismatch);
+
+        // Terminal: the Champion PDA is created here, exactly once
+        let champion_info = ctx.accounts.champion.to_account_info();
+        require!(champion_info.data_is_empty(), AuditError::ChampionAlreadyDeclared);
+        let chant_key = chant.key();
+        create_pda(
+            &ctx.accounts.authority.to_account_info(),
+            &champion_info,
+            &ctx.accounts.system_program.to_account_info(),
+            &crate::ID,
+            Champion::SPACE,
+            &[b"champion", chant_key.as_ref(), &[ctx.bumps.champion]],
+        )?;
+
+        let champion = Champion {
+            chant: chant_key,
+            idea_index,
+            text_hash,
+            total_tiers,
+            total_voters,
+            winner_wallet,
+            vote_root,
+            declared_at: Clock::get()?.unix_timestamp,
+            winning_xp,
+            runner_up_xp,
+            bump: ctx.bumps.champion,
+        };
+        champion.try_serialize(&mut &mut champion_info.try_borrow_mut_data()?[..])?;
 
         chant.phase = Phase::Completed as u8;
 
@@ -262,6 +733,82 @@ pub mod chant_audit {
             idea_index,
             total_tiers,
             total_voters,
+            winner_wallet,
+            winning_xp,
+            runner_up_xp,
+            timestamp: champion.declared_at,
+        });
+
+        Ok(())
+    }
+
+    /// Ranked generalization of `declare_champion` (which stays the N = 1 case):
+    /// records up to MAX_PODIUM_SIZE places. Ranks must be exactly 1..=N and each
+    /// idea live and distinct, with rank 1 matching the Champion if one is
+    /// declared. Terminal, like the champion.
+    pub fn declare_podium(ctx: Context<DeclarePodium>, places: Vec<PodiumPlace>) -> Result<()> {
+        let chant = &mut ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+        require!(
+            (1..=MAX_PODIUM_SIZE).contains(&places.len()),
+            AuditError::InvalidPodiumSize
+        );
+
+        let mut places = places;
+        places.sort_by_key(|p| p.rank);
+        for (i, place) in places.iter().enumerate() {
+            require!(place.rank as usize == i + 1, AuditError::DuplicatePodiumRank);
+            require!(place.idea_index < chant.idea_count, AuditError::IndexMismatch);
+            require!(
+                chant.disqualified_indices.binary_search(&place.idea_index).is_err(),
+                AuditError::AlreadyDisqualified
+            );
+            require!(
+                !places[..i].iter().any(|p| p.idea_index == place.idea_index),
+                AuditError::DuplicatePodiumIdea
+            );
+        }
+
+        // A declared champion is first place; the podium can't contradict it
+        let champion_info = ctx.accounts.champion.to_account_info();
+        if !champion_info.data_is_empty() {
+            let champion = Champion::try_deserialize(&mut &champion_info.try_borrow_data()?[..])?;
+            require!(
+                places[0].idea_index == champion.idea_index,
+                AuditError::PodiumChampionMismatch
+            );
+        }
+
+        let podium_info = ctx.accounts.podium.to_account_info();
+        require!(podium_info.data_is_empty(), AuditError::PodiumAlreadyDeclared);
+        let chant_key = chant.key();
+        create_pda(
+            &ctx.accounts.authority.to_account_info(),
+            &podium_info,
+            &ctx.accounts.system_program.to_account_info(),
+            &crate::ID,
+            Podium::space(places.len()),
+            &[b"podium", chant_key.as_ref(), &[ctx.bumps.podium]],
+        )?;
+
+        let podium = Podium {
+            chant: chant_key,
+            places,
+            declared_at: Clock::get()?.unix_timestamp,
+            bump: ctx.bumps.podium,
+        };
+        podium.try_serialize(&mut &mut podium_info.try_borrow_mut_data()?[..])?;
+
+        chant.phase = Phase::Completed as u8;
+
+        emit!(PodiumDeclared {
+            chant: chant_key,
+            idea_indices: podium.places.iter().map(|p| p.idea_index).collect(),
+            timestamp: podium.declared_at,
         });
 
         Ok(())
@@ -277,7 +824,13 @@ pub mod chant_audit {
             ctx.accounts.authority.key() == chant.authority,
             AuditError::Unauthorized
         );
+        require!(!chant.paused, AuditError::ChantPaused);
         require!(new_phase <= 3, AuditError::InvalidPhase);
+        // A sealed transcript pins the chant as Completed
+        require!(
+            chant.transcript_hash == [0u8; 32],
+            AuditError::TranscriptAlreadySealed
+        );
 
         let old_phase = chant.phase;
         chant.phase = new_phase;
@@ -286,48 +839,653 @@ pub mod chant_audit {
             chant: chant.key(),
             old_phase,
             new_phase,
+            timestamp: Clock::get()?.unix_timestamp,
         });
 
         Ok(())
     }
-}
 
-// ═══════════════════════════════════════════════════════
-// Account contexts
-// ═══════════════════════════════════════════════════════
+    // ═══════════════════════════════════════════════════
+    // Seal the full off-chain transcript — once, after completion
+    // ═══════════════════════════════════════════════════
 
-#[derive(Accounts)]
-#[instruction(chant_id: String, question: String)]
-pub struct InitializeChant<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = Chant::space(&chant_id, &question),
-        seeds = [b"chant", chant_id.as_bytes()],
-        bump,
-    )]
-    pub chant: Account<'info, Chant>,
+    pub fn seal_transcript(ctx: Context<ChantAction>, transcript_hash: [u8; 32]) -> Result<()> {
+        let chant = &mut ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+        require!(
+            chant.phase == Phase::Completed as u8,
+            AuditError::InvalidPhase
+        );
+        require!(
+            chant.transcript_hash == [0u8; 32],
+            AuditError::TranscriptAlreadySealed
+        );
+        require!(transcript_hash != [0u8; 32], AuditError::InvalidTranscriptHash);
 
-    #[account(mut)]
-    pub authority: Signer<'info>,
+        chant.transcript_hash = transcript_hash;
 
-    pub system_program: Program<'info, System>,
-}
+        emit!(TranscriptSealed {
+            chant: chant.key(),
+            transcript_hash,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
 
-#[derive(Accounts)]
-#[instruction(idea_index: u16, text: String, author_id: String)]
-pub struct RecordIdea<'info> {
-    #[account(mut)]
-    pub chant: Account<'info, Chant>,
+        Ok(())
+    }
 
-    #[account(
-        init,
-        payer = authority,
-        space = Idea::space(&text, &author_id),
-        seeds = [b"idea", chant.key().as_ref(), &idea_index.to_le_bytes()],
-        bump,
+    // ═══════════════════════════════════════════════════
+    // Fix the question — only before anyone has responded
+    // ═══════════════════════════════════════════════════
+
+    pub fn update_question(ctx: Context<UpdateQuestion>, new_question: String) -> Result<()> {
+        require!(new_question.len() <= MAX_QUESTION, AuditError::StringTooLong);
+
+        let chant = &mut ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+        require!(
+            chant.idea_count == 0 && chant.phase == Phase::Submission as u8,
+            AuditError::QuestionLockedAfterSubmissions
+        );
+
+        chant.question = new_question;
+
+        emit!(QuestionUpdated {
+            chant: chant.key(),
+            question: chant.question.clone(),
+        });
+
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Emergency pause — blocks all recording until unpaused
+    // ═══════════════════════════════════════════════════
+
+    pub fn pause_chant(ctx: Context<ChantAction>) -> Result<()> {
+        let chant = &mut ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::AlreadyPaused);
+        chant.paused = true;
+
+        emit!(ChantPaused { chant: chant.key() });
+        Ok(())
+    }
+
+    pub fn unpause_chant(ctx: Context<ChantAction>) -> Result<()> {
+        let chant = &mut ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(chant.paused, AuditError::NotPaused);
+        chant.paused = false;
+
+        emit!(ChantUnpaused { chant: chant.key() });
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Mark a cell's voting as finished
+    // ═══════════════════════════════════════════════════
+
+    pub fn complete_cell(ctx: Context<CompleteCell>) -> Result<()> {
+        let chant = &ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+
+        let cell = &mut ctx.accounts.cell;
+        require!(
+            cell.status == CellStatus::Voting as u8,
+            AuditError::CellNotVoting
+        );
+        cell.status = CellStatus::Completed as u8;
+
+        emit!(CellCompleted {
+            chant: chant.key(),
+            cell_index: cell.index,
+            voter_count: cell.voter_count,
+        });
+
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Void an invalid cell (collusion etc.) so it can be re-run
+    // ═══════════════════════════════════════════════════
+
+    /// Marks the cell Voided: it takes no more votes and can't back advancement
+    /// in `record_tier_result`. A replacement cell for the same ideas is then
+    /// recorded with `record_cell` as usual. Only while the tier is still open.
+    pub fn void_cell(ctx: Context<VoidCell>) -> Result<()> {
+        let chant = &mut ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+        require!(
+            ctx.accounts.tier_result.data_is_empty(),
+            AuditError::TierAlreadyRecorded
+        );
+
+        let cell = &mut ctx.accounts.cell;
+        require!(
+            cell.status != CellStatus::Voided as u8,
+            AuditError::CellAlreadyVoided
+        );
+        cell.status = CellStatus::Voided as u8;
+        chant.tier_cell_counts[cell.tier as usize] -= 1;
+
+        emit!(CellVoided {
+            chant: chant.key(),
+            cell_index: cell.index,
+            tier: cell.tier,
+            voter_count: cell.voter_count,
+        });
+
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Close a completed cell (and its votes) to reclaim rent
+    // ═══════════════════════════════════════════════════
+
+    /// Every vote record of the cell is passed via `remaining_accounts`, in
+    /// ascending address order, and closed. The cell account is rewritten in
+    /// place as a CellTombstone whose digest chains the cell and those votes
+    /// the way export_root does, so exports still commit to them; the rent
+    /// above the tombstone's goes to the authority.
+    /// The cell index stays consumed — `record_cell` only accepts `chant.cell_count`.
+    /// Only Completed cells close; a Voided cell stays on-chain as the record of
+    /// why its tier was re-run.
+    pub fn close_cell<'info>(ctx: Context<'_, '_, 'info, 'info, CloseCell<'info>>) -> Result<()> {
+        let chant = &ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+        require!(!chant.paused, AuditError::ChantPaused);
+
+        let cell_info = ctx.accounts.cell.to_account_info();
+        let cell = Cell::try_deserialize(&mut &cell_info.try_borrow_data()?[..])?;
+        require!(cell.chant == chant.key(), AuditError::IndexMismatch);
+        require!(
+            cell.status == CellStatus::Completed as u8,
+            AuditError::CellNotCompleted
+        );
+        // The cell's tier must already have its result recorded
+        let tier_result = &ctx.accounts.tier_result;
+        require!(
+            tier_result.chant == chant.key() && tier_result.tier == cell.tier,
+            AuditError::TierNotRecorded
+        );
+        require!(
+            ctx.remaining_accounts.len() == cell.vote_count as usize,
+            AuditError::InvalidVoteAccount
+        );
+
+        // Votes close into the cell, which pays out everything above the
+        // tombstone's rent at the end
+        let mut digest = hash::tagged_hash(hash::LEAF_TAG, &[&cell_info.try_borrow_data()?]);
+        let mut last_vote = Pubkey::default();
+        for info in ctx.remaining_accounts.iter() {
+            require!(
+                info.is_writable && info.key() > last_vote,
+                AuditError::InvalidVoteAccount
+            );
+            let vote = Account::<VoteRecord>::try_from(info)?;
+            require!(vote.cell == cell_info.key(), AuditError::InvalidVoteAccount);
+            let leaf = hash::tagged_hash(hash::LEAF_TAG, &[&info.try_borrow_data()?]);
+            digest = hash::node_hash(&digest, &leaf);
+            last_vote = info.key();
+            vote.close(cell_info.clone())?;
+        }
+
+        let tombstone = CellTombstone {
+            chant: chant.key(),
+            index: cell.index,
+            tier: cell.tier,
+            vote_count: cell.vote_count,
+            digest,
+            bump: cell.bump,
+        };
+        let rent = Rent::get()?.minimum_balance(CellTombstone::SPACE);
+        let surplus = cell_info
+            .lamports()
+            .checked_sub(rent)
+            .ok_or(ProgramError::AccountNotRentExempt)?;
+        cell_info.resize(CellTombstone::SPACE)?;
+        tombstone.try_serialize(&mut &mut cell_info.try_borrow_mut_data()?[..])?;
+        **cell_info.try_borrow_mut_lamports()? -= surplus;
+        **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += surplus;
+
+        emit!(CellClosed {
+            chant: chant.key(),
+            cell_index: cell.index,
+            votes_closed: cell.vote_count,
+            digest,
+        });
+
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Audit export checkpoint (content-addressed root)
+    // ═══════════════════════════════════════════════════
+
+    /// Folds program-owned accounts (passed via `remaining_accounts`) into the
+    /// checkpoint's running root. May be called repeatedly; `seal` freezes it.
+    ///
+    /// Construction, over raw account data (discriminator included):
+    ///   root_0 = keccak("UC:CHANT" ++ chant)
+    ///   root_n = keccak("UC:NODE" ++ root_{n-1} ++ keccak("UC:LEAF" ++ account_n))
+    /// (see the `hash` module).
+    /// Canonical order, enforced across calls: every idea of this chant by
+    /// index, then every cell by index (each followed by its votes in ascending
+    /// address order; a closed cell is its CellTombstone, with no votes after
+    /// it), then tier results by ascending tier, then the champion.
+    /// Sealing requires all of it: idea_count ideas, cell_count cells, each
+    /// cell's vote_count votes, a result for every closed tier, and the
+    /// champion once one is declared.
+    pub fn export_root(ctx: Context<ExportRoot>, checkpoint_id: u32, seal: bool) -> Result<()> {
+        let chant = &ctx.accounts.chant;
+        require!(
+            ctx.accounts.authority.key() == chant.authority,
+            AuditError::Unauthorized
+        );
+
+        // A paused chant may be mid-correction; don't commit to it
+        require!(!chant.paused, AuditError::ChantPaused);
+
+        let now = Clock::get()?.unix_timestamp;
+        let checkpoint = &mut ctx.accounts.checkpoint;
+        require!(!checkpoint.sealed, AuditError::ExportSealed);
+
+        if checkpoint.chant == Pubkey::default() {
+            let chant_info = chant.to_account_info();
+            checkpoint.chant = chant.key();
+            checkpoint.checkpoint_id = checkpoint_id;
+            checkpoint.root = hash::tagged_hash(hash::CHANT_TAG, &[&chant_info.try_borrow_data()?]);
+            checkpoint.item_count = 0;
+            checkpoint.created_at = now;
+            checkpoint.bump = ctx.bumps.checkpoint;
+        }
+
+        for info in ctx.remaining_accounts.iter() {
+            require!(info.owner == &crate::ID, AuditError::InvalidExportAccount);
+            checkpoint.advance(chant, info.key(), &info.try_borrow_data()?)?;
+            let leaf = hash::tagged_hash(hash::LEAF_TAG, &[&info.try_borrow_data()?]);
+            checkpoint.root = hash::node_hash(&checkpoint.root, &leaf);
+            checkpoint.item_count =
+                checkpoint.item_count.checked_add(1).ok_or(AuditError::MathOverflow)?;
+        }
+
+        if seal {
+            let tiers_closed = chant.tier_closed.iter().filter(|&&closed| closed).count() as u16;
+            let champion_declared = !ctx.accounts.champion.data_is_empty();
+            require!(
+                checkpoint.next_idea == chant.idea_count
+                    && checkpoint.next_cell == chant.cell_count
+                    && checkpoint.votes_left == 0
+                    && checkpoint.tier_results == tiers_closed
+                    && (!champion_declared || checkpoint.stage == ExportStage::Done as u8),
+                AuditError::ExportIncomplete
+            );
+            checkpoint.sealed = true;
+            checkpoint.sealed_at = now;
+
+            emit!(ExportRootSealed {
+                chant: chant.key(),
+                checkpoint_id,
+                root: checkpoint.root,
+                item_count: checkpoint.item_count,
+            });
+        }
+
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Views
+    // ═══════════════════════════════════════════════════
+
+    /// Live (not disqualified) idea indices in [start, start + limit), as
+    /// half-open ranges. Page by calling again from the last `end`.
+    pub fn get_live_idea_ranges(
+        ctx: Context<ViewChant>,
+        start: u16,
+        limit: u16,
+    ) -> Result<Vec<IdeaRange>> {
+        ctx.accounts.chant.live_idea_ranges(start, limit)
+    }
+}
+
+// ═══════════════════════════════════════════════════════
+// Account contexts
+// ═══════════════════════════════════════════════════════
+
+#[derive(Accounts)]
+#[instruction(chant_id: String, question: String)]
+pub struct InitializeChant<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = Chant::space(&chant_id, &question),
+        seeds = [b"chant", chant_id.as_bytes()],
+        bump,
+    )]
+    pub chant: Account<'info, Chant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(idea_index: u16, text: String, author_id: String)]
+pub struct RecordIdea<'info> {
+    #[account(mut)]
+    pub chant: Account<'info, Chant>,
+
+    /// CHECK: Idea PDA, created by record_idea after the index is checked.
+    #[account(
+        mut,
+        seeds = [b"idea", chant.key().as_ref(), &idea_index.to_le_bytes()],
+        bump,
+    )]
+    pub idea: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = AuthorStats::SPACE,
+        seeds = [b"author", chant.key().as_ref(), author_id.as_bytes()],
+        bump,
+    )]
+    pub author_stats: Account<'info, AuthorStats>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Optional author wallet; must co-sign to prove authorship.
+    #[account(signer @ AuditError::AuthorNotSigner)]
+    pub author: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(idea_index: u16)]
+pub struct DisqualifyIdea<'info> {
+    #[account(
+        mut,
+        realloc = Chant::space(&chant.chant_id, &chant.question)
+            + 2 * (chant.disqualified_indices.len() + 1),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub chant: Account<'info, Chant>,
+
+    #[account(
+        mut,
+        has_one = chant,
+        seeds = [b"idea", chant.key().as_ref(), &idea_index.to_le_bytes()],
+        bump = idea.bump,
+    )]
+    pub idea: Account<'info, Idea>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(cell_index: u16, tier: u8, batch: u8, idea_indices: Vec<u16>)]
+pub struct RecordCell<'info> {
+    #[account(mut)]
+    pub chant: Account<'info, Chant>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Cell::space(&idea_indices),
+        seeds = [b"cell", chant.key().as_ref(), &cell_index.to_le_bytes()],
+        bump,
+    )]
+    pub cell: Account<'info, Cell>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(voter_id: String, allocations: Vec<Allocation>)]
+pub struct RecordVote<'info> {
+    pub chant: Account<'info, Chant>,
+
+    #[account(mut, has_one = chant)]
+    pub cell: Account<'info, Cell>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = VoteRecord::space(&voter_id, &allocations),
+        seeds = [b"vote", cell.key().as_ref(), voter_id.as_bytes()],
+        bump,
+    )]
+    pub vote: Account<'info, VoteRecord>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(voter_commitment: [u8; 32], allocations: Vec<Allocation>)]
+pub struct RecordCommittedVote<'info> {
+    pub chant: Account<'info, Chant>,
+
+    #[account(mut, has_one = chant)]
+    pub cell: Account<'info, Cell>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = VoteRecord::space("", &allocations),
+        seeds = [b"vote", cell.key().as_ref(), voter_commitment.as_ref()],
+        bump,
+    )]
+    pub vote: Account<'info, VoteRecord>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Realloc to make room for the revealed voter_id.
+#[derive(Accounts)]
+#[instruction(voter_id: String)]
+pub struct RevealVoter<'info> {
+    pub chant: Account<'info, Chant>,
+
+    #[account(
+        mut,
+        realloc = VoteRecord::space(&voter_id, &vote.allocations),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub vote: Account<'info, VoteRecord>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(voter_id: String, version: u16, allocations: Vec<Allocation>)]
+pub struct ReviseVote<'info> {
+    pub chant: Account<'info, Chant>,
+
+    #[account(mut, has_one = chant)]
+    pub cell: Account<'info, Cell>,
+
+    /// Current latest version for this voter; marked superseded.
+    #[account(
+        mut,
+        has_one = cell @ AuditError::InvalidVoteAccount,
+        constraint = prior.voter_id == voter_id @ AuditError::InvalidVoteAccount,
+    )]
+    pub prior: Account<'info, VoteRecord>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = VoteRecord::space(&voter_id, &allocations),
+        seeds = [b"vote", cell.key().as_ref(), voter_id.as_bytes(), &version.to_le_bytes()],
+        bump,
+    )]
+    pub vote: Account<'info, VoteRecord>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: u8, advancing_indices: Vec<u16>, xp_totals: Vec<XpEntry>)]
+pub struct RecordTierResult<'info> {
+    #[account(mut)]
+    pub chant: Account<'info, Chant>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = TierResult::space(&advancing_indices, &xp_totals),
+        seeds = [b"tier", chant.key().as_ref(), &[tier]],
+        bump,
+    )]
+    pub tier_result: Account<'info, TierResult>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct ApplyIdeaStatuses<'info> {
+    pub chant: Account<'info, Chant>,
+
+    #[account(
+        seeds = [b"tier", chant.key().as_ref(), &[tier]],
+        bump = tier_result.bump,
+    )]
+    pub tier_result: Account<'info, TierResult>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DeclareChampion<'info> {
+    #[account(mut)]
+    pub chant: Account<'info, Chant>,
+
+    /// CHECK: Champion PDA, created by declare_champion if not already declared.
+    #[account(
+        mut,
+        seeds = [b"champion", chant.key().as_ref()],
+        bump,
+    )]
+    pub champion: UncheckedAccount<'info>,
+
+    /// CHECK: Podium PDA; when declared, the champion must be its first place.
+    #[account(
+        seeds = [b"podium", chant.key().as_ref()],
+        bump,
+    )]
+    pub podium: UncheckedAccount<'info>,
+
+    /// Final tier's result — source of the recorded margin.
+    #[account(
+        seeds = [b"tier", chant.key().as_ref(), &[chant.current_tier]],
+        bump,
+    )]
+    pub tier_result: Account<'info, TierResult>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DeclarePodium<'info> {
+    #[account(mut)]
+    pub chant: Account<'info, Chant>,
+
+    /// CHECK: Podium PDA, created by declare_podium if not already declared.
+    #[account(
+        mut,
+        seeds = [b"podium", chant.key().as_ref()],
+        bump,
+    )]
+    pub podium: UncheckedAccount<'info>,
+
+    /// CHECK: Champion PDA; when declared, first place must be the champion.
+    #[account(
+        seeds = [b"champion", chant.key().as_ref()],
+        bump,
+    )]
+    pub champion: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdatePhase<'info> {
+    #[account(mut)]
+    pub chant: Account<'info, Chant>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Realloc to the new question's length (no ideas yet, so nothing disqualified).
+#[derive(Accounts)]
+#[instruction(new_question: String)]
+pub struct UpdateQuestion<'info> {
+    #[account(
+        mut,
+        realloc = Chant::space(&chant.chant_id, &new_question),
+        realloc::payer = authority,
+        realloc::zero = false,
     )]
-    pub idea: Account<'info, Idea>,
+    pub chant: Account<'info, Chant>,
 
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -336,83 +1494,83 @@ pub struct RecordIdea<'info> {
 }
 
 #[derive(Accounts)]
-#[instruction(cell_index: u16, tier: u8, batch: u8, idea_indices: Vec<u16>)]
-pub struct RecordCell<'info> {
+pub struct ViewChant<'info> {
+    pub chant: Account<'info, Chant>,
+}
+
+/// Authority-gated chant action (pause, unpause, seal_transcript).
+#[derive(Accounts)]
+pub struct ChantAction<'info> {
     #[account(mut)]
     pub chant: Account<'info, Chant>,
 
-    #[account(
-        init,
-        payer = authority,
-        space = Cell::space(&idea_indices),
-        seeds = [b"cell", chant.key().as_ref(), &cell_index.to_le_bytes()],
-        bump,
-    )]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CompleteCell<'info> {
+    pub chant: Account<'info, Chant>,
+
+    #[account(mut, has_one = chant)]
     pub cell: Account<'info, Cell>,
 
-    #[account(mut)]
     pub authority: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(voter_id: String, allocations: Vec<Allocation>)]
-pub struct RecordVote<'info> {
+pub struct VoidCell<'info> {
+    #[account(mut)]
     pub chant: Account<'info, Chant>,
 
-    #[account(mut)]
+    #[account(mut, has_one = chant)]
     pub cell: Account<'info, Cell>,
 
+    /// CHECK: The cell's TierResult PDA; must not exist yet (tier still open).
     #[account(
-        init,
-        payer = authority,
-        space = VoteRecord::space(&voter_id, &allocations),
-        seeds = [b"vote", cell.key().as_ref(), voter_id.as_bytes()],
+        seeds = [b"tier", chant.key().as_ref(), &[cell.tier]],
         bump,
     )]
-    pub vote: Account<'info, VoteRecord>,
+    pub tier_result: UncheckedAccount<'info>,
 
-    #[account(mut)]
     pub authority: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-#[instruction(tier: u8, advancing_indices: Vec<u16>, xp_totals: Vec<XpEntry>)]
-pub struct RecordTierResult<'info> {
-    #[account(mut)]
+pub struct CloseCell<'info> {
     pub chant: Account<'info, Chant>,
 
-    #[account(
-        init,
-        payer = authority,
-        space = TierResult::space(&advancing_indices, &xp_totals),
-        seeds = [b"tier", chant.key().as_ref(), &[tier]],
-        bump,
-    )]
+    /// CHECK: A Completed Cell of this chant (checked in close_cell), rewritten
+    /// in place as its CellTombstone.
+    #[account(mut, owner = crate::ID)]
+    pub cell: UncheckedAccount<'info>,
+
+    /// The cell's tier result; close_cell checks it is this chant's, for the cell's tier.
     pub tier_result: Account<'info, TierResult>,
 
     #[account(mut)]
     pub authority: Signer<'info>,
-
-    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct DeclareChampion<'info> {
-    #[account(mut)]
+#[instruction(checkpoint_id: u32)]
+pub struct ExportRoot<'info> {
     pub chant: Account<'info, Chant>,
 
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
-        space = Champion::SPACE,
+        space = ExportCheckpoint::SPACE,
+        seeds = [b"export", chant.key().as_ref(), &checkpoint_id.to_le_bytes()],
+        bump,
+    )]
+    pub checkpoint: Account<'info, ExportCheckpoint>,
+
+    /// CHECK: Champion PDA; once declared, sealing needs it folded in.
+    #[account(
         seeds = [b"champion", chant.key().as_ref()],
         bump,
     )]
-    pub champion: Account<'info, Champion>,
+    pub champion: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -420,17 +1578,16 @@ pub struct DeclareChampion<'info> {
     pub system_program: Program<'info, System>,
 }
 
-#[derive(Accounts)]
-pub struct UpdatePhase<'info> {
-    #[account(mut)]
-    pub chant: Account<'info, Chant>,
-
-    pub authority: Signer<'info>,
-}
-
 // ═══════════════════════════════════════════════════════
 // Account structs
 // ═══════════════════════════════════════════════════════
+//
+// Layout break: every account below gained fields in the middle of the
+// layout the first devnet deployment wrote, so chants, ideas, cells, votes,
+// tier results and champions created by that deployment don't deserialize
+// here and are not migrated — those chants are abandoned and must be
+// re-recorded. Fields added once this layout is deployed go after `bump`,
+// with a realloc migration instruction.
 
 #[account]
 pub struct Chant {
@@ -443,6 +1600,21 @@ pub struct Chant {
     pub current_tier: u8,        // 1
     pub idea_count: u16,         // 2
     pub cell_count: u16,         // 2
+    pub submission_deadline: i64, // 8 (0 = open-ended)
+    pub vote_budget: u16,        // 2
+    pub paused: bool,            // 1 — emergency stop for the recorder
+    pub max_ideas_per_author: u16, // 2 (0 = unlimited)
+    pub live_idea_count: u16,    // 2 — idea_count minus disqualified
+    pub disqualified_indices: Vec<u16>, // 4 + 2 * len, sorted; grown by realloc
+    pub open_tier: u8,           // 1 — tier cells are being recorded for (highest, in continuous flow)
+    pub tier_in_progress: bool,  // 1 — open_tier has cells but no TierResult yet
+    pub secret_ballot: bool,     // 1 — votes recorded by commitment, not voter_id
+    pub tier_cell_counts: [u16; MAX_TIERS], // 2 * MAX_TIERS — live (non-voided) cells per tier
+    pub max_ideas: u16,          // 2 (0 = unlimited)
+    pub transcript_hash: [u8; 32], // 32 — keccak of the full transcript (zero = unsealed)
+    pub tier_started_at: [i64; MAX_TIERS], // 8 * MAX_TIERS — first cell's created_at per tier (0 = not started)
+    pub tier_last_batch: [u8; MAX_TIERS], // MAX_TIERS — highest batch recorded per tier
+    pub tier_closed: [bool; MAX_TIERS], // MAX_TIERS — TierResult recorded; no more cells
     pub created_at: i64,         // 8
     pub bump: u8,                // 1
 }
@@ -459,9 +1631,166 @@ impl Chant {
         1 +   // current_tier
         2 +   // idea_count
         2 +   // cell_count
+        8 +   // submission_deadline
+        2 +   // vote_budget
+        1 +   // paused
+        2 +   // max_ideas_per_author
+        2 +   // live_idea_count
+        4 +   // disqualified_indices (empty at init)
+        1 +   // open_tier
+        1 +   // tier_in_progress
+        1 +   // secret_ballot
+        2 * MAX_TIERS + // tier_cell_counts
+        2 +   // max_ideas
+        32 +  // transcript_hash
+        8 * MAX_TIERS + // tier_started_at
+        MAX_TIERS +     // tier_last_batch
+        MAX_TIERS +     // tier_closed
         8 +   // created_at
         1     // bump
     }
+
+    /// record_cell's tier sequencing for a new cell at `tier` / `batch`.
+    pub fn open_cell(&mut self, tier: u8, batch: u8) -> Result<()> {
+        // No cells for tiers already closed out, and batches never go backwards
+        // within a tier, however cells of different tiers interleave
+        require!((tier as usize) < MAX_TIERS, AuditError::TierLimitExceeded);
+        require!(!self.tier_closed[tier as usize], AuditError::TierAlreadyRecorded);
+        require!(
+            batch >= self.tier_last_batch[tier as usize],
+            AuditError::NonMonotonicCell
+        );
+
+        if self.continuous_flow {
+            // Continuous flow: advancing ideas are mixed into new cells as they
+            // arrive, so cells of different tiers may be open at the same time.
+            self.open_tier = self.open_tier.max(tier);
+        } else if self.tier_in_progress {
+            // Batch mode: every cell belongs to the one open tier...
+            require!(tier == self.open_tier, AuditError::TierOutOfSequence);
+        } else {
+            // ...and the next tier opens only after the last one's result is recorded
+            require!(
+                self.cell_count == 0 || tier == self.open_tier.saturating_add(1),
+                AuditError::TierOutOfSequence
+            );
+            self.open_tier = tier;
+        }
+        self.tier_in_progress = true;
+        self.tier_last_batch[tier as usize] = batch;
+        Ok(())
+    }
+
+    /// Count a cell record_cell just created at `created_at`.
+    pub fn count_cell(&mut self, tier: u8, created_at: i64) -> Result<()> {
+        self.cell_count = self.cell_count.checked_add(1).ok_or(AuditError::MathOverflow)?;
+        self.tier_cell_counts[tier as usize] += 1;
+        // First cell starts the tier's clock; voiding it later doesn't reset it
+        if self.tier_started_at[tier as usize] == 0 {
+            self.tier_started_at[tier as usize] = created_at;
+        }
+        Ok(())
+    }
+
+    /// Cells seat between MIN_CELL_SIZE (pairs) and MAX_CELL_SIZE (panels) voters.
+    pub fn check_cell_size(cell_size: u8) -> Result<()> {
+        require!(
+            (MIN_CELL_SIZE..=MAX_CELL_SIZE).contains(&cell_size),
+            AuditError::CellSizeOutOfBounds
+        );
+        Ok(())
+    }
+
+    /// Every member of a cell must be a recorded idea, and appear once.
+    pub fn check_cell_ideas(&self, idea_indices: &[u16]) -> Result<()> {
+        for (i, idx) in idea_indices.iter().enumerate() {
+            if *idx >= self.idea_count {
+                msg!("Idea index {} out of range (idea_count {})", idx, self.idea_count);
+                return err!(AuditError::IdeaIndexOutOfRange);
+            }
+            require!(
+                !idea_indices[..i].contains(idx),
+                AuditError::DuplicateCellIdea
+            );
+        }
+        Ok(())
+    }
+
+    /// A ballot must spend exactly the chant's vote budget; returns its point total.
+    pub fn check_vote_budget(&self, allocations: &[Allocation]) -> Result<u16> {
+        let total: u16 = allocations.iter().map(|a| a.points as u16).sum();
+        if total != self.vote_budget {
+            msg!("Vote points must sum to {} (got {})", self.vote_budget, total);
+            return err!(AuditError::InvalidPointTotal);
+        }
+        Ok(total)
+    }
+
+    /// record_tier_result's advancement check. `cells` must be every live cell
+    /// of `tier`; `ideas` holds one Idea per advancing index, and each must
+    /// have sat in one of those cells and still be in the running.
+    pub fn check_advancement(
+        &self,
+        chant_key: Pubkey,
+        tier: u8,
+        cells: &[Cell],
+        ideas: &[Idea],
+        advancing_indices: &[u16],
+    ) -> Result<()> {
+        let mut tier_ideas: Vec<u16> = Vec::new();
+        for cell in cells {
+            // Voided cells don't count toward the tally
+            require!(
+                cell.chant == chant_key
+                    && cell.tier == tier
+                    && cell.status != CellStatus::Voided as u8,
+                AuditError::InvalidAdvancement
+            );
+            tier_ideas.extend_from_slice(&cell.idea_indices);
+        }
+        // The recorded count must match the cells actually supplied
+        require!(
+            cells.len() == self.tier_cell_counts[tier as usize] as usize,
+            AuditError::CellCountMismatch
+        );
+        for (idea, &index) in ideas.iter().zip(advancing_indices.iter()) {
+            require!(
+                idea.chant == chant_key
+                    && idea.index == index
+                    && idea.status != IdeaStatus::Eliminated as u8
+                    && idea.status != IdeaStatus::Disqualified as u8
+                    && tier_ideas.contains(&index),
+                AuditError::InvalidAdvancement
+            );
+        }
+        Ok(())
+    }
+
+    /// get_live_idea_ranges: live indices in [start, start + limit).
+    pub fn live_idea_ranges(&self, start: u16, limit: u16) -> Result<Vec<IdeaRange>> {
+        let end = self.idea_count.min(start.saturating_add(limit));
+
+        let mut ranges = Vec::new();
+        let mut cursor = start;
+        for &skipped in self.disqualified_indices.iter() {
+            if skipped < cursor {
+                continue;
+            }
+            if skipped >= end {
+                break;
+            }
+            if skipped > cursor {
+                ranges.push(IdeaRange { start: cursor, end: skipped });
+            }
+            cursor = skipped + 1;
+        }
+        if cursor < end {
+            ranges.push(IdeaRange { start: cursor, end });
+        }
+        require!(ranges.len() <= MAX_LIVE_RANGES, AuditError::TooManyItems);
+
+        Ok(ranges)
+    }
 }
 
 #[account]
@@ -474,6 +1803,9 @@ pub struct Idea {
     pub tier: u8,                // 1
     pub total_xp: u16,           // 2
     pub created_at: i64,         // 8
+    pub author_pubkey: Pubkey,   // 32 (default = author did not co-sign)
+    pub last_xp_tier: Option<u8>, // 2 — last tier folded into total_xp
+    pub cell_appearances: u16,   // 2 — cells this idea was placed in, all tiers
     pub bump: u8,                // 1
 }
 
@@ -488,8 +1820,30 @@ impl Idea {
         1 +   // tier
         2 +   // total_xp
         8 +   // created_at
+        32 +  // author_pubkey
+        2 +   // last_xp_tier
+        2 +   // cell_appearances
         1     // bump
     }
+
+    /// record_cell's check that this is the member at `index`, counting the
+    /// appearance.
+    pub fn join_cell(&mut self, chant: Pubkey, index: u16) -> Result<()> {
+        require!(
+            self.chant == chant && self.index == index,
+            AuditError::IndexMismatch
+        );
+        self.cell_appearances = self.cell_appearances.saturating_add(1);
+        Ok(())
+    }
+
+    /// Fold one tier's XP into the running cross-tier total, once per tier.
+    pub fn apply_tier_xp(&mut self, tier: u8, xp: u16) -> Result<()> {
+        require!(self.last_xp_tier != Some(tier), AuditError::TierXpAlreadyApplied);
+        self.total_xp = self.total_xp.saturating_add(xp);
+        self.last_xp_tier = Some(tier);
+        Ok(())
+    }
 }
 
 #[account]
@@ -501,11 +1855,39 @@ pub struct Cell {
     pub status: u8,              // 1
     pub idea_indices: Vec<u16>,  // 4 + 2 * len
     pub voter_count: u8,         // 1
+    pub vote_count: u16,         // 2 — VoteRecords for this cell, revisions included
+    pub total_points: u32,       // 4 — sum of live votes' points, ≤ cell_size * vote_budget
+    pub voting_deadline: i64,    // 8 (0 = no deadline)
     pub created_at: i64,         // 8
     pub bump: u8,                // 1
 }
 
 impl Cell {
+    /// Votes are accepted until voting_deadline, when one is set.
+    pub fn voting_open(&self, now: i64) -> bool {
+        self.voting_deadline == 0 || now <= self.voting_deadline
+    }
+
+    /// The one gate for record_vote, record_committed_vote and revise_vote:
+    /// the cell is still Voting and its deadline hasn't passed.
+    pub fn require_accepting_votes(&self, now: i64) -> Result<()> {
+        require!(self.status == CellStatus::Voting as u8, AuditError::CellNotVoting);
+        require!(self.voting_open(now), AuditError::CellVotingClosed);
+        Ok(())
+    }
+
+    /// At most cell_size voters' worth of points — catches extra votes in a full cell.
+    pub fn add_points(&mut self, chant: &Chant, points: u16) -> Result<()> {
+        let max = chant.cell_size as u32 * chant.vote_budget as u32;
+        let total_points = self.total_points + points as u32;
+        if total_points > max {
+            msg!("Cell points would reach {} (max {})", total_points, max);
+            return err!(AuditError::CellPointsOverflow);
+        }
+        self.total_points = total_points;
+        Ok(())
+    }
+
     pub fn space(idea_indices: &[u16]) -> usize {
         8 +   // discriminator
         32 +  // chant
@@ -515,11 +1897,39 @@ impl Cell {
         1 +   // status
         4 + 2 * idea_indices.len() + // idea_indices
         1 +   // voter_count
+        2 +   // vote_count
+        4 +   // total_points
+        8 +   // voting_deadline
         8 +   // created_at
         1     // bump
     }
 }
 
+/// What close_cell leaves at a closed cell's address. The digest chains the
+/// cell and its votes as export_root would have folded them:
+///   d_0 = keccak("UC:LEAF" ++ cell), d_k = keccak("UC:NODE" ++ d_{k-1} ++ keccak("UC:LEAF" ++ vote_k))
+/// with votes in ascending address order.
+#[account]
+pub struct CellTombstone {
+    pub chant: Pubkey,           // 32
+    pub index: u16,              // 2
+    pub tier: u8,                // 1
+    pub vote_count: u16,         // 2 — votes closed with the cell
+    pub digest: [u8; 32],        // 32
+    pub bump: u8,                // 1 — the cell's PDA bump
+}
+
+impl CellTombstone {
+    pub const SPACE: usize =
+        8 +   // discriminator
+        32 +  // chant
+        2 +   // index
+        1 +   // tier
+        2 +   // vote_count
+        32 +  // digest
+        1;    // bump
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct Allocation {
     pub idea_index: u16,
@@ -532,6 +1942,9 @@ pub struct VoteRecord {
     pub voter_id: String,        // 4 + len
     pub allocations: Vec<Allocation>, // 4 + 3 * len
     pub voted_at: i64,           // 8
+    pub version: u16,            // 2 — 0 = original record_vote, +1 per revise_vote
+    pub superseded: bool,        // 1 — a later version replaces this one
+    pub voter_commitment: [u8; 32], // 32 — secret ballot only; zero for plaintext votes
     pub bump: u8,                // 1
 }
 
@@ -542,8 +1955,23 @@ impl VoteRecord {
         4 + voter_id.len() +  // voter_id
         4 + 3 * allocations.len() + // allocations (u16 + u8 = 3 bytes each)
         8 +   // voted_at
+        2 +   // version
+        1 +   // superseded
+        32 +  // voter_commitment
         1     // bump
     }
+
+    /// Hand this record over to revision `version`: only the latest version may
+    /// be revised, and versions go up one at a time.
+    pub fn supersede(&mut self, version: u16) -> Result<()> {
+        require!(!self.superseded, AuditError::VoteSuperseded);
+        require!(
+            self.version.checked_add(1) == Some(version),
+            AuditError::InvalidVoteVersion
+        );
+        self.superseded = true;
+        Ok(())
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -558,6 +1986,8 @@ pub struct TierResult {
     pub tier: u8,                     // 1
     pub advancing_indices: Vec<u16>,  // 4 + 2 * len
     pub xp_totals: Vec<XpEntry>,     // 4 + 4 * len
+    pub cell_count: u16,              // 2 — live cells the tier ran
+    pub started_at: i64,              // 8 — first cell recorded (duration = completed_at - started_at)
     pub completed_at: i64,            // 8
     pub bump: u8,                     // 1
 }
@@ -569,9 +1999,20 @@ impl TierResult {
         1 +   // tier
         4 + 2 * advancing.len() +   // advancing_indices
         4 + 4 * xp_totals.len() +   // xp_totals (u16 + u16 = 4 bytes each)
+        2 +   // cell_count
+        8 +   // started_at
         8 +   // completed_at
         1     // bump
     }
+
+    /// TierCompleted's standings for live dashboards: the MAX_EVENT_XP highest
+    /// entries, highest first. The full list stays in the TierResult.
+    pub fn top_xp(&self) -> Vec<XpEntry> {
+        let mut top_xp = self.xp_totals.clone();
+        top_xp.sort_by_key(|e| std::cmp::Reverse(e.total_xp));
+        top_xp.truncate(MAX_EVENT_XP);
+        top_xp
+    }
 }
 
 #[account]
@@ -581,7 +2022,11 @@ pub struct Champion {
     pub text_hash: [u8; 32],     // 32
     pub total_tiers: u8,         // 1
     pub total_voters: u16,       // 2
+    pub winner_wallet: Pubkey,   // 32 — launch pool winner this champion maps to
+    pub vote_root: [u8; 32],     // 32 — Merkle root of the chant's votes
     pub declared_at: i64,        // 8
+    pub winning_xp: u16,         // 2 — champion's XP in the final TierResult
+    pub runner_up_xp: u16,       // 2 — best other XP there (0 if unopposed)
     pub bump: u8,                // 1
 }
 
@@ -593,8 +2038,196 @@ impl Champion {
         32 +  // text_hash
         1 +   // total_tiers
         2 +   // total_voters
+        32 +  // winner_wallet
+        32 +  // vote_root
+        8 +   // declared_at
+        2 +   // winning_xp
+        2 +   // runner_up_xp
+        1;    // bump
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PodiumPlace {
+    pub rank: u8,                // 1 = champion
+    pub idea_index: u16,
+    pub text_hash: [u8; 32],
+}
+
+#[account]
+pub struct Podium {
+    pub chant: Pubkey,           // 32
+    pub places: Vec<PodiumPlace>, // 4 + 35 * len, sorted by rank, ≤ MAX_PODIUM_SIZE
+    pub declared_at: i64,        // 8
+    pub bump: u8,                // 1
+}
+
+impl Podium {
+    pub fn space(places: usize) -> usize {
+        8 +   // discriminator
+        32 +  // chant
+        4 + 35 * places + // places (u8 + u16 + 32 bytes each)
         8 +   // declared_at
+        1     // bump
+    }
+}
+
+/// Per-author submission count, keyed by author_id.
+#[account]
+pub struct AuthorStats {
+    pub chant: Pubkey,           // 32
+    pub author_id: String,       // 4 + MAX_AUTHOR_ID
+    pub idea_count: u16,         // 2
+    pub bump: u8,                // 1
+}
+
+impl AuthorStats {
+    pub const SPACE: usize =
+        8 +   // discriminator
+        32 +  // chant
+        4 + MAX_AUTHOR_ID + // author_id
+        2 +   // idea_count
+        1;    // bump
+}
+
+#[account]
+pub struct ExportCheckpoint {
+    pub chant: Pubkey,           // 32
+    pub checkpoint_id: u32,      // 4
+    pub root: [u8; 32],          // 32
+    pub item_count: u32,         // 4
+    pub sealed: bool,            // 1
+    pub created_at: i64,         // 8
+    pub sealed_at: i64,          // 8
+    pub stage: u8,               // 1 — ExportStage reached so far
+    pub next_idea: u16,          // 2 — ideas folded (= next expected index)
+    pub next_cell: u16,          // 2 — cells folded (= next expected index)
+    pub last_cell: Pubkey,       // 32 — cell whose votes may follow
+    pub last_vote: Pubkey,       // 32 — votes of last_cell ascend by address
+    pub next_tier: u16,          // 2 — tier results must be at or above this
+    pub votes_left: u16,         // 2 — votes of last_cell not yet folded
+    pub tier_results: u16,       // 2 — tier results folded
+    pub bump: u8,                // 1
+}
+
+impl ExportCheckpoint {
+    pub const SPACE: usize =
+        8 +   // discriminator
+        32 +  // chant
+        4 +   // checkpoint_id
+        32 +  // root
+        4 +   // item_count
+        1 +   // sealed
+        8 +   // created_at
+        8 +   // sealed_at
+        1 +   // stage
+        2 +   // next_idea
+        2 +   // next_cell
+        32 +  // last_cell
+        32 +  // last_vote
+        2 +   // next_tier
+        2 +   // votes_left
+        2 +   // tier_results
         1;    // bump
+
+    /// Check that the account `key` with `data` comes next in the canonical
+    /// export order for `chant`, and move the cursor past it.
+    pub fn advance(&mut self, chant: &Account<Chant>, key: Pubkey, data: &[u8]) -> Result<()> {
+        let ideas_done = self.next_idea == chant.idea_count;
+        // A cell is done once all of its votes are in
+        let cells_done = ideas_done && self.next_cell == chant.cell_count && self.votes_left == 0;
+        let disc = data.get(..8).ok_or(AuditError::InvalidExportAccount)?;
+        let mut data = data;
+        if disc == Idea::DISCRIMINATOR {
+            let idea = Idea::try_deserialize(&mut data)?;
+            require!(
+                self.stage == ExportStage::Ideas as u8
+                    && idea.chant == chant.key()
+                    && idea.index == self.next_idea,
+                AuditError::ExportOutOfOrder
+            );
+            self.next_idea += 1;
+        } else if disc == Cell::DISCRIMINATOR {
+            let cell = Cell::try_deserialize(&mut data)?;
+            require!(
+                self.stage <= ExportStage::Cells as u8
+                    && ideas_done
+                    && self.votes_left == 0
+                    && cell.chant == chant.key()
+                    && cell.index == self.next_cell,
+                AuditError::ExportOutOfOrder
+            );
+            self.stage = ExportStage::Cells as u8;
+            self.next_cell += 1;
+            self.last_cell = key;
+            self.last_vote = Pubkey::default();
+            self.votes_left = cell.vote_count;
+        } else if disc == CellTombstone::DISCRIMINATOR {
+            // A closed cell: its votes are already chained into the digest
+            let tombstone = CellTombstone::try_deserialize(&mut data)?;
+            require!(
+                self.stage <= ExportStage::Cells as u8
+                    && ideas_done
+                    && self.votes_left == 0
+                    && tombstone.chant == chant.key()
+                    && tombstone.index == self.next_cell,
+                AuditError::ExportOutOfOrder
+            );
+            self.stage = ExportStage::Cells as u8;
+            self.next_cell += 1;
+            self.last_cell = key;
+            self.last_vote = Pubkey::default();
+        } else if disc == VoteRecord::DISCRIMINATOR {
+            let vote = VoteRecord::try_deserialize(&mut data)?;
+            require!(
+                self.stage == ExportStage::Cells as u8
+                    && self.votes_left > 0
+                    && vote.cell == self.last_cell
+                    && key > self.last_vote,
+                AuditError::ExportOutOfOrder
+            );
+            self.last_vote = key;
+            self.votes_left -= 1;
+        } else if disc == TierResult::DISCRIMINATOR {
+            let result = TierResult::try_deserialize(&mut data)?;
+            require!(
+                self.stage <= ExportStage::TierResults as u8
+                    && cells_done
+                    && result.chant == chant.key()
+                    && result.tier as u16 >= self.next_tier,
+                AuditError::ExportOutOfOrder
+            );
+            self.stage = ExportStage::TierResults as u8;
+            self.next_tier = result.tier as u16 + 1;
+            self.tier_results += 1;
+        } else if disc == Champion::DISCRIMINATOR {
+            let champion = Champion::try_deserialize(&mut data)?;
+            require!(
+                self.stage < ExportStage::Done as u8 && cells_done && champion.chant == chant.key(),
+                AuditError::ExportOutOfOrder
+            );
+            self.stage = ExportStage::Done as u8;
+        } else {
+            return err!(AuditError::InvalidExportAccount);
+        }
+        Ok(())
+    }
+}
+
+/// Half-open range [start, end) of live idea indices.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct IdeaRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+/// Optional chant settings for initialize_chant. Zero keeps the default.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChantConfig {
+    pub submission_deadline: i64,   // 0 = open-ended
+    pub vote_budget: u16,           // 0 = DEFAULT_VOTE_BUDGET
+    pub max_ideas_per_author: u16,  // 0 = unlimited
+    pub secret_ballot: bool,        // Votes by commitment (record_committed_vote)
+    pub max_ideas: u16,             // 0 = unlimited
 }
 
 // ═══════════════════════════════════════════════════════
@@ -616,12 +2249,23 @@ pub enum IdeaStatus {
     Advancing = 2,
     Eliminated = 3,
     Winner = 4,
+    Disqualified = 5,
+}
+
+/// Sections of the canonical export order (ExportCheckpoint::advance).
+#[repr(u8)]
+pub enum ExportStage {
+    Ideas = 0,
+    Cells = 1,          // Each cell followed by its votes
+    TierResults = 2,
+    Done = 3,           // Champion folded; nothing may follow
 }
 
 #[repr(u8)]
 pub enum CellStatus {
     Voting = 0,
     Completed = 1,
+    Voided = 2,
 }
 
 // ═══════════════════════════════════════════════════════
@@ -640,6 +2284,7 @@ pub struct IdeaRecorded {
     pub chant: Pubkey,
     pub idea_index: u16,
     pub author_id: String,
+    pub author_pubkey: Pubkey,
 }
 
 #[event]
@@ -648,6 +2293,8 @@ pub struct CellRecorded {
     pub cell_index: u16,
     pub tier: u8,
     pub batch: u8,
+    pub idea_indices: Vec<u16>,         // ≤ MAX_IDEAS_PER_CELL
+    pub voting_deadline: i64,           // 0 = none
 }
 
 #[event]
@@ -657,11 +2304,55 @@ pub struct VoteRecorded {
     pub voter_id: String,
 }
 
+#[event]
+pub struct CommittedVoteRecorded {
+    pub chant: Pubkey,
+    pub cell: Pubkey,
+    pub voter_commitment: [u8; 32],
+}
+
+#[event]
+pub struct VoterRevealed {
+    pub chant: Pubkey,
+    pub cell: Pubkey,
+    pub voter_commitment: [u8; 32],
+    pub voter_id: String,
+}
+
+#[event]
+pub struct VoteRevised {
+    pub chant: Pubkey,
+    pub cell: Pubkey,
+    pub voter_id: String,
+    pub version: u16,
+    pub prior: Pubkey,                  // Now-superseded record
+}
+
+#[event]
+pub struct IdeaStatusesApplied {
+    pub chant: Pubkey,
+    pub tier: u8,
+    pub advancing: u16,
+    pub eliminated: u16,
+}
+
 #[event]
 pub struct TierCompleted {
     pub chant: Pubkey,
     pub tier: u8,
     pub advancing_count: u16,
+    pub xp_count: u16,                  // Total XP entries in the TierResult
+    pub cell_count: u16,                // Live cells the tier ran
+    pub top_xp: Vec<XpEntry>,           // Up to MAX_EVENT_XP, highest first
+    pub started_at: i64,                // First cell recorded in the tier
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PodiumDeclared {
+    pub chant: Pubkey,
+    pub idea_indices: Vec<u16>,         // By rank, first place first
+    pub timestamp: i64,
 }
 
 #[event]
@@ -670,6 +2361,10 @@ pub struct ChampionDeclared {
     pub idea_index: u16,
     pub total_tiers: u8,
     pub total_voters: u16,
+    pub winner_wallet: Pubkey,
+    pub winning_xp: u16,
+    pub runner_up_xp: u16,
+    pub timestamp: i64,
 }
 
 #[event]
@@ -677,6 +2372,68 @@ pub struct PhaseUpdated {
     pub chant: Pubkey,
     pub old_phase: u8,
     pub new_phase: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TranscriptSealed {
+    pub chant: Pubkey,
+    pub transcript_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChantPaused {
+    pub chant: Pubkey,
+}
+
+#[event]
+pub struct ChantUnpaused {
+    pub chant: Pubkey,
+}
+
+#[event]
+pub struct QuestionUpdated {
+    pub chant: Pubkey,
+    pub question: String,
+}
+
+#[event]
+pub struct IdeaDisqualified {
+    pub chant: Pubkey,
+    pub idea_index: u16,
+    pub live_idea_count: u16,
+}
+
+#[event]
+pub struct CellCompleted {
+    pub chant: Pubkey,
+    pub cell_index: u16,
+    pub voter_count: u8,
+}
+
+#[event]
+pub struct CellVoided {
+    pub chant: Pubkey,
+    pub cell_index: u16,
+    pub tier: u8,
+    pub voter_count: u8,                // Votes now excluded
+}
+
+#[event]
+pub struct CellClosed {
+    pub chant: Pubkey,
+    pub cell_index: u16,
+    pub votes_closed: u16,
+    pub digest: [u8; 32],
+}
+
+#[event]
+pub struct ExportRootSealed {
+    pub chant: Pubkey,
+    pub checkpoint_id: u32,
+    pub root: [u8; 32],
+    pub item_count: u32,
 }
 
 // ═══════════════════════════════════════════════════════
@@ -695,8 +2452,100 @@ pub enum AuditError {
     IndexMismatch,
     #[msg("Too many items in vector")]
     TooManyItems,
-    #[msg("Vote points must sum to 10")]
+    #[msg("Vote points must sum to the chant's vote budget")]
     InvalidPointTotal,
     #[msg("Invalid phase value")]
     InvalidPhase,
+    #[msg("Cell is not in voting status")]
+    CellNotVoting,
+    #[msg("Cell must be completed before closing")]
+    CellNotCompleted,
+    #[msg("Vote account does not belong to this cell")]
+    InvalidVoteAccount,
+    #[msg("Submission deadline has passed")]
+    SubmissionClosed,
+    #[msg("Export checkpoint is already sealed")]
+    ExportSealed,
+    #[msg("Export account is not owned by this program")]
+    InvalidExportAccount,
+    #[msg("Chant is paused")]
+    ChantPaused,
+    #[msg("Chant is already paused")]
+    AlreadyPaused,
+    #[msg("Chant is not paused")]
+    NotPaused,
+    #[msg("Author must sign")]
+    AuthorNotSigner,
+    #[msg("Author has reached the idea limit for this chant")]
+    AuthorIdeaLimitReached,
+    #[msg("Advancing idea did not compete in this tier")]
+    InvalidAdvancement,
+    #[msg("Idea is already disqualified")]
+    AlreadyDisqualified,
+    #[msg("Cell size must be between 2 and 12")]
+    CellSizeOutOfBounds,
+    #[msg("Cell tier is out of sequence")]
+    TierOutOfSequence,
+    #[msg("Question can't change once ideas are submitted")]
+    QuestionLockedAfterSubmissions,
+    #[msg("Cell tier or batch is out of order")]
+    NonMonotonicCell,
+    #[msg("Tier XP already applied to this idea")]
+    TierXpAlreadyApplied,
+    #[msg("Vote has already been superseded by a later version")]
+    VoteSuperseded,
+    #[msg("Vote version must be the prior version plus one")]
+    InvalidVoteVersion,
+    #[msg("Cell already holds cell_size * vote_budget points")]
+    CellPointsOverflow,
+    #[msg("Vote instruction does not match the chant's ballot mode")]
+    BallotModeMismatch,
+    #[msg("Voter commitment is missing or does not match")]
+    InvalidCommitment,
+    #[msg("Voter has already been revealed")]
+    VoterAlreadyRevealed,
+    #[msg("Champion has already been declared for this chant")]
+    ChampionAlreadyDeclared,
+    #[msg("Podium must have between 1 and 10 places")]
+    InvalidPodiumSize,
+    #[msg("Podium ranks must be unique and run 1..=N")]
+    DuplicatePodiumRank,
+    #[msg("An idea can hold only one podium place")]
+    DuplicatePodiumIdea,
+    #[msg("Podium has already been declared for this chant")]
+    PodiumAlreadyDeclared,
+    #[msg("Cell references an idea that hasn't been recorded")]
+    IdeaIndexOutOfRange,
+    #[msg("Cell lists the same idea twice")]
+    DuplicateCellIdea,
+    #[msg("Cell voting deadline has passed")]
+    CellVotingClosed,
+    #[msg("Champion's margin doesn't match the final tier's recorded XP")]
+    MarginDataMismatch,
+    #[msg("Cell has already been voided")]
+    CellAlreadyVoided,
+    #[msg("The cell's tier result is already recorded")]
+    TierAlreadyRecorded,
+    #[msg("Tier exceeds the tracked tier limit")]
+    TierLimitExceeded,
+    #[msg("Idea did not compete in this tier")]
+    IdeaTierMismatch,
+    #[msg("Chant has reached its maximum number of ideas")]
+    MaxIdeasReached,
+    #[msg("Transcript already sealed")]
+    TranscriptAlreadySealed,
+    #[msg("Transcript hash must be non-zero")]
+    InvalidTranscriptHash,
+    #[msg("Cells supplied don't match the tier's recorded cell count")]
+    CellCountMismatch,
+    #[msg("Account is out of canonical export order or from another chant")]
+    ExportOutOfOrder,
+    #[msg("Export must include every idea, cell, vote, tier result and the champion before sealing")]
+    ExportIncomplete,
+    #[msg("First podium place must be the declared champion")]
+    PodiumChampionMismatch,
+    #[msg("The cell's tier result isn't recorded")]
+    TierNotRecorded,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
 }
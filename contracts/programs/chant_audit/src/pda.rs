@@ -0,0 +1,62 @@
+//! Account creation shared by the UnionChant programs.
+//!
+//! Both programs create some PDAs by hand — conditionally, or with a size only
+//! known inside the instruction — where Anchor's `init` doesn't fit. The launch
+//! program reuses this through its chant_audit dependency.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+/// Create a PDA owned by `owner`, as Anchor's `init` would — including when
+/// the address was pre-funded by someone else.
+pub fn create_pda<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    owner: &Pubkey,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer = &[seeds];
+
+    if target.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount { from: payer.clone(), to: target.clone() },
+                signer,
+            ),
+            rent,
+            space as u64,
+            owner,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(target.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer { from: payer.clone(), to: target.clone() },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: target.clone() },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: target.clone() },
+            signer,
+        ),
+        owner,
+    )
+}
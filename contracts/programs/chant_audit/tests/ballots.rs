@@ -0,0 +1,63 @@
+//! Secret ballots: committed votes and reveal_voter. record_committed_vote
+//! creates its VoteRecord through `init`, so those votes are seeded here.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::system_program;
+use chant_audit::{hash, AuditError, VoteRecord};
+use common::*;
+
+const SALT: [u8; 32] = [9; 32];
+
+fn reveal_voter(audit: &mut Audit, vote: Pubkey, voter_id: &str, salt: [u8; 32]) -> TxResult {
+    // The revealed voter_id grows the record; pre-fund the rent so realloc needs no transfer
+    audit.bank.airdrop(&vote, 1_000_000);
+    audit.bank.process(ix(
+        chant_audit::instruction::RevealVoter { voter_id: voter_id.to_string(), salt },
+        chant_audit::accounts::RevealVoter {
+            chant: audit.chant,
+            vote,
+            authority: audit.authority,
+            system_program: system_program::ID,
+        },
+    ))
+}
+
+/// A secret-ballot chant with one cell and voter-a's committed vote in it.
+fn committed() -> (Audit, Pubkey) {
+    let mut audit = Audit::new();
+    audit.update(|chant| chant.secret_ballot = true);
+    let ideas = audit.seed_ideas(2);
+    audit.seed_cell(0, &ideas);
+    let commitment = hash::voter_commitment(&audit.cell(0), "voter-a", &SALT);
+    let vote = audit.seed_committed_vote(0, commitment);
+    (audit, vote)
+}
+
+#[test]
+fn committed_vote_opens_with_its_salt() {
+    let (mut audit, vote) = committed();
+    assert!(audit.bank.get::<VoteRecord>(&vote).voter_id.is_empty());
+
+    assert_error(reveal_voter(&mut audit, vote, "voter-a", [0; 32]), AuditError::InvalidCommitment);
+    assert_error(reveal_voter(&mut audit, vote, "voter-b", SALT), AuditError::InvalidCommitment);
+    reveal_voter(&mut audit, vote, "voter-a", SALT).unwrap();
+    let record: VoteRecord = audit.bank.get(&vote);
+    assert_eq!(record.voter_id, "voter-a");
+    assert_eq!(audit.bank.data_len(&vote), VoteRecord::space("voter-a", &record.allocations));
+}
+
+#[test]
+fn voter_is_revealed_once() {
+    let (mut audit, vote) = committed();
+    reveal_voter(&mut audit, vote, "voter-a", SALT).unwrap();
+    assert_error(reveal_voter(&mut audit, vote, "voter-a", SALT), AuditError::VoterAlreadyRevealed);
+}
+
+#[test]
+fn plain_vote_has_nothing_to_reveal() {
+    let (mut audit, _) = committed();
+    let plain = audit.seed_vote(0, "voter-c");
+    assert_error(reveal_voter(&mut audit, plain, "voter-c", SALT), AuditError::InvalidCommitment);
+}
@@ -0,0 +1,240 @@
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator, Event};
+use chant_audit::{AuditError, Cell, CellRecorded, CellStatus, CellTombstone, Chant, Idea};
+use common::{assert_error, ix, Audit, Bank, TxResult};
+
+/// One cell of two ideas with two votes, its votes in ascending address order.
+fn voted() -> (Audit, Vec<Pubkey>) {
+    let mut audit = Audit::new();
+    let ideas = audit.seed_ideas(2);
+    audit.seed_cell(0, &ideas);
+    let mut votes = vec![audit.seed_vote(0, "voter-a"), audit.seed_vote(0, "voter-b")];
+    votes.sort();
+    (audit, votes)
+}
+
+/// `voted`, with the cell completed and tier 0's result recorded.
+fn closable() -> (Audit, Vec<Pubkey>) {
+    let (mut audit, votes) = voted();
+    audit.complete_cell(0);
+    audit.seed_tier_result(0, &[0], &[(0, 20), (1, 0)]);
+    (audit, votes)
+}
+
+#[test]
+fn closing_returns_rent_to_the_authority() {
+    let (mut audit, votes) = closable();
+    let cell = audit.cell(0);
+    let held = audit.bank.lamports(&cell) + votes.iter().map(|v| audit.bank.lamports(v)).sum::<u64>();
+    let before = audit.bank.lamports(&audit.authority);
+
+    audit.close_cell(0, &votes).unwrap();
+
+    // Everything but the tombstone's own rent comes back
+    let tombstone_rent = Bank::rent(CellTombstone::SPACE);
+    assert_eq!(audit.bank.lamports(&audit.authority), before + held - tombstone_rent);
+    assert_eq!(audit.bank.lamports(&cell), tombstone_rent);
+    assert_eq!(audit.bank.data_len(&cell), CellTombstone::SPACE);
+    for vote in &votes {
+        assert!(!audit.bank.exists(vote));
+    }
+}
+
+#[test]
+fn closed_cell_index_is_not_reused() {
+    let (mut audit, votes) = closable();
+    audit.close_cell(0, &votes).unwrap();
+
+    // The tombstone keeps the PDA allocated, so record_cell's init can't take
+    // it again, and it no longer reads as a Cell
+    let cell = audit.cell(0);
+    assert!(audit.bank.exists(&cell));
+    assert_eq!(audit.state().cell_count, 1);
+    let result = audit.bank.process(ix(
+        chant_audit::instruction::CompleteCell {},
+        chant_audit::accounts::CompleteCell { chant: audit.chant, cell, authority: audit.authority },
+    ));
+    assert_error(result, ErrorCode::AccountDiscriminatorMismatch);
+}
+
+#[test]
+fn voting_cell_cannot_be_closed() {
+    let (mut audit, votes) = voted();
+    audit.seed_tier_result(0, &[0], &[(0, 20), (1, 0)]);
+    assert_error(audit.close_cell(0, &votes), AuditError::CellNotCompleted);
+    assert_eq!(audit.bank.get::<Cell>(&audit.cell(0)).vote_count, 2);
+}
+
+#[test]
+fn cell_needs_its_tier_result() {
+    let (mut audit, votes) = voted();
+    audit.complete_cell(0);
+    assert_error(audit.close_cell(0, &votes), ErrorCode::AccountNotInitialized);
+}
+
+#[test]
+fn every_vote_closes_in_address_order() {
+    let (mut audit, votes) = closable();
+    assert_error(audit.close_cell(0, &votes[..1]), AuditError::InvalidVoteAccount);
+    assert_error(audit.close_cell(0, &[votes[1], votes[0]]), AuditError::InvalidVoteAccount);
+    audit.close_cell(0, &votes).unwrap();
+}
+
+#[test]
+fn cell_size_runs_from_pairs_to_panels_of_twelve() {
+    for size in [2, 7, 12] {
+        Chant::check_cell_size(size).unwrap();
+    }
+    for size in [0, 1, 13] {
+        assert_eq!(Chant::check_cell_size(size).unwrap_err(), AuditError::CellSizeOutOfBounds.into());
+    }
+
+    // A panel of twelve seats twelve ballots over twelve ideas
+    let mut audit = Audit::new();
+    audit.update(|chant| chant.cell_size = 12);
+    let ideas = audit.seed_ideas(12);
+    audit.seed_cell(0, &ideas);
+    let chant = audit.state();
+    let mut cell: Cell = audit.bank.get(&audit.cell(0));
+    for _ in 0..12 {
+        cell.add_points(&chant, chant.vote_budget).unwrap();
+    }
+    assert_eq!(cell.add_points(&chant, 1).unwrap_err(), AuditError::CellPointsOverflow.into());
+}
+
+#[test]
+fn continuous_flow_opens_tiers_side_by_side() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(4);
+    audit.seed_cell(1, &[0, 1]);
+
+    // Batch mode: tier 2 waits for tier 1's result
+    let mut chant = audit.state();
+    assert!(!chant.continuous_flow);
+    assert_eq!(chant.open_cell(2, 0).unwrap_err(), AuditError::TierOutOfSequence.into());
+    chant.open_cell(1, 0).unwrap();
+
+    audit.update(|chant| chant.continuous_flow = true);
+    let mut chant = audit.state();
+    chant.open_cell(2, 0).unwrap();
+    chant.open_cell(1, 1).unwrap();
+    assert_eq!(chant.open_tier, 2);
+    // Batches within a tier still only move forward
+    assert_eq!(chant.open_cell(1, 0).unwrap_err(), AuditError::NonMonotonicCell.into());
+}
+
+#[test]
+fn cells_are_recorded_in_tier_and_batch_order() {
+    let mut chant = Audit::new().state();
+    chant.open_cell(0, 0).unwrap();
+    // record_cell counts the cell after open_cell admits it
+    chant.cell_count = 1;
+    chant.open_cell(0, 0).unwrap();
+    chant.open_cell(0, 1).unwrap();
+    assert_eq!(chant.open_cell(0, 0).unwrap_err(), AuditError::NonMonotonicCell.into());
+    // Tier 3 can't open while tier 0 is running, nor skip tiers once it's done
+    assert_eq!(chant.open_cell(3, 0).unwrap_err(), AuditError::TierOutOfSequence.into());
+    chant.tier_closed[0] = true;
+    chant.tier_in_progress = false;
+    assert_eq!(chant.open_cell(0, 2).unwrap_err(), AuditError::TierAlreadyRecorded.into());
+    assert_eq!(chant.open_cell(3, 0).unwrap_err(), AuditError::TierOutOfSequence.into());
+    chant.open_cell(1, 0).unwrap();
+    assert_eq!(chant.open_tier, 1);
+}
+
+#[test]
+fn cell_event_carries_the_matchup() {
+    // record_cell can't run to its emit! here, so check the logged bytes directly
+    let ideas: Vec<u16> = (0..12).collect();
+    let event = CellRecorded {
+        chant: Pubkey::new_unique(),
+        cell_index: 4,
+        tier: 1,
+        batch: 2,
+        idea_indices: ideas.clone(),
+        voting_deadline: 0,
+    };
+    let data = event.data();
+    let logged = data.strip_prefix(CellRecorded::DISCRIMINATOR).unwrap();
+    let decoded = CellRecorded::deserialize(&mut &logged[..]).unwrap();
+    assert_eq!(decoded.idea_indices, ideas);
+    // A full twelve-idea cell stays well inside a log line
+    assert_eq!(data.len(), 8 + 32 + 2 + 1 + 1 + 4 + 2 * 12 + 8);
+}
+
+#[test]
+fn cell_members_are_distinct_recorded_ideas() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(4);
+    let chant = audit.state();
+    chant.check_cell_ideas(&[0, 1, 2, 3]).unwrap();
+    chant.check_cell_ideas(&[3, 0]).unwrap();
+    assert_eq!(chant.check_cell_ideas(&[0, 4]).unwrap_err(), AuditError::IdeaIndexOutOfRange.into());
+    assert_eq!(chant.check_cell_ideas(&[1, 2, 1]).unwrap_err(), AuditError::DuplicateCellIdea.into());
+}
+
+fn void_cell(audit: &mut Audit, cell_index: u16) -> TxResult {
+    let cell = audit.cell(cell_index);
+    let tier = audit.bank.get::<Cell>(&cell).tier;
+    audit.bank.process(ix(
+        chant_audit::instruction::VoidCell {},
+        chant_audit::accounts::VoidCell {
+            chant: audit.chant,
+            cell,
+            tier_result: audit.tier_result(tier),
+            authority: audit.authority,
+        },
+    ))
+}
+
+#[test]
+fn voided_cell_is_rerun_by_its_replacement() {
+    let (mut audit, _) = voted();
+    void_cell(&mut audit, 0).unwrap();
+    let voided: Cell = audit.bank.get(&audit.cell(0));
+    assert_eq!(voided.status, CellStatus::Voided as u8);
+    assert_eq!(audit.state().tier_cell_counts[0], 0);
+    assert_error(void_cell(&mut audit, 0), AuditError::CellAlreadyVoided);
+    assert_eq!(voided.require_accepting_votes(audit.bank.now()).unwrap_err(), AuditError::CellNotVoting.into());
+
+    // The replacement takes the same ideas in the still-open tier
+    let mut chant = audit.state();
+    chant.check_cell_ideas(&voided.idea_indices).unwrap();
+    chant.open_cell(0, 0).unwrap();
+    audit.seed_cell(0, &voided.idea_indices);
+    let replacement: Cell = audit.bank.get(&audit.cell(1));
+    let winner: Idea = audit.bank.get(&audit.idea(0));
+
+    // Only the replacement backs advancement; the voided cell's votes don't count
+    let chant = audit.state();
+    chant.check_advancement(audit.chant, 0, &[replacement], std::slice::from_ref(&winner), &[0]).unwrap();
+    let result = chant.check_advancement(audit.chant, 0, &[voided], &[winner], &[0]);
+    assert_eq!(result.unwrap_err(), AuditError::InvalidAdvancement.into());
+}
+
+#[test]
+fn recorded_tier_cannot_void_its_cells() {
+    let (mut audit, _) = closable();
+    assert_error(void_cell(&mut audit, 0), AuditError::TierAlreadyRecorded);
+}
+
+#[test]
+fn idea_counts_each_cell_it_joins() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(3);
+    let mut idea: Idea = audit.bank.get(&audit.idea(1));
+    assert_eq!(idea.cell_appearances, 0);
+
+    // A tier-0 cell, then a tier-1 cell after advancing
+    idea.join_cell(audit.chant, 1).unwrap();
+    idea.join_cell(audit.chant, 1).unwrap();
+    assert_eq!(idea.cell_appearances, 2);
+
+    // Passed in the wrong slot, or from another chant, it isn't counted
+    assert_eq!(idea.join_cell(audit.chant, 2).unwrap_err(), AuditError::IndexMismatch.into());
+    assert_eq!(idea.join_cell(Pubkey::new_unique(), 1).unwrap_err(), AuditError::IndexMismatch.into());
+    assert_eq!(idea.cell_appearances, 2);
+}
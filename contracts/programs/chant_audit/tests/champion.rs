@@ -0,0 +1,143 @@
+//! The chant's result: the Champion and the ranked Podium.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use chant_audit::{AuditError, Champion, PodiumPlace};
+use common::*;
+
+fn declare_champion(audit: &mut Audit, idea_index: u16) -> TxResult {
+    let tier = audit.state().current_tier;
+    declare_champion_against(audit, idea_index, tier)
+}
+
+/// declare_champion, passing `tier`'s result as the final one.
+fn declare_champion_against(audit: &mut Audit, idea_index: u16, tier: u8) -> TxResult {
+    audit.bank.process(ix(
+        chant_audit::instruction::DeclareChampion {
+            idea_index,
+            text_hash: [7; 32],
+            total_tiers: tier + 1,
+            total_voters: 5,
+            winner_wallet: Pubkey::new_unique(),
+            vote_root: [1; 32],
+        },
+        chant_audit::accounts::DeclareChampion {
+            chant: audit.chant,
+            champion: audit.champion(),
+            podium: audit.podium(),
+            tier_result: audit.tier_result(tier),
+            authority: audit.authority,
+            system_program: system_program::ID,
+        },
+    ))
+}
+
+fn declare_podium(audit: &mut Audit, places: Vec<PodiumPlace>) -> TxResult {
+    audit.bank.process(ix(
+        chant_audit::instruction::DeclarePodium { places },
+        chant_audit::accounts::DeclarePodium {
+            chant: audit.chant,
+            podium: audit.podium(),
+            champion: audit.champion(),
+            authority: audit.authority,
+            system_program: system_program::ID,
+        },
+    ))
+}
+
+/// Five ideas through one tier; idea 2 leads on XP, then 1, then 0.
+fn decided() -> Audit {
+    let mut audit = Audit::new();
+    let ideas = audit.seed_ideas(5);
+    audit.seed_cell(0, &ideas);
+    audit.seed_tier_result(0, &[2], &[(2, 30), (1, 20), (0, 10), (3, 5), (4, 0)]);
+    audit
+}
+
+#[test]
+fn three_place_podium_is_declared() {
+    let mut audit = decided();
+    assert_reaches_cpi(declare_podium(&mut audit, places(&[2, 1, 0])));
+}
+
+#[test]
+fn podium_ranks_are_one_to_n() {
+    let mut audit = decided();
+    let mut duplicate_rank = places(&[2, 1, 0]);
+    duplicate_rank[2].rank = 2;
+    assert_error(declare_podium(&mut audit, duplicate_rank), AuditError::DuplicatePodiumRank);
+
+    let mut gap = places(&[2, 1]);
+    gap[1].rank = 3;
+    assert_error(declare_podium(&mut audit, gap), AuditError::DuplicatePodiumRank);
+
+    assert_error(declare_podium(&mut audit, places(&[2, 1, 2])), AuditError::DuplicatePodiumIdea);
+    assert_error(declare_podium(&mut audit, vec![]), AuditError::InvalidPodiumSize);
+}
+
+#[test]
+fn podium_first_place_must_be_the_champion() {
+    let mut audit = decided();
+    audit.seed_champion(2);
+    assert_error(declare_podium(&mut audit, places(&[1, 2, 0])), AuditError::PodiumChampionMismatch);
+    assert_reaches_cpi(declare_podium(&mut audit, places(&[2, 1, 0])));
+}
+
+#[test]
+fn champion_must_be_the_podium_first_place() {
+    let mut audit = decided();
+    audit.seed_podium(&[2, 1, 0]);
+    assert_error(declare_champion(&mut audit, 1), AuditError::PodiumChampionMismatch);
+    assert_reaches_cpi(declare_champion(&mut audit, 2));
+}
+
+#[test]
+fn disqualified_idea_cannot_be_champion() {
+    let mut audit = decided();
+    audit.disqualify_idea(2).unwrap();
+    assert_error(declare_champion(&mut audit, 2), AuditError::AlreadyDisqualified);
+}
+
+/// `decided`, then ideas 2 and 1 through tier 1, where 1 overtakes 2.
+fn two_tiers() -> Audit {
+    let mut audit = decided();
+    audit.seed_cell(1, &[2, 1]);
+    audit.seed_tier_result(1, &[1], &[(1, 12), (2, 10)]);
+    audit
+}
+
+#[test]
+fn champion_margin_comes_from_the_final_tier() {
+    let mut audit = two_tiers();
+    // Idea 2 led tier 0 but trails in tier 1
+    assert_error(declare_champion(&mut audit, 2), AuditError::MarginDataMismatch);
+    // Idea 0 never reached tier 1
+    assert_error(declare_champion(&mut audit, 0), AuditError::MarginDataMismatch);
+    assert_reaches_cpi(declare_champion(&mut audit, 1));
+}
+
+#[test]
+fn earlier_tier_result_is_not_the_final_one() {
+    let mut audit = two_tiers();
+    assert_error(declare_champion_against(&mut audit, 2, 0), anchor_lang::error::ErrorCode::ConstraintSeeds);
+
+    // A late result for tier 0 leaves tier 1 as the final tier
+    audit.seed_tier_result(0, &[2], &[(2, 30), (1, 20)]);
+    assert_eq!(audit.state().current_tier, 1);
+    assert_error(declare_champion_against(&mut audit, 2, 0), anchor_lang::error::ErrorCode::ConstraintSeeds);
+}
+
+#[test]
+fn champion_is_declared_once() {
+    let mut audit = decided();
+    audit.seed_champion(2);
+    let declared: Champion = audit.bank.get(&audit.champion());
+
+    // A second declaration gets the domain error, not an account-in-use failure
+    assert_error(declare_champion(&mut audit, 2), AuditError::ChampionAlreadyDeclared);
+    let champion: Champion = audit.bank.get(&audit.champion());
+    assert_eq!((champion.idea_index, champion.declared_at), (declared.idea_index, declared.declared_at));
+    assert_eq!(audit.state().phase, 3);
+}
@@ -0,0 +1,685 @@
+//! In-process harness for the chant_audit tests.
+//!
+//! There's no validator here: accounts live in a map and each instruction runs
+//! through the program's Anchor `entry`, from an input buffer laid out the way
+//! the loader lays it out (so `close =` and realloc behave as on-chain). The
+//! syscall stubs only supply Clock and Rent. Anchor's CPI is unavailable off
+//! the SBF target: an instruction that gets as far as creating an account
+//! fails with `REACHED_CPI` instead, so its checks are still exercised, and
+//! the accounts it would create are seeded directly in the state it leaves.
+//! A failed instruction leaves every account untouched.
+
+#![allow(dead_code)]
+
+use std::cell::Cell as StdCell;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::{deserialize, MAX_PERMITTED_DATA_INCREASE};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::InstructionData;
+use chant_audit::{
+    Allocation, AuthorStats, Cell, CellStatus, Champion, Chant, ExportCheckpoint, Idea, IdeaStatus, Podium,
+    PodiumPlace, TierResult, VoteRecord, XpEntry,
+};
+use solana_sysvar::program_stubs::{set_syscall_stubs, SyscallStubs};
+
+pub type TxResult = std::result::Result<(), ProgramError>;
+
+/// Start of every test's clock.
+pub const T0: i64 = 1_700_000_000;
+
+/// What `Bank::process` returns for an instruction that passed its checks and
+/// went on to a CPI (account creation, transfers), which can't run here.
+pub const REACHED_CPI: u32 = u32::MAX;
+
+const CPI_PANIC: &str = "only supported with `target_os = \"solana\"";
+
+thread_local! {
+    static NOW: StdCell<i64> = const { StdCell::new(T0) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock { slot: 1, unix_timestamp: NOW.with(StdCell::get), ..Clock::default() };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        0
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap_or("")
+}
+
+#[derive(Clone)]
+pub struct AccountData {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+impl Default for AccountData {
+    fn default() -> Self {
+        Self { lamports: 0, data: Vec::new(), owner: system_program::ID, executable: false }
+    }
+}
+
+/// Accounts by address. Programs are executable placeholders.
+pub struct Bank {
+    accounts: HashMap<Pubkey, AccountData>,
+}
+
+impl Bank {
+    pub fn new() -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(Stubs));
+            // A CPI panic is an expected outcome (REACHED_CPI), not a failure to report
+            let default_hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                if !panic_message(info.payload()).contains(CPI_PANIC) {
+                    default_hook(info);
+                }
+            }));
+        });
+        NOW.with(|now| now.set(T0));
+
+        let mut bank = Bank { accounts: HashMap::new() };
+        for program in [chant_audit::ID, system_program::ID] {
+            bank.accounts.insert(
+                program,
+                AccountData { lamports: 1, executable: true, ..AccountData::default() },
+            );
+        }
+        bank
+    }
+
+    pub fn now(&self) -> i64 {
+        NOW.with(StdCell::get)
+    }
+
+    pub fn set_time(&mut self, unix_timestamp: i64) {
+        NOW.with(|now| now.set(unix_timestamp));
+    }
+
+    pub fn rent(space: usize) -> u64 {
+        Rent::default().minimum_balance(space)
+    }
+
+    pub fn exists(&self, key: &Pubkey) -> bool {
+        self.accounts.get(key).is_some_and(|a| a.lamports > 0)
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.accounts.get(key).map_or(0, |a| a.lamports)
+    }
+
+    pub fn data_len(&self, key: &Pubkey) -> usize {
+        self.accounts.get(key).map_or(0, |a| a.data.len())
+    }
+
+    /// A funded system-owned wallet.
+    pub fn wallet(&mut self, lamports: u64) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.airdrop(&key, lamports);
+        key
+    }
+
+    pub fn airdrop(&mut self, key: &Pubkey, lamports: u64) {
+        self.accounts.entry(*key).or_default().lamports += lamports;
+    }
+
+    /// Raw account data, discriminator included.
+    pub fn data(&self, key: &Pubkey) -> Vec<u8> {
+        self.accounts.get(key).unwrap_or_else(|| panic!("no account {key}")).data.clone()
+    }
+
+    /// Deserialize an Anchor account (discriminator checked).
+    pub fn get<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.accounts.get(key).unwrap_or_else(|| panic!("no account {key}"));
+        T::try_deserialize(&mut &account.data[..]).expect("account deserializes")
+    }
+
+    /// Write an Anchor account owned by chant_audit: `space` bytes (zero-padded),
+    /// rent-exempt.
+    pub fn put<T: AccountSerialize>(&mut self, key: Pubkey, account: &T, space: usize) {
+        let mut data = Vec::with_capacity(space);
+        account.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= space, "account larger than its space");
+        data.resize(space, 0);
+        let lamports = Self::rent(space);
+        self.accounts.insert(key, AccountData { lamports, data, owner: chant_audit::ID, executable: false });
+    }
+
+    /// Rewrite an existing Anchor account in place, keeping its size and lamports.
+    pub fn update<T: AccountSerialize + AccountDeserialize>(&mut self, key: &Pubkey, f: impl FnOnce(&mut T)) {
+        let mut account: T = self.get(key);
+        f(&mut account);
+        let data = &mut self.accounts.get_mut(key).unwrap().data;
+        let mut serialized = Vec::with_capacity(data.len());
+        account.try_serialize(&mut serialized).unwrap();
+        assert!(serialized.len() <= data.len(), "account outgrew its space");
+        data[..serialized.len()].copy_from_slice(&serialized);
+    }
+
+    pub fn process(&mut self, ix: Instruction) -> TxResult {
+        // Unique accounts in first-seen order, with merged signer / writable flags
+        let mut keys: Vec<Pubkey> = Vec::new();
+        let mut flags: Vec<(bool, bool)> = Vec::new();
+        let mut slots: Vec<usize> = Vec::new();
+        for meta in &ix.accounts {
+            match keys.iter().position(|k| *k == meta.pubkey) {
+                Some(i) => {
+                    flags[i].0 |= meta.is_signer;
+                    flags[i].1 |= meta.is_writable;
+                    slots.push(i);
+                }
+                None => {
+                    keys.push(meta.pubkey);
+                    flags.push((meta.is_signer, meta.is_writable));
+                    slots.push(keys.len() - 1);
+                }
+            }
+        }
+        let lamports_before: u64 = keys.iter().map(|k| self.lamports(k)).sum();
+
+        let mut input = Vec::<u8>::new();
+        input.extend_from_slice(&(ix.accounts.len() as u64).to_le_bytes());
+        let mut seen = vec![false; keys.len()];
+        for &i in &slots {
+            if std::mem::replace(&mut seen[i], true) {
+                input.push(i as u8);
+                input.extend_from_slice(&[0; 7]);
+                continue;
+            }
+            let account = self.accounts.get(&keys[i]).cloned().unwrap_or_default();
+            input.extend_from_slice(&[0xff, flags[i].0 as u8, flags[i].1 as u8, account.executable as u8]);
+            input.extend_from_slice(&[0; 4]);
+            input.extend_from_slice(keys[i].as_ref());
+            input.extend_from_slice(account.owner.as_ref());
+            input.extend_from_slice(&account.lamports.to_le_bytes());
+            input.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            input.extend_from_slice(&account.data);
+            input.resize(input.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            input.resize(input.len().next_multiple_of(8), 0);
+            input.extend_from_slice(&u64::MAX.to_le_bytes());
+        }
+        input.extend_from_slice(&(ix.data.len() as u64).to_le_bytes());
+        input.extend_from_slice(&ix.data);
+        input.extend_from_slice(ix.program_id.as_ref());
+
+        // u64-backed so every field the loader reads is aligned
+        let mut buffer = vec![0u64; input.len().div_ceil(8)];
+        let bytes = unsafe { std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, input.len()) };
+        bytes.copy_from_slice(&input);
+
+        let (program_id, infos, data) = unsafe { deserialize(buffer.as_mut_ptr() as *mut u8) };
+        if *program_id != chant_audit::ID {
+            return Err(ProgramError::IncorrectProgramId);
+        }
+        match panic::catch_unwind(AssertUnwindSafe(|| chant_audit::entry(program_id, &infos, data))) {
+            Ok(result) => result?,
+            Err(payload) if panic_message(payload.as_ref()).contains(CPI_PANIC) => {
+                return Err(ProgramError::Custom(REACHED_CPI));
+            }
+            Err(payload) => panic::resume_unwind(payload),
+        }
+
+        let mut seen = vec![false; keys.len()];
+        let mut updates = Vec::new();
+        for (info, &i) in infos.iter().zip(&slots) {
+            if std::mem::replace(&mut seen[i], true) {
+                continue;
+            }
+            let account = AccountData {
+                lamports: info.lamports(),
+                data: info.data.borrow().to_vec(),
+                owner: *info.owner,
+                executable: info.executable,
+            };
+            updates.push((keys[i], account));
+        }
+        let lamports_after: u64 = updates.iter().map(|(_, a)| a.lamports).sum();
+        assert_eq!(lamports_before, lamports_after, "instruction created or destroyed lamports");
+        for (key, account) in updates {
+            self.accounts.insert(key, account);
+        }
+        Ok(())
+    }
+}
+
+/// Assert that `result` failed with the given program error code.
+#[track_caller]
+pub fn assert_error(result: TxResult, code: impl Into<u32>) {
+    let code = code.into();
+    match result {
+        Err(ProgramError::Custom(actual)) if actual == code => {}
+        other => panic!("expected custom error {code}, got {other:?}"),
+    }
+}
+
+/// Assert that the instruction passed every check and went on to create or
+/// fund an account (see REACHED_CPI).
+#[track_caller]
+pub fn assert_reaches_cpi(result: TxResult) {
+    assert_error(result, REACHED_CPI);
+}
+
+pub fn ix(data: impl InstructionData, accounts: impl ToAccountMetas) -> Instruction {
+    Instruction { program_id: chant_audit::ID, accounts: accounts.to_account_metas(None), data: data.data() }
+}
+
+pub fn pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &chant_audit::ID)
+}
+
+/// A chant under a fresh authority, with helpers that seed the accounts
+/// CPI-bound instructions create and run the CPI-free ones.
+pub struct Audit {
+    pub bank: Bank,
+    pub authority: Pubkey,
+    pub chant: Pubkey,
+}
+
+impl Audit {
+    pub const CHANT_ID: &'static str = "chant-1";
+    pub const QUESTION: &'static str = "What should we build?";
+
+    /// A Voting chant with cells of 5, as initialize_chant leaves it.
+    pub fn new() -> Self {
+        let mut bank = Bank::new();
+        let authority = bank.wallet(1_000_000_000);
+        let (chant, bump) = pda(&[b"chant", Self::CHANT_ID.as_bytes()]);
+        let state = Chant {
+            authority,
+            chant_id: Self::CHANT_ID.to_string(),
+            question: Self::QUESTION.to_string(),
+            cell_size: 5,
+            continuous_flow: false,
+            phase: 1,
+            current_tier: 0,
+            idea_count: 0,
+            cell_count: 0,
+            submission_deadline: 0,
+            vote_budget: 10,
+            paused: false,
+            max_ideas_per_author: 0,
+            live_idea_count: 0,
+            disqualified_indices: vec![],
+            open_tier: 0,
+            tier_in_progress: false,
+            secret_ballot: false,
+            tier_cell_counts: [0; 32],
+            max_ideas: 0,
+            transcript_hash: [0; 32],
+            tier_started_at: [0; 32],
+            tier_last_batch: [0; 32],
+            tier_closed: [false; 32],
+            created_at: T0,
+            bump,
+        };
+        bank.put(chant, &state, Chant::space(Self::CHANT_ID, Self::QUESTION));
+        Audit { bank, authority, chant }
+    }
+
+    pub fn state(&self) -> Chant {
+        self.bank.get(&self.chant)
+    }
+
+    pub fn update(&mut self, f: impl FnOnce(&mut Chant)) {
+        self.bank.update(&self.chant, f);
+    }
+
+    pub fn idea(&self, index: u16) -> Pubkey {
+        pda(&[b"idea", self.chant.as_ref(), &index.to_le_bytes()]).0
+    }
+
+    pub fn cell(&self, index: u16) -> Pubkey {
+        pda(&[b"cell", self.chant.as_ref(), &index.to_le_bytes()]).0
+    }
+
+    pub fn tier_result(&self, tier: u8) -> Pubkey {
+        pda(&[b"tier", self.chant.as_ref(), &[tier]]).0
+    }
+
+    pub fn champion(&self) -> Pubkey {
+        pda(&[b"champion", self.chant.as_ref()]).0
+    }
+
+    pub fn podium(&self) -> Pubkey {
+        pda(&[b"podium", self.chant.as_ref()]).0
+    }
+
+    /// The next idea, as record_idea leaves it.
+    pub fn seed_idea(&mut self, text: &str) -> u16 {
+        let index = self.state().idea_count;
+        let (key, bump) = pda(&[b"idea", self.chant.as_ref(), &index.to_le_bytes()]);
+        let author_id = format!("author-{index}");
+        let idea = Idea {
+            chant: self.chant,
+            index,
+            text: text.to_string(),
+            author_id: author_id.clone(),
+            status: IdeaStatus::Submitted as u8,
+            tier: 0,
+            total_xp: 0,
+            created_at: self.bank.now(),
+            author_pubkey: Pubkey::default(),
+            last_xp_tier: None,
+            cell_appearances: 0,
+            bump,
+        };
+        self.bank.put(key, &idea, Idea::space(text, &author_id));
+        self.update(|chant| {
+            chant.idea_count += 1;
+            chant.live_idea_count += 1;
+        });
+        index
+    }
+
+    /// `count` ideas, returning their indices.
+    pub fn seed_ideas(&mut self, count: u16) -> Vec<u16> {
+        (0..count).map(|i| self.seed_idea(&format!("Idea {i}"))).collect()
+    }
+
+    /// The next cell, at `tier`, holding `ideas`, as record_cell leaves it.
+    pub fn seed_cell(&mut self, tier: u8, ideas: &[u16]) -> u16 {
+        let index = self.state().cell_count;
+        let (key, bump) = pda(&[b"cell", self.chant.as_ref(), &index.to_le_bytes()]);
+        let now = self.bank.now();
+        let cell = Cell {
+            chant: self.chant,
+            index,
+            tier,
+            batch: 0,
+            status: CellStatus::Voting as u8,
+            idea_indices: ideas.to_vec(),
+            voter_count: 0,
+            vote_count: 0,
+            total_points: 0,
+            voting_deadline: 0,
+            created_at: now,
+            bump,
+        };
+        self.bank.put(key, &cell, Cell::space(ideas));
+        for &idea in ideas {
+            self.bank.update(&self.idea(idea), |idea: &mut Idea| {
+                idea.status = IdeaStatus::InVoting as u8;
+                idea.cell_appearances += 1;
+            });
+        }
+        self.update(|chant| {
+            chant.count_cell(tier, now).unwrap();
+            chant.open_tier = chant.open_tier.max(tier);
+            chant.tier_in_progress = true;
+        });
+        index
+    }
+
+    /// `voter_id`'s vote in cell `cell_index`, all points on the cell's first
+    /// idea, as record_vote leaves it.
+    pub fn seed_vote(&mut self, cell_index: u16, voter_id: &str) -> Pubkey {
+        let cell = self.cell(cell_index);
+        let (key, bump) = pda(&[b"vote", cell.as_ref(), voter_id.as_bytes()]);
+        let state = self.state();
+        let first_idea = self.bank.get::<Cell>(&cell).idea_indices[0];
+        let allocations = vec![Allocation { idea_index: first_idea, points: state.vote_budget as u8 }];
+        let vote = VoteRecord {
+            cell,
+            voter_id: voter_id.to_string(),
+            allocations: allocations.clone(),
+            voted_at: self.bank.now(),
+            version: 0,
+            superseded: false,
+            voter_commitment: [0; 32],
+            bump,
+        };
+        self.bank.put(key, &vote, VoteRecord::space(voter_id, &allocations));
+        self.bank.update(&cell, |cell: &mut Cell| {
+            cell.voter_count += 1;
+            cell.vote_count += 1;
+            cell.total_points += state.vote_budget as u32;
+        });
+        key
+    }
+
+    /// A secret-ballot vote in cell `cell_index` under `voter_commitment`, voter
+    /// not yet revealed, as record_committed_vote leaves it.
+    pub fn seed_committed_vote(&mut self, cell_index: u16, voter_commitment: [u8; 32]) -> Pubkey {
+        let cell = self.cell(cell_index);
+        let (key, bump) = pda(&[b"vote", cell.as_ref(), voter_commitment.as_ref()]);
+        let state = self.state();
+        let first_idea = self.bank.get::<Cell>(&cell).idea_indices[0];
+        let allocations = vec![Allocation { idea_index: first_idea, points: state.vote_budget as u8 }];
+        let vote = VoteRecord {
+            cell,
+            voter_id: String::new(),
+            allocations: allocations.clone(),
+            voted_at: self.bank.now(),
+            version: 0,
+            superseded: false,
+            voter_commitment,
+            bump,
+        };
+        self.bank.put(key, &vote, VoteRecord::space("", &allocations));
+        self.bank.update(&cell, |cell: &mut Cell| {
+            cell.voter_count += 1;
+            cell.vote_count += 1;
+            cell.total_points += state.vote_budget as u32;
+        });
+        key
+    }
+
+    /// record_idea for the next idea. The author's stats account is seeded
+    /// empty the first time, as init_if_needed would create it; an idea that
+    /// passes every check then fails with REACHED_CPI.
+    pub fn record_idea(&mut self, idea_index: u16, author_id: &str) -> TxResult {
+        self.record_idea_by(idea_index, author_id, None)
+    }
+
+    /// record_idea with `author` passed as the co-signing author wallet.
+    pub fn record_idea_by(&mut self, idea_index: u16, author_id: &str, author: Option<Pubkey>) -> TxResult {
+        let (author_stats, bump) = pda(&[b"author", self.chant.as_ref(), author_id.as_bytes()]);
+        if !self.bank.exists(&author_stats) {
+            let empty = AuthorStats { chant: Pubkey::default(), author_id: String::new(), idea_count: 0, bump };
+            self.bank.put(author_stats, &empty, AuthorStats::SPACE);
+        }
+        self.bank.process(ix(
+            chant_audit::instruction::RecordIdea {
+                idea_index,
+                text: "An idea".to_string(),
+                author_id: author_id.to_string(),
+            },
+            chant_audit::accounts::RecordIdea {
+                chant: self.chant,
+                idea: self.idea(idea_index),
+                author_stats,
+                authority: self.authority,
+                author,
+                system_program: system_program::ID,
+            },
+        ))
+    }
+
+    pub fn disqualify_idea(&mut self, idea_index: u16) -> TxResult {
+        // disqualify_idea grows the chant by one index; pre-fund the larger rent
+        self.bank.airdrop(&self.chant, 1_000_000);
+        self.bank.process(ix(
+            chant_audit::instruction::DisqualifyIdea { idea_index },
+            chant_audit::accounts::DisqualifyIdea {
+                chant: self.chant,
+                idea: self.idea(idea_index),
+                authority: self.authority,
+                system_program: system_program::ID,
+            },
+        ))
+    }
+
+    /// Mark cell `cell_index` Completed, as complete_cell leaves it.
+    pub fn complete_cell(&mut self, cell_index: u16) {
+        self.bank.update(&self.cell(cell_index), |cell: &mut Cell| {
+            cell.status = CellStatus::Completed as u8;
+        });
+    }
+
+    /// close_cell on cell `cell_index`, closing `votes` into it.
+    pub fn close_cell(&mut self, cell_index: u16, votes: &[Pubkey]) -> TxResult {
+        let cell = self.cell(cell_index);
+        let tier = self.bank.get::<Cell>(&cell).tier;
+        let mut ix = ix(
+            chant_audit::instruction::CloseCell {},
+            chant_audit::accounts::CloseCell {
+                chant: self.chant,
+                cell,
+                tier_result: self.tier_result(tier),
+                authority: self.authority,
+            },
+        );
+        ix.accounts.extend(votes.iter().map(|key| AccountMeta::new(*key, false)));
+        self.bank.process(ix)
+    }
+
+    pub fn checkpoint(&self, checkpoint_id: u32) -> Pubkey {
+        pda(&[b"export", self.chant.as_ref(), &checkpoint_id.to_le_bytes()]).0
+    }
+
+    /// export_root folding `accounts`. The checkpoint is seeded empty the
+    /// first time, as init_if_needed would create it.
+    pub fn export_root(&mut self, checkpoint_id: u32, accounts: &[Pubkey], seal: bool) -> TxResult {
+        let checkpoint = self.checkpoint(checkpoint_id);
+        if !self.bank.exists(&checkpoint) {
+            let empty = ExportCheckpoint {
+                chant: Pubkey::default(),
+                checkpoint_id: 0,
+                root: [0; 32],
+                item_count: 0,
+                sealed: false,
+                created_at: 0,
+                sealed_at: 0,
+                stage: 0,
+                next_idea: 0,
+                next_cell: 0,
+                last_cell: Pubkey::default(),
+                last_vote: Pubkey::default(),
+                next_tier: 0,
+                votes_left: 0,
+                tier_results: 0,
+                bump: 0,
+            };
+            self.bank.put(checkpoint, &empty, ExportCheckpoint::SPACE);
+        }
+        let mut ix = ix(
+            chant_audit::instruction::ExportRoot { checkpoint_id, seal },
+            chant_audit::accounts::ExportRoot {
+                chant: self.chant,
+                checkpoint,
+                champion: self.champion(),
+                authority: self.authority,
+                system_program: system_program::ID,
+            },
+        );
+        ix.accounts.extend(accounts.iter().map(|key| AccountMeta::new_readonly(*key, false)));
+        self.bank.process(ix)
+    }
+
+    /// `tier`'s result, as record_tier_result leaves it.
+    pub fn seed_tier_result(&mut self, tier: u8, advancing: &[u16], xp: &[(u16, u16)]) {
+        let (key, bump) = pda(&[b"tier", self.chant.as_ref(), &[tier]]);
+        let xp_totals: Vec<XpEntry> =
+            xp.iter().map(|&(idea_index, total_xp)| XpEntry { idea_index, total_xp }).collect();
+        let state = self.state();
+        let result = TierResult {
+            chant: self.chant,
+            tier,
+            advancing_indices: advancing.to_vec(),
+            cell_count: state.tier_cell_counts[tier as usize],
+            started_at: state.tier_started_at[tier as usize],
+            completed_at: self.bank.now(),
+            bump,
+            xp_totals: xp_totals.clone(),
+        };
+        self.bank.put(key, &result, TierResult::space(advancing, &xp_totals));
+        self.update(|chant| {
+            chant.current_tier = chant.current_tier.max(tier);
+            chant.tier_closed[tier as usize] = true;
+            if tier == chant.open_tier {
+                chant.tier_in_progress = false;
+            }
+        });
+    }
+
+    /// The Champion, as declare_champion leaves it.
+    pub fn seed_champion(&mut self, idea_index: u16) {
+        let (key, bump) = pda(&[b"champion", self.chant.as_ref()]);
+        let champion = Champion {
+            chant: self.chant,
+            idea_index,
+            text_hash: [7; 32],
+            total_tiers: 1,
+            total_voters: 5,
+            winner_wallet: Pubkey::new_unique(),
+            vote_root: [1; 32],
+            declared_at: self.bank.now(),
+            winning_xp: 30,
+            runner_up_xp: 12,
+            bump,
+        };
+        self.bank.put(key, &champion, Champion::SPACE);
+        self.update(|chant| chant.phase = 3);
+    }
+
+    /// The Podium, as declare_podium leaves it; `ideas` in rank order.
+    pub fn seed_podium(&mut self, ideas: &[u16]) {
+        let (key, bump) = pda(&[b"podium", self.chant.as_ref()]);
+        let podium = Podium {
+            chant: self.chant,
+            places: places(ideas),
+            declared_at: self.bank.now(),
+            bump,
+        };
+        self.bank.put(key, &podium, Podium::space(ideas.len()));
+        self.update(|chant| chant.phase = 3);
+    }
+
+    pub fn pause(&mut self) -> TxResult {
+        let accounts = self.chant_action();
+        self.bank.process(ix(chant_audit::instruction::PauseChant {}, accounts))
+    }
+
+    pub fn unpause(&mut self) -> TxResult {
+        let accounts = self.chant_action();
+        self.bank.process(ix(chant_audit::instruction::UnpauseChant {}, accounts))
+    }
+
+    pub fn chant_action(&self) -> chant_audit::accounts::ChantAction {
+        chant_audit::accounts::ChantAction { chant: self.chant, authority: self.authority }
+    }
+}
+
+/// Podium places for `ideas`, ranked in order.
+pub fn places(ideas: &[u16]) -> Vec<PodiumPlace> {
+    ideas
+        .iter()
+        .enumerate()
+        .map(|(i, &idea_index)| PodiumPlace { rank: i as u8 + 1, idea_index, text_hash: [7; 32] })
+        .collect()
+}
@@ -0,0 +1,150 @@
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use chant_audit::hash::{node_hash, tagged_hash, CHANT_TAG, LEAF_TAG};
+use chant_audit::{AuditError, CellTombstone, ExportCheckpoint};
+use common::{assert_error, ix, Audit, Bank, TxResult};
+
+/// Two ideas in one completed cell with two votes, and tier 0's result.
+/// Returns the votes in ascending address order.
+fn decided() -> (Audit, Vec<Pubkey>) {
+    let mut audit = Audit::new();
+    let ideas = audit.seed_ideas(2);
+    audit.seed_cell(0, &ideas);
+    let mut votes = vec![audit.seed_vote(0, "voter-a"), audit.seed_vote(0, "voter-b")];
+    votes.sort();
+    audit.complete_cell(0);
+    audit.seed_tier_result(0, &[0], &[(0, 20), (1, 0)]);
+    (audit, votes)
+}
+
+/// Every account of `decided`, in canonical export order.
+fn export_order(audit: &Audit, votes: &[Pubkey]) -> Vec<Pubkey> {
+    let mut accounts = vec![audit.idea(0), audit.idea(1), audit.cell(0)];
+    accounts.extend_from_slice(votes);
+    accounts.push(audit.tier_result(0));
+    accounts
+}
+
+/// The running root over `accounts`, computed from their raw data the way a
+/// client would.
+fn client_root(bank: &Bank, chant: &Pubkey, accounts: &[Pubkey]) -> [u8; 32] {
+    let mut root = tagged_hash(CHANT_TAG, &[&bank.data(chant)]);
+    for key in accounts {
+        root = node_hash(&root, &tagged_hash(LEAF_TAG, &[&bank.data(key)]));
+    }
+    root
+}
+
+#[test]
+fn sealed_root_matches_a_client_computed_root() {
+    let (mut audit, votes) = decided();
+    let accounts = export_order(&audit, &votes);
+    let expected = client_root(&audit.bank, &audit.chant, &accounts);
+
+    // Fold the ideas in one call and the rest in another: the root is the same
+    audit.export_root(1, &accounts[..2], false).unwrap();
+    audit.export_root(1, &accounts[2..], true).unwrap();
+
+    let checkpoint: ExportCheckpoint = audit.bank.get(&audit.checkpoint(1));
+    assert!(checkpoint.sealed);
+    assert_eq!(checkpoint.item_count, 6);
+    assert_eq!(checkpoint.root, expected);
+
+    // The vote's own leaf is part of it: any other vote data gives another root
+    let mut forged = audit.bank.data(&votes[0]);
+    *forged.last_mut().unwrap() ^= 1;
+    let mut root = tagged_hash(CHANT_TAG, &[&audit.bank.data(&audit.chant)]);
+    for key in &accounts {
+        let data = if *key == votes[0] { forged.clone() } else { audit.bank.data(key) };
+        root = node_hash(&root, &tagged_hash(LEAF_TAG, &[&data]));
+    }
+    assert_ne!(root, checkpoint.root);
+}
+
+#[test]
+fn seal_needs_every_vote() {
+    let (mut audit, votes) = decided();
+    audit.export_root(1, &[audit.idea(0), audit.idea(1), audit.cell(0), votes[0]], false).unwrap();
+
+    // The tier result can't come before the cell's last vote
+    assert_error(
+        audit.export_root(1, &[audit.tier_result(0)], false),
+        AuditError::ExportOutOfOrder,
+    );
+    assert_error(audit.export_root(1, &[], true), AuditError::ExportIncomplete);
+
+    audit.export_root(1, &[votes[1], audit.tier_result(0)], true).unwrap();
+}
+
+#[test]
+fn votes_follow_their_cell_in_address_order() {
+    let (mut audit, votes) = decided();
+    audit.export_root(1, &[audit.idea(0), audit.idea(1), audit.cell(0)], false).unwrap();
+    assert_error(audit.export_root(1, &[votes[1], votes[0]], false), AuditError::ExportOutOfOrder);
+}
+
+#[test]
+fn seal_needs_every_closed_tier_result() {
+    let (mut audit, votes) = decided();
+    let mut accounts = export_order(&audit, &votes);
+    accounts.pop();
+    assert_error(audit.export_root(1, &accounts, true), AuditError::ExportIncomplete);
+}
+
+#[test]
+fn seal_needs_the_declared_champion() {
+    let (mut audit, votes) = decided();
+    audit.seed_champion(0);
+    let accounts = export_order(&audit, &votes);
+    audit.export_root(1, &accounts, false).unwrap();
+    assert_error(audit.export_root(1, &[], true), AuditError::ExportIncomplete);
+
+    audit.export_root(1, &[audit.champion()], true).unwrap();
+    let checkpoint: ExportCheckpoint = audit.bank.get(&audit.checkpoint(1));
+    assert_eq!(checkpoint.item_count, 7);
+}
+
+#[test]
+fn closed_cell_exports_as_its_tombstone() {
+    let (mut audit, votes) = decided();
+    let cell = audit.cell(0);
+    // The tombstone's digest chains the cell and its votes as they were
+    let mut digest = tagged_hash(LEAF_TAG, &[&audit.bank.data(&cell)]);
+    for vote in &votes {
+        digest = node_hash(&digest, &tagged_hash(LEAF_TAG, &[&audit.bank.data(vote)]));
+    }
+
+    audit.close_cell(0, &votes).unwrap();
+    let tombstone: CellTombstone = audit.bank.get(&cell);
+    assert_eq!(tombstone.vote_count, 2);
+    assert_eq!(tombstone.digest, digest);
+
+    // The tombstone stands in for the cell and its votes
+    let accounts = [audit.idea(0), audit.idea(1), cell, audit.tier_result(0)];
+    let expected = client_root(&audit.bank, &audit.chant, &accounts);
+    audit.export_root(1, &accounts, true).unwrap();
+    let checkpoint: ExportCheckpoint = audit.bank.get(&audit.checkpoint(1));
+    assert_eq!(checkpoint.root, expected);
+}
+
+fn seal_transcript(audit: &mut Audit, transcript_hash: [u8; 32]) -> TxResult {
+    let accounts = audit.chant_action();
+    audit.bank.process(ix(chant_audit::instruction::SealTranscript { transcript_hash }, accounts))
+}
+
+#[test]
+fn transcript_is_sealed_once_after_completion() {
+    let mut audit = Audit::new();
+    assert_error(seal_transcript(&mut audit, [3; 32]), AuditError::InvalidPhase);
+
+    audit.update(|chant| chant.phase = 3);
+    assert_error(seal_transcript(&mut audit, [0; 32]), AuditError::InvalidTranscriptHash);
+    seal_transcript(&mut audit, [3; 32]).unwrap();
+    assert_eq!(audit.state().transcript_hash, [3; 32]);
+
+    // Immutable once set, even to the same hash
+    assert_error(seal_transcript(&mut audit, [4; 32]), AuditError::TranscriptAlreadySealed);
+    assert_error(seal_transcript(&mut audit, [3; 32]), AuditError::TranscriptAlreadySealed);
+    assert_eq!(audit.state().transcript_hash, [3; 32]);
+}
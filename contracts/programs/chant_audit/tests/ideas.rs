@@ -0,0 +1,147 @@
+//! Ideas: recording, disqualification and live-index enumeration.
+
+mod common;
+
+use chant_audit::{AuditError, AuthorStats, Idea, IdeaRange, IdeaStatus};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::system_program;
+use common::*;
+
+fn ranges(audit: &Audit, start: u16, limit: u16) -> Vec<(u16, u16)> {
+    let ranges: Vec<IdeaRange> = audit.state().live_idea_ranges(start, limit).unwrap();
+    ranges.iter().map(|range| (range.start, range.end)).collect()
+}
+
+#[test]
+fn disqualified_ideas_leave_the_live_ranges() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(6);
+    assert_eq!(ranges(&audit, 0, 10), [(0, 6)]);
+
+    audit.disqualify_idea(4).unwrap();
+    audit.disqualify_idea(1).unwrap();
+    let state = audit.state();
+    assert_eq!(state.live_idea_count, 4);
+    assert_eq!(state.idea_count, 6);
+    assert_eq!(state.disqualified_indices, [1, 4]);
+    let idea: Idea = audit.bank.get(&audit.idea(4));
+    assert_eq!(idea.status, IdeaStatus::Disqualified as u8);
+
+    assert_eq!(ranges(&audit, 0, 10), [(0, 1), (2, 4), (5, 6)]);
+    // Paging from a later index, or a limit that stops short
+    assert_eq!(ranges(&audit, 2, 10), [(2, 4), (5, 6)]);
+    assert_eq!(ranges(&audit, 0, 3), [(0, 1), (2, 3)]);
+    assert_eq!(ranges(&audit, 4, 1), []);
+}
+
+#[test]
+fn idea_is_disqualified_once() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(2);
+    audit.disqualify_idea(0).unwrap();
+    assert_error(audit.disqualify_idea(0), AuditError::AlreadyDisqualified);
+    assert_eq!(audit.state().live_idea_count, 1);
+}
+
+#[test]
+fn record_idea_takes_the_next_index() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(2);
+    assert_error(audit.record_idea(1, "author-x"), AuditError::IndexMismatch);
+    assert_error(audit.record_idea(3, "author-x"), AuditError::IndexMismatch);
+    assert_reaches_cpi(audit.record_idea(2, "author-x"));
+}
+
+#[test]
+fn idea_counter_overflow_is_an_error() {
+    let mut audit = Audit::new();
+    audit.update(|chant| {
+        chant.idea_count = u16::MAX;
+        chant.live_idea_count = u16::MAX;
+    });
+    assert_error(audit.record_idea(u16::MAX, "author-x"), AuditError::MathOverflow);
+}
+
+#[test]
+fn ideas_close_at_the_submission_deadline() {
+    let mut audit = Audit::new();
+    audit.update(|chant| chant.submission_deadline = T0 + 3_600);
+
+    audit.bank.set_time(T0 + 3_600);
+    assert_reaches_cpi(audit.record_idea(0, "author-x"));
+    audit.bank.set_time(T0 + 3_601);
+    assert_error(audit.record_idea(0, "author-x"), AuditError::SubmissionClosed);
+}
+
+#[test]
+fn co_signing_author_must_sign() {
+    let mut audit = Audit::new();
+    let author = Pubkey::new_unique();
+    audit.seed_ideas(1);
+
+    // Without an author wallet the string author_id is all there is
+    assert_reaches_cpi(audit.record_idea_by(1, "author-x", None));
+    assert_reaches_cpi(audit.record_idea_by(1, "author-x", Some(author)));
+
+    // Named but not signing: no proof of authorship
+    let mut ix = ix(
+        chant_audit::instruction::RecordIdea {
+            idea_index: 1,
+            text: "An idea".to_string(),
+            author_id: "author-x".to_string(),
+        },
+        chant_audit::accounts::RecordIdea {
+            chant: audit.chant,
+            idea: audit.idea(1),
+            author_stats: pda(&[b"author", audit.chant.as_ref(), b"author-x"]).0,
+            authority: audit.authority,
+            author: Some(author),
+            system_program: system_program::ID,
+        },
+    );
+    for meta in ix.accounts.iter_mut().filter(|meta| meta.pubkey == author) {
+        meta.is_signer = false;
+    }
+    assert_error(audit.bank.process(ix), AuditError::AuthorNotSigner);
+}
+
+#[test]
+fn author_cap_stops_the_third_idea() {
+    let mut audit = Audit::new();
+    audit.update(|chant| chant.max_ideas_per_author = 2);
+    let stats = pda(&[b"author", audit.chant.as_ref(), b"author-x"]).0;
+    assert_reaches_cpi(audit.record_idea(0, "author-x"));
+
+    // Two ideas in: the third is over the cap, another author's first is not
+    audit.bank.update(&stats, |stats: &mut AuthorStats| stats.idea_count = 2);
+    assert_error(audit.record_idea(0, "author-x"), AuditError::AuthorIdeaLimitReached);
+    assert_reaches_cpi(audit.record_idea(0, "author-y"));
+
+    audit.update(|chant| chant.max_ideas_per_author = 0);
+    assert_reaches_cpi(audit.record_idea(0, "author-x"));
+}
+
+#[test]
+fn raced_submission_gets_a_retryable_error() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(2);
+    // Two clients read idea_count 2; the first submission lands
+    audit.seed_idea("First in");
+
+    // The loser fails on the index, not on the idea PDA already taken
+    assert_error(audit.record_idea(2, "author-y"), AuditError::IndexMismatch);
+    assert_reaches_cpi(audit.record_idea(3, "author-y"));
+}
+
+#[test]
+fn chant_takes_ideas_up_to_its_cap() {
+    let mut audit = Audit::new();
+    audit.update(|chant| chant.max_ideas = 3);
+    audit.seed_ideas(2);
+    assert_reaches_cpi(audit.record_idea(2, "author-x"));
+
+    audit.seed_idea("Third");
+    assert_error(audit.record_idea(3, "author-x"), AuditError::MaxIdeasReached);
+    audit.update(|chant| chant.max_ideas = 0);
+    assert_reaches_cpi(audit.record_idea(3, "author-x"));
+}
@@ -0,0 +1,48 @@
+//! The chant's kill switch: pause_chant blocks every write until unpaused.
+
+mod common;
+
+use chant_audit::AuditError;
+use common::*;
+
+#[test]
+fn pause_and_unpause_toggle_once() {
+    let mut audit = Audit::new();
+    assert_error(audit.unpause(), AuditError::NotPaused);
+    audit.pause().unwrap();
+    assert!(audit.state().paused);
+    assert_error(audit.pause(), AuditError::AlreadyPaused);
+    audit.unpause().unwrap();
+    assert!(!audit.state().paused);
+}
+
+#[test]
+fn only_the_authority_pauses() {
+    let mut audit = Audit::new();
+    audit.authority = audit.bank.wallet(1_000_000_000);
+    assert_error(audit.pause(), AuditError::Unauthorized);
+}
+
+#[test]
+fn recording_stops_while_paused_and_resumes_after() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(2);
+    audit.pause().unwrap();
+    assert_error(audit.record_idea(2, "author-x"), AuditError::ChantPaused);
+    assert_error(audit.disqualify_idea(0), AuditError::ChantPaused);
+
+    audit.unpause().unwrap();
+    assert_reaches_cpi(audit.record_idea(2, "author-x"));
+    audit.disqualify_idea(0).unwrap();
+}
+
+#[test]
+fn export_waits_for_unpause() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(1);
+    audit.pause().unwrap();
+    assert_error(audit.export_root(1, &[audit.idea(0)], true), AuditError::ChantPaused);
+
+    audit.unpause().unwrap();
+    audit.export_root(1, &[audit.idea(0)], true).unwrap();
+}
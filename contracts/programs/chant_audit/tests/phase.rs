@@ -0,0 +1,39 @@
+//! update_phase. Anchor's `emit!` is a no-op off the SBF target, so
+//! PhaseUpdated's payload (its timestamp included) isn't visible here; these
+//! cover the transition it reports.
+
+mod common;
+
+use chant_audit::{AuditError, Phase};
+use common::*;
+
+fn update_phase(audit: &mut Audit, new_phase: u8) -> TxResult {
+    let accounts = chant_audit::accounts::UpdatePhase { chant: audit.chant, authority: audit.authority };
+    audit.bank.process(ix(chant_audit::instruction::UpdatePhase { new_phase }, accounts))
+}
+
+#[test]
+fn phase_moves_to_any_known_phase() {
+    let mut audit = Audit::new();
+    update_phase(&mut audit, Phase::Accumulating as u8).unwrap();
+    assert_eq!(audit.state().phase, Phase::Accumulating as u8);
+    update_phase(&mut audit, Phase::Voting as u8).unwrap();
+    assert_eq!(audit.state().phase, Phase::Voting as u8);
+}
+
+#[test]
+fn unknown_phase_is_rejected() {
+    let mut audit = Audit::new();
+    assert_error(update_phase(&mut audit, 4), AuditError::InvalidPhase);
+    assert_eq!(audit.state().phase, Phase::Voting as u8);
+}
+
+#[test]
+fn sealed_transcript_pins_the_phase() {
+    let mut audit = Audit::new();
+    audit.update(|chant| {
+        chant.phase = Phase::Completed as u8;
+        chant.transcript_hash = [7; 32];
+    });
+    assert_error(update_phase(&mut audit, Phase::Voting as u8), AuditError::TranscriptAlreadySealed);
+}
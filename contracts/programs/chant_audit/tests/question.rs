@@ -0,0 +1,44 @@
+//! update_question: editable until the first idea arrives.
+
+mod common;
+
+use anchor_lang::solana_program::system_program;
+use chant_audit::{AuditError, Chant, Phase};
+use common::*;
+
+fn update_question(audit: &mut Audit, new_question: &str) -> TxResult {
+    // A longer question grows the chant; pre-fund the rent so realloc needs no transfer
+    audit.bank.airdrop(&audit.chant, 1_000_000);
+    audit.bank.process(ix(
+        chant_audit::instruction::UpdateQuestion { new_question: new_question.to_string() },
+        chant_audit::accounts::UpdateQuestion {
+            chant: audit.chant,
+            authority: audit.authority,
+            system_program: system_program::ID,
+        },
+    ))
+}
+
+#[test]
+fn question_is_editable_before_submissions() {
+    let mut audit = Audit::new();
+    audit.update(|chant| chant.phase = Phase::Submission as u8);
+    let question = "Which project should the treasury fund this quarter, and why?";
+    update_question(&mut audit, question).unwrap();
+    let chant = audit.state();
+    assert_eq!(chant.question, question);
+    assert_eq!(audit.bank.data_len(&audit.chant), Chant::space(&chant.chant_id, question));
+}
+
+#[test]
+fn question_locks_after_the_first_idea() {
+    let mut audit = Audit::new();
+    audit.update(|chant| chant.phase = Phase::Submission as u8);
+    audit.seed_ideas(1);
+    assert_error(update_question(&mut audit, "A clearer prompt?"), AuditError::QuestionLockedAfterSubmissions);
+    assert_eq!(audit.state().question, Audit::QUESTION);
+
+    // Nor once the chant has moved on to voting, as Audit::new leaves it
+    let mut audit = Audit::new();
+    assert_error(update_question(&mut audit, "A clearer prompt?"), AuditError::QuestionLockedAfterSubmissions);
+}
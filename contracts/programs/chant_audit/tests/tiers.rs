@@ -0,0 +1,150 @@
+//! Tier results: advancement checked against the tier's cells, the XP
+//! standings TierCompleted carries, each idea's running XP, the tier's
+//! duration, and the statuses apply_idea_statuses settles. record_tier_result creates its TierResult over
+//! a CPI, so it's covered through the checks it calls.
+
+mod common;
+
+use anchor_lang::prelude::AccountMeta;
+use chant_audit::{AuditError, Cell, Idea, IdeaStatus, TierResult};
+use common::*;
+
+/// Five ideas, the first four split over two tier-0 cells; idea 4 sits out.
+fn two_cells() -> Audit {
+    let mut audit = Audit::new();
+    audit.seed_ideas(5);
+    audit.seed_cell(0, &[0, 1]);
+    audit.seed_cell(0, &[2, 3]);
+    audit
+}
+
+fn check(audit: &Audit, cells: &[u16], advancing: &[u16]) -> anchor_lang::Result<()> {
+    let cells: Vec<Cell> = cells.iter().map(|&i| audit.bank.get(&audit.cell(i))).collect();
+    let ideas: Vec<Idea> = advancing.iter().map(|&i| audit.bank.get(&audit.idea(i))).collect();
+    audit.state().check_advancement(audit.chant, 0, &cells, &ideas, advancing)
+}
+
+#[test]
+fn cell_winners_advance() {
+    let audit = two_cells();
+    check(&audit, &[0, 1], &[0, 3]).unwrap();
+    check(&audit, &[0, 1], &[]).unwrap();
+}
+
+#[test]
+fn idea_outside_the_tier_cannot_advance() {
+    let audit = two_cells();
+    assert_eq!(check(&audit, &[0, 1], &[0, 4]).unwrap_err(), AuditError::InvalidAdvancement.into());
+}
+
+#[test]
+fn every_cell_of_the_tier_is_supplied() {
+    let audit = two_cells();
+    assert_eq!(check(&audit, &[0], &[0]).unwrap_err(), AuditError::CellCountMismatch.into());
+}
+
+#[test]
+fn eliminated_idea_cannot_advance() {
+    let mut audit = two_cells();
+    let idea = audit.idea(3);
+    audit.bank.update(&idea, |idea: &mut Idea| idea.status = IdeaStatus::Eliminated as u8);
+    assert_eq!(check(&audit, &[0, 1], &[0, 3]).unwrap_err(), AuditError::InvalidAdvancement.into());
+}
+
+#[test]
+fn tier_event_carries_the_top_xp() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(40);
+    let xp: Vec<(u16, u16)> = (0..40).map(|i| (i, (i * 7) % 40)).collect();
+    audit.seed_tier_result(0, &[], &xp);
+    let result: TierResult = audit.bank.get(&audit.tier_result(0));
+    assert_eq!(result.xp_totals.len(), 40);
+
+    // Capped at 32 for the event, highest first
+    let top: Vec<(u16, u16)> = result.top_xp().iter().map(|e| (e.idea_index, e.total_xp)).collect();
+    assert_eq!(top.len(), 32);
+    assert_eq!(top[0], (17, 39));
+    assert!(top.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    assert_eq!(top[31].1, 8);
+}
+
+#[test]
+fn xp_accumulates_once_per_tier() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(1);
+    let mut idea: Idea = audit.bank.get(&audit.idea(0));
+    assert_eq!((idea.total_xp, idea.last_xp_tier), (0, None));
+
+    idea.apply_tier_xp(0, 30).unwrap();
+    assert_eq!(idea.apply_tier_xp(0, 30).unwrap_err(), AuditError::TierXpAlreadyApplied.into());
+    idea.apply_tier_xp(1, 45).unwrap();
+    assert_eq!((idea.total_xp, idea.last_xp_tier), (75, Some(1)));
+}
+
+#[test]
+fn chant_counts_each_tiers_cells() {
+    let mut audit = two_cells();
+    audit.seed_cell(0, &[4, 0]);
+    assert_eq!(audit.state().tier_cell_counts[0], 3);
+    assert_eq!(check(&audit, &[0, 1], &[0]).unwrap_err(), AuditError::CellCountMismatch.into());
+    check(&audit, &[0, 1, 2], &[0, 4]).unwrap();
+    assert_eq!(audit.state().tier_cell_counts[1], 0);
+}
+
+fn apply_idea_statuses(audit: &mut Audit, tier: u8, ideas: &[u16]) -> TxResult {
+    let mut ix = ix(
+        chant_audit::instruction::ApplyIdeaStatuses { tier },
+        chant_audit::accounts::ApplyIdeaStatuses {
+            chant: audit.chant,
+            tier_result: audit.tier_result(tier),
+            authority: audit.authority,
+        },
+    );
+    for &idea in ideas {
+        ix.accounts.push(AccountMeta::new(audit.idea(idea), false));
+    }
+    audit.bank.process(ix)
+}
+
+#[test]
+fn tier_result_sets_advancing_and_eliminated() {
+    let mut audit = two_cells();
+    audit.seed_tier_result(0, &[0, 3], &[(0, 20), (1, 0), (2, 5), (3, 15)]);
+    apply_idea_statuses(&mut audit, 0, &[0, 1, 2, 3]).unwrap();
+
+    let status = |audit: &Audit, index: u16| {
+        let idea: Idea = audit.bank.get(&audit.idea(index));
+        (idea.status, idea.tier)
+    };
+    let advancing = (IdeaStatus::Advancing as u8, 1);
+    let eliminated = (IdeaStatus::Eliminated as u8, 0);
+    assert_eq!([0, 1, 2, 3].map(|i| status(&audit, i)), [advancing, eliminated, eliminated, advancing]);
+
+    // Applied once: neither an eliminated idea nor one already moved on qualifies again
+    assert_error(apply_idea_statuses(&mut audit, 0, &[1]), AuditError::IdeaTierMismatch);
+    assert_error(apply_idea_statuses(&mut audit, 0, &[0]), AuditError::IdeaTierMismatch);
+}
+
+#[test]
+fn tier_duration_runs_from_first_cell_to_result() {
+    let mut audit = Audit::new();
+    audit.seed_ideas(4);
+    audit.seed_cell(0, &[0, 1]);
+    // Later cells of the tier don't move its start
+    audit.bank.set_time(T0 + 600);
+    audit.seed_cell(0, &[2, 3]);
+    assert_eq!(audit.state().tier_started_at[0], T0);
+
+    audit.bank.set_time(T0 + 3_600);
+    audit.seed_tier_result(0, &[0, 3], &[(0, 20), (3, 15)]);
+    let result: TierResult = audit.bank.get(&audit.tier_result(0));
+    assert_eq!(result.completed_at - result.started_at, 3_600);
+
+    // Tier 1's clock starts with its own first cell
+    audit.bank.set_time(T0 + 5_000);
+    let mut chant = audit.state();
+    chant.count_cell(1, audit.bank.now()).unwrap();
+    chant.count_cell(1, T0 + 9_000).unwrap();
+    assert_eq!(chant.tier_started_at[1], T0 + 5_000);
+    assert_eq!(chant.tier_started_at[0], T0);
+}
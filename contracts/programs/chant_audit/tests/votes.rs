@@ -0,0 +1,86 @@
+//! Ballots. record_vote, record_committed_vote and revise_vote create their
+//! VoteRecord through `init`, whose CPI runs before the handler, so their
+//! rules are covered through the checks they share.
+
+mod common;
+
+use chant_audit::{Allocation, AuditError, Cell, VoteRecord};
+use common::*;
+
+fn ballot(points: &[(u16, u8)]) -> Vec<Allocation> {
+    points.iter().map(|&(idea_index, points)| Allocation { idea_index, points }).collect()
+}
+
+#[test]
+fn ballot_spends_exactly_the_vote_budget() {
+    let audit = Audit::new();
+    let chant = audit.state();
+    assert_eq!(chant.vote_budget, 10);
+
+    // Credits may be spread or stacked, but must all be spent
+    assert_eq!(chant.check_vote_budget(&ballot(&[(0, 10)])).unwrap(), 10);
+    assert_eq!(chant.check_vote_budget(&ballot(&[(0, 6), (1, 3), (2, 1)])).unwrap(), 10);
+    assert_eq!(chant.check_vote_budget(&ballot(&[(0, 9)])).unwrap_err(), AuditError::InvalidPointTotal.into());
+    assert_eq!(chant.check_vote_budget(&ballot(&[(0, 6), (1, 5)])).unwrap_err(), AuditError::InvalidPointTotal.into());
+    assert_eq!(chant.check_vote_budget(&[]).unwrap_err(), AuditError::InvalidPointTotal.into());
+}
+
+#[test]
+fn budget_is_per_chant() {
+    let mut audit = Audit::new();
+    audit.update(|chant| chant.vote_budget = 100);
+    let chant = audit.state();
+    assert_eq!(chant.check_vote_budget(&ballot(&[(0, 60), (1, 40)])).unwrap(), 100);
+    assert!(chant.check_vote_budget(&ballot(&[(0, 10)])).is_err());
+}
+
+#[test]
+fn revisions_supersede_the_latest_version_in_order() {
+    let mut audit = Audit::new();
+    let ideas = audit.seed_ideas(2);
+    audit.seed_cell(0, &ideas);
+    let original = audit.seed_vote(0, "voter-a");
+    let mut v0: VoteRecord = audit.bank.get(&original);
+    assert_eq!((v0.version, v0.superseded), (0, false));
+
+    assert_eq!(v0.supersede(2).unwrap_err(), AuditError::InvalidVoteVersion.into());
+    v0.supersede(1).unwrap();
+    assert!(v0.superseded);
+    // A superseded record can't be revised again; only the latest version counts
+    assert_eq!(v0.supersede(1).unwrap_err(), AuditError::VoteSuperseded.into());
+
+    let mut v1 = VoteRecord { version: 1, superseded: false, ..v0 };
+    v1.supersede(2).unwrap();
+}
+
+#[test]
+fn cell_holds_at_most_cell_size_ballots_of_points() {
+    let mut audit = Audit::new();
+    let ideas = audit.seed_ideas(2);
+    audit.seed_cell(0, &ideas);
+    let chant = audit.state();
+    let mut cell: Cell = audit.bank.get(&audit.cell(0));
+
+    for _ in 0..chant.cell_size {
+        cell.add_points(&chant, chant.vote_budget).unwrap();
+    }
+    assert_eq!(cell.total_points, 50);
+    assert_eq!(cell.add_points(&chant, chant.vote_budget).unwrap_err(), AuditError::CellPointsOverflow.into());
+    assert_eq!(cell.total_points, 50);
+}
+
+#[test]
+fn votes_close_at_the_cell_deadline() {
+    let mut audit = Audit::new();
+    let ideas = audit.seed_ideas(2);
+    audit.seed_cell(0, &ideas);
+    let mut cell: Cell = audit.bank.get(&audit.cell(0));
+
+    // No deadline: open for as long as the cell is Voting
+    assert_eq!(cell.voting_deadline, 0);
+    cell.require_accepting_votes(i64::MAX).unwrap();
+
+    cell.voting_deadline = T0 + 600;
+    cell.require_accepting_votes(T0 + 600).unwrap();
+    assert_eq!(cell.require_accepting_votes(T0 + 601).unwrap_err(), AuditError::CellVotingClosed.into());
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
+use anchor_spl::associated_token::{get_associated_token_address, AssociatedToken};
 use anchor_spl::token::{self, Mint, MintTo, SetAuthority, Token, TokenAccount};
 use anchor_spl::token::spl_token::instruction::AuthorityType;
+use anchor_spl::token::spl_token::native_mint;
+use chant_audit::pda::create_pda;
+use chant_audit::{hash, Champion, Chant};
 
 declare_id!("5ngmZdSGoTX1J1iZF3BDJzWf983aS4aEpQH8CWZ9mBgb");
 
@@ -9,16 +13,92 @@ declare_id!("5ngmZdSGoTX1J1iZF3BDJzWf983aS4aEpQH8CWZ9mBgb");
 const CONTRIBUTOR_SHARE_BPS: u64 = 9400; // 94% → token distribution to contributors
 const WINNER_SHARE_BPS: u64 = 500;       // 5%  → SOL to winner for operations
 const PLATFORM_SHARE_BPS: u64 = 100;     // 1%  → tokens to UC platform
+// Smallest pool where every SOL split is at least one lamport
+const MIN_SPLIT_LAMPORTS: u64 = 10000 / PLATFORM_SHARE_BPS;
 
-// Total token supply minted on finalize
+// Default token supply minted on finalize (overridable per pool)
 const TOKEN_SUPPLY: u64 = 1_000_000_000;
 const TOKEN_DECIMALS: u8 = 6;
+const MAX_TOKEN_DECIMALS: u8 = 18;      // 10^19 no longer fits in u64
+// And token_supply * 10^token_decimals must fit in u64 (LaunchPool::checked_total_tokens)
 
 // Confirmation window bounds
 const MIN_CONFIRM_SECS: i64 = 86_400;    // 24 hours minimum
 const MAX_CONFIRM_SECS: i64 = 604_800;   // 7 days maximum
 const DEFAULT_CONFIRM_SECS: i64 = 172_800; // 48 hours default
 
+// Confirmation approval threshold: share of voted SOL that must approve
+const DEFAULT_APPROVAL_THRESHOLD_BPS: u16 = 5000; // simple majority
+
+// Window after an expiry cancellation during which the multisig may reopen confirmation
+const REOPEN_GRACE_SECS: i64 = 86_400;   // 24 hours
+
+// Cooling-off between propose_complete and complete_pool (mint authority burn)
+const DEFAULT_COMPLETE_DELAY_SECS: i64 = 86_400; // 24 hours default
+const MAX_COMPLETE_DELAY_SECS: i64 = 604_800;    // 7 days maximum
+
+// Pause safety valve: contributors may refund once a pause outlasts this
+const DEFAULT_MAX_PAUSE_SECS: i64 = 1_209_600; // 14 days
+
+// Winner bond: default window to hit the milestone after depositing
+const DEFAULT_MILESTONE_SECS: i64 = 2_592_000; // 30 days
+const MIN_MILESTONE_SECS: i64 = 604_800;       // 7 days — outlasts any confirmation window
+
+// Refund penalty cap for past-deadline and expiry-cancelled refunds
+const MAX_REFUND_PENALTY_BPS: u16 = 1000; // 10%
+
+// Contribution memo (referral code / message) max length
+const MAX_MEMO_LEN: usize = 64;
+
+// Allow-list leaf: keccak(ALLOWLIST_TAG ++ contributor), chant_audit hashing
+const ALLOWLIST_TAG: &[u8] = b"UC:ALLOW";
+
+// Pool metadata URI (name / logo / description JSON) max length
+const MAX_METADATA_URI_LEN: usize = 200;
+
+// Metaplex Token Metadata program, and its field limits
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = anchor_lang::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33; // Token Metadata instruction index
+const MAX_TOKEN_NAME_LEN: usize = 32;
+const MAX_TOKEN_SYMBOL_LEN: usize = 10;
+const MAX_TOKEN_URI_LEN: usize = 200;
+pub const RECEIPT_SYMBOL: &str = "UCRCPT";
+
+// Pool id (UC deliberation ID) max length
+const MAX_POOL_ID_LEN: usize = 64;
+
+// allowed_actions bitmask — one bit per instruction currently valid for a pool
+#[constant]
+pub const ACTION_CONTRIBUTE: u16 = 1 << 0;
+#[constant]
+pub const ACTION_PROPOSE_FINALIZE: u16 = 1 << 1;
+#[constant]
+pub const ACTION_CONFIRM_VOTE: u16 = 1 << 2;
+#[constant]
+pub const ACTION_EXECUTE_DISTRIBUTION: u16 = 1 << 3;
+#[constant]
+pub const ACTION_EXPIRE_CONFIRMATION: u16 = 1 << 4;
+#[constant]
+pub const ACTION_CLAIM: u16 = 1 << 5;
+#[constant]
+pub const ACTION_REFUND: u16 = 1 << 6;
+#[constant]
+pub const ACTION_PAUSE: u16 = 1 << 7;
+#[constant]
+pub const ACTION_UNPAUSE: u16 = 1 << 8;
+#[constant]
+pub const ACTION_CANCEL: u16 = 1 << 9;
+#[constant]
+pub const ACTION_COMPLETE: u16 = 1 << 10;
+#[constant]
+pub const ACTION_REOPEN_CONFIRMATION: u16 = 1 << 11;
+#[constant]
+pub const ACTION_DELEGATE_VOTE: u16 = 1 << 12;
+#[constant]
+pub const ACTION_PROPOSE_COMPLETE: u16 = 1 << 13;
+#[constant]
+pub const ACTION_DECLINE_WIN: u16 = 1 << 14;
+
 #[program]
 pub mod contracts {
     use super::*;
@@ -33,7 +113,7 @@ pub mod contracts {
         signers: [Pubkey; 3],
         threshold: u8,
     ) -> Result<()> {
-        require!(threshold >= 2 && threshold <= 3, LaunchError::InvalidThreshold);
+        require!((2..=3).contains(&threshold), LaunchError::InvalidThreshold);
         // All signers must be unique
         require!(signers[0] != signers[1] && signers[1] != signers[2] && signers[0] != signers[2], LaunchError::DuplicateSigner);
 
@@ -41,6 +121,8 @@ pub mod contracts {
         ms.signers = signers;
         ms.threshold = threshold;
         ms.nonce = 0;
+        ms.pool_count = 0;
+        ms.last_pool_id = String::new();
         ms.bump = ctx.bumps.multisig;
 
         Ok(())
@@ -57,10 +139,89 @@ pub mod contracts {
         deadline: i64,
         pool_id: String,
         confirm_duration_secs: i64,
+        config: PoolConfig,
     ) -> Result<()> {
         require!(target_lamports > 0, LaunchError::InvalidTarget);
         require!(deadline > Clock::get()?.unix_timestamp, LaunchError::DeadlinePassed);
-        require!(pool_id.len() <= 64, LaunchError::IdTooLong);
+        require!(pool_id.len() <= MAX_POOL_ID_LEN, LaunchError::IdTooLong);
+        require!(
+            config.metadata_uri.len() <= MAX_METADATA_URI_LEN,
+            LaunchError::MetadataTooLong
+        );
+
+        let (token_supply, token_decimals) = if config.token_supply == 0 {
+            (TOKEN_SUPPLY, TOKEN_DECIMALS)
+        } else {
+            require!(config.token_decimals <= MAX_TOKEN_DECIMALS, LaunchError::InvalidDecimals);
+            (config.token_supply, config.token_decimals)
+        };
+        LaunchPool::checked_total_tokens(token_supply, token_decimals)?;
+        require!(
+            config.hard_cap_lamports == 0 || config.soft_cap_lamports <= config.hard_cap_lamports,
+            LaunchError::InvalidCapOrder
+        );
+
+        require!(
+            config.min_distribution_lamports == 0
+                || config.min_distribution_lamports >= MIN_SPLIT_LAMPORTS,
+            LaunchError::InvalidMinDistribution
+        );
+        require!(
+            config.max_contributor_bps <= 10000,
+            LaunchError::InvalidMaxContributorBps
+        );
+        require!(
+            config.contribution_cutoff_secs >= 0
+                && deadline - config.contribution_cutoff_secs > Clock::get()?.unix_timestamp,
+            LaunchError::ContributionWindowClosed
+        );
+        let approval_threshold_bps = if config.approval_threshold_bps == 0 {
+            DEFAULT_APPROVAL_THRESHOLD_BPS
+        } else {
+            require!(
+                (DEFAULT_APPROVAL_THRESHOLD_BPS..=10000).contains(&config.approval_threshold_bps),
+                LaunchError::InvalidApprovalThreshold
+            );
+            config.approval_threshold_bps
+        };
+        require!(
+            config.refund_penalty_bps <= MAX_REFUND_PENALTY_BPS,
+            LaunchError::InvalidRefundPenalty
+        );
+        require!(
+            !config.allowlist_required || config.allowlist_root != [0u8; 32],
+            LaunchError::InvalidAllowlistRoot
+        );
+        // Tranche release and champion-proof release are alternative escrows
+        require!(
+            !(config.escrow_winner_sol && config.require_champion_proof),
+            LaunchError::ConflictingWinnerEscrow
+        );
+        // A champion proof is only as good as the chant it comes from
+        require!(
+            !config.require_champion_proof || config.chant_authority != Pubkey::default(),
+            LaunchError::UntrustedChant
+        );
+
+        let milestone_period_secs = if config.milestone_period_secs <= 0 {
+            DEFAULT_MILESTONE_SECS
+        } else {
+            require!(
+                config.milestone_period_secs >= MIN_MILESTONE_SECS,
+                LaunchError::MilestonePeriodTooShort
+            );
+            config.milestone_period_secs
+        };
+
+        let complete_delay = if config.complete_delay_secs == 0 {
+            DEFAULT_COMPLETE_DELAY_SECS
+        } else {
+            require!(
+                (0..=MAX_COMPLETE_DELAY_SECS).contains(&config.complete_delay_secs),
+                LaunchError::InvalidCompleteDelay
+            );
+            config.complete_delay_secs
+        };
 
         let confirm_secs = if confirm_duration_secs == 0 {
             DEFAULT_CONFIRM_SECS
@@ -70,46 +231,199 @@ pub mod contracts {
             confirm_duration_secs
         };
 
-        let pool = &mut ctx.accounts.pool;
-        pool.authority = ctx.accounts.multisig.key();
-        pool.pool_id = pool_id;
-        pool.target_lamports = target_lamports;
-        pool.current_lamports = 0;
-        pool.deadline = deadline;
-        pool.status = PoolStatus::Funding;
-        pool.winner = Pubkey::default();
-        pool.platform_wallet = ctx.accounts.platform_wallet.key();
-        pool.contributor_count = 0;
-        pool.token_mint = Pubkey::default();
-        pool.merkle_root = [0u8; 32];
-        pool.confirm_deadline = 0;
-        pool.confirm_duration_secs = confirm_secs;
-        pool.approve_lamports = 0;
-        pool.reject_lamports = 0;
-        pool.paused = false;
-        pool.bump = ctx.bumps.pool;
+        // The pool PDA is created by hand so a reused pool_id surfaces as
+        // PoolIdAlreadyUsed instead of init's opaque already-in-use error.
+        let pool_info = ctx.accounts.pool.to_account_info();
+        require!(pool_info.data_is_empty(), LaunchError::PoolIdAlreadyUsed);
+        let multisig_key = ctx.accounts.multisig.key();
+        create_pda(
+            &ctx.accounts.payer.to_account_info(),
+            &pool_info,
+            &ctx.accounts.system_program.to_account_info(),
+            &crate::ID,
+            LaunchPool::space(&pool_id),
+            &[b"pool", multisig_key.as_ref(), pool_id.as_bytes(), &[ctx.bumps.pool]],
+        )?;
+
+        let pool = LaunchPool {
+            authority: multisig_key,
+            pool_id,
+            target_lamports,
+            current_lamports: 0,
+            deadline,
+            status: PoolStatus::Funding,
+            winner: Pubkey::default(),
+            platform_wallet: ctx.accounts.platform_wallet.key(),
+            contributor_count: 0,
+            token_mint: Pubkey::default(),
+            merkle_root: [0u8; 32],
+            confirm_deadline: 0,
+            confirm_duration_secs: confirm_secs,
+            approve_lamports: 0,
+            reject_lamports: 0,
+            paused: false,
+            require_memo: config.require_memo,
+            min_approving_contributors: config.min_approving_contributors,
+            approve_count: 0,
+            reject_count: 0,
+            total_claimed_tokens: 0,
+            claimed_count: 0,
+            token_supply,
+            token_decimals,
+            target_reached: false,
+            require_winner_payout: config.require_winner_payout,
+            winner_payout: Pubkey::default(),
+            distribution_executed: false,
+            platform_fee_in_sol: config.platform_fee_in_sol,
+            proposal_epoch: 0,
+            cancelled_at: 0,
+            cancelled_by_expiry: false,
+            cancelled_lamports: 0,
+            contribution_tx_count: 0,
+            max_single_contributor_lamports: 0,
+            total_eligible_lamports: 0,
+            soft_cap_lamports: config.soft_cap_lamports,
+            hard_cap_lamports: config.hard_cap_lamports,
+            complete_delay_secs: complete_delay,
+            complete_eligible_at: 0,
+            max_refinalizations: config.max_refinalizations,
+            refinalize_count: 0,
+            min_contributors: config.min_contributors,
+            winner_bond_lamports: config.winner_bond_lamports,
+            milestone_period_secs,
+            winner_bond_deposited: false,
+            escrow_winner_sol: config.escrow_winner_sol,
+            min_distribution_lamports: config.min_distribution_lamports,
+            distribution_snapshot_lamports: 0,
+            distribution_contributor_count: 0,
+            paused_at: 0,
+            max_pause_secs: if config.max_pause_secs <= 0 {
+                DEFAULT_MAX_PAUSE_SECS
+            } else {
+                config.max_pause_secs
+            },
+            declined_winner: Pubkey::default(),
+            distributed_at: 0,
+            refund_penalty_bps: config.refund_penalty_bps,
+            require_champion_proof: config.require_champion_proof,
+            allowlist_required: config.allowlist_required,
+            allowlist_root: config.allowlist_root,
+            metadata_uri: config.metadata_uri,
+            majority_reached: false,
+            max_contributor_bps: config.max_contributor_bps,
+            finalize_commitment: [0u8; 32],
+            approval_threshold_bps,
+            contribution_cutoff_secs: config.contribution_cutoff_secs,
+            allocated_count: 0,
+            total_allocated_tokens: 0,
+            forbid_signer_winner: config.forbid_signer_winner,
+            chant_authority: config.chant_authority,
+            require_finalize_commit: config.require_finalize_commit,
+            winner_escrow_lamports: 0,
+            winner_released_lamports: 0,
+            milestone_count: 0,
+            tranche_count: 0,
+            next_sequence: 1,
+            bump: ctx.bumps.pool,
+        };
+        pool.try_serialize(&mut &mut pool_info.try_borrow_mut_data()?[..])?;
+
+        ctx.accounts.multisig.record_pool(&pool.pool_id)?;
 
         emit!(PoolCreated {
-            pool: pool.key(),
+            pool: pool_info.key(),
             pool_id: pool.pool_id.clone(),
             target_lamports,
             deadline,
             confirm_duration_secs: confirm_secs,
+            require_memo: config.require_memo,
+            min_approving_contributors: config.min_approving_contributors,
+            token_supply,
+            token_decimals,
+            soft_cap_lamports: config.soft_cap_lamports,
+            hard_cap_lamports: config.hard_cap_lamports,
         });
 
         Ok(())
     }
 
     /// Contribute SOL to a pool. SOL is transferred to the pool PDA (escrow).
-    pub fn contribute(ctx: Context<Contribute>, amount_lamports: u64) -> Result<()> {
+    /// The memo is stored on the first contribution only; required if the pool says so.
+    /// With a hard cap, only the part of `amount_lamports` that fits is taken.
+    /// Allow-listed pools need a Merkle proof of the contributor every time.
+    /// Passing `wsol_account` pays from wrapped SOL: it must hold exactly
+    /// `amount_lamports`, and is closed (unwrapped) to the contributor before
+    /// the transfer. Accounting stays in lamports.
+    pub fn contribute(
+        ctx: Context<Contribute>,
+        amount_lamports: u64,
+        memo: String,
+        referrer: Option<Pubkey>,
+        allowlist_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
         require!(amount_lamports > 0, LaunchError::InvalidAmount);
+        // A wSOL account is consumed whole, so it must be exactly the contribution
+        if let Some(wsol_account) = &ctx.accounts.wsol_account {
+            require!(wsol_account.amount == amount_lamports, LaunchError::WsolAmountMismatch);
+        }
+        // A refunded record is settled; topping it up would strand the SOL
+        require!(!ctx.accounts.contribution.refunded, LaunchError::AlreadyRefunded);
+        require!(
+            !ctx.accounts.pool.allowlist_required
+                || ctx.accounts.pool.is_allowlisted(&ctx.accounts.contributor.key(), &allowlist_proof),
+            LaunchError::NotAllowlisted
+        );
+        require!(
+            referrer != Some(ctx.accounts.contributor.key()),
+            LaunchError::SelfReferral
+        );
+        require!(memo.len() <= MAX_MEMO_LEN, LaunchError::MemoTooLong);
+        require!(!ctx.accounts.pool.require_memo || !memo.is_empty(), LaunchError::MemoRequired);
         require!(!ctx.accounts.pool.paused, LaunchError::PoolPaused);
         require!(ctx.accounts.pool.status == PoolStatus::Funding, LaunchError::PoolNotFunding);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < ctx.accounts.pool.deadline, LaunchError::DeadlinePassed);
         require!(
-            Clock::get()?.unix_timestamp < ctx.accounts.pool.deadline,
-            LaunchError::DeadlinePassed
+            now < ctx.accounts.pool.contributions_close_at(),
+            LaunchError::ContributionWindowClosed
         );
 
+        let amount_lamports = match ctx.accounts.pool.hard_cap_remaining() {
+            Some(remaining) => {
+                require!(remaining > 0, LaunchError::HardCapExceeded);
+                amount_lamports.min(remaining)
+            }
+            None => amount_lamports,
+        };
+        if let Some(cap) = ctx.accounts.pool.max_contributor_lamports() {
+            let total = ctx
+                .accounts
+                .contribution
+                .amount_lamports
+                .checked_add(amount_lamports)
+                .ok_or(LaunchError::MathOverflow)?;
+            require!(total <= cap, LaunchError::ContributorShareTooLarge);
+        }
+
+        // wSOL integrators: unwrap the account (balance == requested amount) back
+        // to the contributor's wallet first, so the pool only ever holds native
+        // lamports. A hard-cap clamp leaves the excess in the wallet.
+        if let Some(wsol_account) = &ctx.accounts.wsol_account {
+            let token_program = ctx
+                .accounts
+                .token_program
+                .as_ref()
+                .ok_or(LaunchError::InvalidTokenAccount)?;
+            token::close_account(CpiContext::new(
+                token_program.to_account_info(),
+                token::CloseAccount {
+                    account: wsol_account.to_account_info(),
+                    destination: ctx.accounts.contributor.to_account_info(),
+                    authority: ctx.accounts.contributor.to_account_info(),
+                },
+            ))?;
+        }
+
         // Transfer SOL from contributor to pool PDA
         system_program::transfer(
             CpiContext::new(
@@ -128,19 +442,85 @@ pub mod contracts {
         if record.amount_lamports == 0 {
             record.pool = pool_key;
             record.contributor = ctx.accounts.contributor.key();
+            record.memo = memo.clone();
             record.bump = ctx.bumps.contribution;
-            pool.contributor_count += 1;
         }
-        record.amount_lamports += amount_lamports;
-        pool.current_lamports += amount_lamports;
+        pool.record_contribution(record, amount_lamports)?;
+
+        // First referrer wins; later contributions credit the stored one
+        let newly_referred = match referrer {
+            Some(referrer) if record.set_referrer(referrer) => {
+                emit!(ReferralRecorded {
+                    pool: pool_key,
+                    contributor: record.contributor,
+                    referrer,
+                });
+                true
+            }
+            _ => false,
+        };
+        if record.referrer != Pubkey::default() {
+            let stats_info = ctx
+                .accounts
+                .referral_stats
+                .as_ref()
+                .ok_or(LaunchError::ReferralStatsRequired)?
+                .to_account_info();
+            let (expected, bump) = ReferralStats::pda(&pool_key, &record.referrer);
+            require!(stats_info.key() == expected, LaunchError::ReferralStatsRequired);
+
+            let mut stats = if stats_info.data_is_empty() {
+                create_pda(
+                    &ctx.accounts.contributor.to_account_info(),
+                    &stats_info,
+                    &ctx.accounts.system_program.to_account_info(),
+                    &crate::ID,
+                    ReferralStats::SPACE,
+                    &[b"referral", pool_key.as_ref(), record.referrer.as_ref(), &[bump]],
+                )?;
+                ReferralStats {
+                    pool: pool_key,
+                    referrer: record.referrer,
+                    referred_lamports: 0,
+                    referred_count: 0,
+                    bump,
+                }
+            } else {
+                ReferralStats::try_deserialize(&mut &stats_info.try_borrow_data()?[..])?
+            };
+            if newly_referred {
+                stats.referred_count =
+                    stats.referred_count.checked_add(1).ok_or(LaunchError::MathOverflow)?;
+            }
+            stats.referred_lamports = stats
+                .referred_lamports
+                .checked_add(amount_lamports)
+                .ok_or(LaunchError::MathOverflow)?;
+            stats.try_serialize(&mut &mut stats_info.try_borrow_mut_data()?[..])?;
+        }
 
         emit!(ContributionMade {
             pool: pool_key,
             contributor: ctx.accounts.contributor.key(),
             amount_lamports,
             total_lamports: pool.current_lamports,
+            memo,
+            sequence: record.sequence,
         });
 
+        if pool.mark_target_reached() {
+            emit!(TargetReached {
+                pool: pool_key,
+                total_lamports: pool.current_lamports,
+                timestamp: now,
+            });
+        }
+
+        // Full: stop taking contributions, wait for finalize
+        if pool.current_lamports >= pool.funding_ceiling() {
+            pool.status = PoolStatus::Funded;
+        }
+
         Ok(())
     }
 
@@ -150,64 +530,130 @@ pub mod contracts {
 
     /// Propose finalization: declare winner + Merkle root of deliberation votes.
     /// Pool enters CONFIRMING state with a timelock. Contributors must approve.
-    /// Requires multisig signer.
+    /// Requires multisig signer. Pools with require_finalize_commit must use
+    /// commit_finalize / reveal_finalize instead.
     pub fn propose_finalize(
         ctx: Context<ProposeFinalize>,
         merkle_root: [u8; 32],
     ) -> Result<()> {
-        let pool = &ctx.accounts.pool;
-        require!(!pool.paused, LaunchError::PoolPaused);
-        require!(pool.status == PoolStatus::Funding, LaunchError::PoolNotFunding);
-        require!(pool.current_lamports > 0, LaunchError::NoContributions);
-
-        let now = Clock::get()?.unix_timestamp;
-        let confirm_deadline = now + pool.confirm_duration_secs;
+        require!(
+            !ctx.accounts.pool.require_finalize_commit,
+            LaunchError::FinalizeCommitRequired
+        );
+        require!(
+            ctx.accounts.pool.finalize_commitment == [0u8; 32],
+            LaunchError::FinalizeCommitPending
+        );
+        open_confirmation(ctx.accounts, merkle_root)
+    }
 
+    /// First half of the two-phase proposal: commit to
+    /// keccak(winner ++ merkle_root) without revealing either.
+    /// Requires multisig signer. A later commit replaces an unrevealed one; a
+    /// zero hash withdraws it.
+    pub fn commit_finalize(ctx: Context<MultisigAction>, param_hash: [u8; 32]) -> Result<()> {
         let pool = &mut ctx.accounts.pool;
-        pool.status = PoolStatus::Confirming;
-        pool.winner = ctx.accounts.winner.key();
-        pool.token_mint = ctx.accounts.token_mint.key();
-        pool.merkle_root = merkle_root;
-        pool.confirm_deadline = confirm_deadline;
-        pool.approve_lamports = 0;
-        pool.reject_lamports = 0;
+        require!(!pool.paused, LaunchError::PoolPaused);
+        require!(pool.is_pre_finalize(), LaunchError::PoolNotFunding);
+
+        pool.finalize_commitment = param_hash;
 
-        emit!(FinalizeProposed {
+        emit!(FinalizeCommitted {
             pool: pool.key(),
-            winner: ctx.accounts.winner.key(),
-            token_mint: ctx.accounts.token_mint.key(),
-            merkle_root,
-            confirm_deadline,
+            param_hash,
         });
-
         Ok(())
     }
 
+    /// Second half: reveal the committed winner (account) and merkle_root. On a
+    /// match this is propose_finalize — same checks, pool enters Confirming.
+    pub fn reveal_finalize(
+        ctx: Context<ProposeFinalize>,
+        merkle_root: [u8; 32],
+    ) -> Result<()> {
+        let commitment = ctx.accounts.pool.finalize_commitment;
+        require!(commitment != [0u8; 32], LaunchError::FinalizeNotCommitted);
+        require!(
+            finalize_param_hash(&ctx.accounts.winner.key(), &merkle_root) == commitment,
+            LaunchError::FinalizeRevealMismatch
+        );
+
+        ctx.accounts.pool.finalize_commitment = [0u8; 32];
+        open_confirmation(ctx.accounts, merkle_root)
+    }
+
     /// Contributors vote to approve or reject the proposed finalization (#12).
-    /// Vote weight = their SOL contribution amount.
-    pub fn confirm_vote(ctx: Context<ConfirmVote>, approve: bool) -> Result<()> {
+    /// Vote weight = their SOL contribution amount, plus that of every contributor
+    /// who delegated to them (their ContributionRecords via `remaining_accounts`,
+    /// writable: each records the round and side it counted in).
+    pub fn confirm_vote<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConfirmVote<'info>>,
+        approve: bool,
+    ) -> Result<()> {
         let pool = &ctx.accounts.pool;
         require!(pool.status == PoolStatus::Confirming, LaunchError::NotConfirming);
         require!(Clock::get()?.unix_timestamp < pool.confirm_deadline, LaunchError::ConfirmExpired);
 
+        // A refunded contributor has no stake, even if the pool went back to Funding
         let record = &ctx.accounts.contribution;
-        require!(record.amount_lamports > 0, LaunchError::NoContribution);
+        require!(record.live_lamports() > 0, LaunchError::NoContribution);
+        require!(record.delegate == Pubkey::default(), LaunchError::DelegationConflict);
+
+        // Delegated weight. Each delegator can name only one delegate, and the
+        // delegate votes once per epoch, so no contribution is counted twice.
+        let contributor_key = ctx.accounts.contributor.key();
+        let mut delegated_lamports: u64 = 0;
+        let mut delegators: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+        for info in ctx.remaining_accounts.iter() {
+            let mut delegator = Account::<ContributionRecord>::try_from(info)?;
+            require!(info.is_writable, LaunchError::DelegationConflict);
+            require!(delegator.pool == pool.key(), LaunchError::DelegationConflict);
+            require!(delegator.delegate == contributor_key, LaunchError::DelegationConflict);
+            require!(!delegators.contains(&delegator.contributor), LaunchError::DelegationConflict);
+            require!(delegator.live_lamports() > 0, LaunchError::NoContribution);
+            delegated_lamports = delegated_lamports
+                .checked_add(delegator.live_lamports())
+                .ok_or(LaunchError::MathOverflow)?;
+            delegators.push(delegator.contributor);
+            // Marked so a refund this round can take the weight back out
+            delegator.voted_epoch = pool.proposal_epoch;
+            delegator.voted_approve = approve;
+            delegator.exit(&crate::ID)?;
+        }
 
+        // The record is per epoch (PDA seed), so a vote only counts for the
+        // confirmation round it was cast in and earlier rounds can't bleed in
         let vote = &mut ctx.accounts.confirmation_vote;
-        require!(!vote.has_voted, LaunchError::AlreadyVoted);
+        require!(
+            !(vote.has_voted && vote.epoch == pool.proposal_epoch),
+            LaunchError::AlreadyVoted
+        );
 
         vote.pool = pool.key();
         vote.contributor = ctx.accounts.contributor.key();
         vote.approve = approve;
-        vote.weight = record.amount_lamports;
+        vote.weight = record
+            .live_lamports()
+            .checked_add(delegated_lamports)
+            .ok_or(LaunchError::MathOverflow)?;
         vote.has_voted = true;
+        vote.epoch = pool.proposal_epoch;
         vote.bump = ctx.bumps.confirmation_vote;
 
+        let record = &mut ctx.accounts.contribution;
+        record.voted_epoch = pool.proposal_epoch;
+        record.voted_approve = approve;
+
         let pool = &mut ctx.accounts.pool;
+        let voters = 1 + delegators.len() as u32;
         if approve {
-            pool.approve_lamports += vote.weight;
+            pool.approve_lamports =
+                pool.approve_lamports.checked_add(vote.weight).ok_or(LaunchError::MathOverflow)?;
+            pool.approve_count = pool.approve_count.checked_add(voters).ok_or(LaunchError::MathOverflow)?;
         } else {
-            pool.reject_lamports += vote.weight;
+            pool.reject_lamports =
+                pool.reject_lamports.checked_add(vote.weight).ok_or(LaunchError::MathOverflow)?;
+            pool.reject_count = pool.reject_count.checked_add(voters).ok_or(LaunchError::MathOverflow)?;
         }
 
         emit!(ConfirmationVoteCast {
@@ -215,10 +661,72 @@ pub mod contracts {
             contributor: ctx.accounts.contributor.key(),
             approve,
             weight: vote.weight,
+            delegators: delegators.len() as u32,
             total_approve: pool.approve_lamports,
             total_reject: pool.reject_lamports,
         });
 
+        if pool.mark_majority_reached() {
+            emit!(ConfirmationThresholdCrossed {
+                pool: pool.key(),
+                epoch: pool.proposal_epoch,
+                total_approve: pool.approve_lamports,
+                total_reject: pool.reject_lamports,
+                approve_count: pool.approve_count,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Hand this contributor's confirmation weight to another contributor.
+    /// Permanent for the pool; the delegator can no longer vote directly.
+    /// Delegation is one hop: the delegate must not have delegated or been
+    /// refunded, and a contributor others delegated to can't delegate.
+    pub fn delegate_vote(ctx: Context<DelegateVote>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        require!(
+            pool.is_pre_finalize() || pool.status == PoolStatus::Confirming,
+            LaunchError::PoolNotFunding
+        );
+
+        let record = &mut ctx.accounts.contribution;
+        require!(record.amount_lamports > 0, LaunchError::NoContribution);
+        require!(!record.refunded, LaunchError::AlreadyRefunded);
+        require!(record.delegate == Pubkey::default(), LaunchError::AlreadyDelegated);
+        require!(record.delegator_count == 0, LaunchError::DelegationConflict);
+        require!(ctx.accounts.delegate.key() != record.contributor, LaunchError::DelegationConflict);
+        let delegate_record = &ctx.accounts.delegate_contribution;
+        require!(
+            delegate_record.delegate == Pubkey::default() && !delegate_record.refunded,
+            LaunchError::DelegationConflict
+        );
+
+        // Can't delegate weight that already counted in the current round
+        let vote_info = ctx.accounts.confirmation_vote.to_account_info();
+        if pool.status == PoolStatus::Confirming && !vote_info.data_is_empty() {
+            let data = vote_info.try_borrow_data()?;
+            let vote = ConfirmationVoteRecord::try_deserialize(&mut &data[..])?;
+            require!(
+                !(vote.has_voted && vote.epoch == pool.proposal_epoch),
+                LaunchError::DelegationConflict
+            );
+        }
+
+        record.delegate = ctx.accounts.delegate.key();
+        let delegate_record = &mut ctx.accounts.delegate_contribution;
+        delegate_record.delegator_count = delegate_record
+            .delegator_count
+            .checked_add(1)
+            .ok_or(LaunchError::MathOverflow)?;
+
+        emit!(VoteDelegated {
+            pool: pool.key(),
+            delegator: record.contributor,
+            delegate: record.delegate,
+            weight: record.amount_lamports,
+        });
+
         Ok(())
     }
 
@@ -226,15 +734,42 @@ pub mod contracts {
     /// Can be called by anyone once majority approves.
     pub fn execute_distribution(ctx: Context<ExecuteDistribution>) -> Result<()> {
         let pool = &ctx.accounts.pool;
+        // Independent of status, so a reordered status write can never mint twice
+        require!(!pool.distribution_executed, LaunchError::AlreadyDistributed);
         require!(!pool.paused, LaunchError::PoolPaused);
         require!(pool.status == PoolStatus::Confirming, LaunchError::NotConfirming);
+        // Pause-timeout refunds can run while Confirming; what's left must still fund
+        require!(pool.soft_cap_reached(), LaunchError::SoftCapNotReached);
+        require!(
+            pool.contributor_count >= pool.min_contributors,
+            LaunchError::NotEnoughContributors
+        );
 
         // Check majority: approve > reject (weighted by SOL contribution)
         require!(pool.approve_lamports > pool.reject_lamports, LaunchError::NotApproved);
+        // ...by the pool's threshold, if it asks for more than a bare majority
+        require!(pool.supermajority_met(), LaunchError::SupermajorityNotMet);
+        // ...and enough distinct contributors approved, so one whale can't carry it
+        require!(
+            pool.approve_count >= pool.min_approving_contributors,
+            LaunchError::NotEnoughApprovers
+        );
+        require!(pool.winner_payout_ready(), LaunchError::WinnerPayoutNotRegistered);
+        require!(pool.winner_bond_ready(), LaunchError::WinnerBondRequired);
+        require!(
+            pool.current_lamports >= pool.min_distribution_lamports,
+            LaunchError::PoolTooSmallToDistribute
+        );
 
-        // Calculate SOL splits
-        let total_sol = pool.current_lamports;
-        let winner_sol = total_sol * WINNER_SHARE_BPS / 10000;
+        // Calculate splits — the same numbers preview_distribution reports
+        let split = pool.preview_distribution(Clock::get()?.unix_timestamp)?;
+        let total_sol = split.total_sol;
+        let winner_sol = split.winner_sol;
+        let platform_sol = split.platform_sol;
+        if winner_sol == 0 {
+            // Only possible without a distribution floor; PoolFinalized reports it too
+            msg!("Winner SOL share rounds to zero ({} lamports in pool)", total_sol);
+        }
 
         let pool_id = pool.pool_id.clone();
         let authority = pool.authority;
@@ -242,12 +777,33 @@ pub mod contracts {
         let seeds = &[b"pool" as &[u8], authority.as_ref(), pool_id.as_bytes(), &[bump]];
         let signer_seeds = &[&seeds[..]];
 
-        // Transfer 5% SOL to winner
-        **ctx.accounts.pool.to_account_info().try_borrow_mut_lamports()? -= winner_sol;
-        **ctx.accounts.winner.to_account_info().try_borrow_mut_lamports()? += winner_sol;
+        // Transfer 5% SOL to winner — or into escrow if the pool uses one
+        let winner_sol_to = if ctx.accounts.pool.escrows_winner_sol() {
+            let escrow = ctx
+                .accounts
+                .winner_escrow
+                .as_mut()
+                .ok_or(LaunchError::WinnerEscrowRequired)?;
+            escrow.pool = ctx.accounts.pool.key();
+            escrow.winner = ctx.accounts.winner.key();
+            escrow.bump = ctx.bumps.winner_escrow.unwrap();
+            escrow.to_account_info()
+        } else {
+            ctx.accounts.winner.to_account_info()
+        };
+        debit_pool(&ctx.accounts.pool.to_account_info(), &winner_sol_to, winner_sol)?;
+
+        // Platform fee in SOL mode: 1% SOL to the platform wallet instead of tokens
+        if platform_sol > 0 {
+            debit_pool(
+                &ctx.accounts.pool.to_account_info(),
+                &ctx.accounts.platform_wallet.to_account_info(),
+                platform_sol,
+            )?;
+        }
 
         // Mint total token supply
-        let total_tokens = TOKEN_SUPPLY * 10u64.pow(TOKEN_DECIMALS as u32);
+        let total_tokens = split.total_tokens;
         token::mint_to(
             CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
@@ -261,24 +817,31 @@ pub mod contracts {
             total_tokens,
         )?;
 
-        // Transfer 1% tokens to platform
-        let platform_tokens = total_tokens * PLATFORM_SHARE_BPS / 10000;
-        token::transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                token::Transfer {
-                    from: ctx.accounts.pool_token_account.to_account_info(),
-                    to: ctx.accounts.platform_token_account.to_account_info(),
-                    authority: ctx.accounts.pool.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            platform_tokens,
-        )?;
+        // Transfer 1% tokens to platform (token fee mode only)
+        let platform_tokens = split.platform_tokens;
+        if platform_tokens > 0 {
+            let platform_token_account = ctx
+                .accounts
+                .platform_token_account
+                .as_ref()
+                .ok_or(LaunchError::InvalidTokenAccount)?;
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.pool_token_account.to_account_info(),
+                        to: platform_token_account.to_account_info(),
+                        authority: ctx.accounts.pool.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                platform_tokens,
+            )?;
+        }
 
-        let contributor_tokens = total_tokens * CONTRIBUTOR_SHARE_BPS / 10000;
+        let contributor_tokens = split.contributor_tokens;
         let pool = &mut ctx.accounts.pool;
-        pool.status = PoolStatus::Distributing;
+        pool.record_distribution(total_sol, winner_sol, Clock::get()?.unix_timestamp);
 
         emit!(PoolFinalized {
             pool: pool.key(),
@@ -286,23 +849,96 @@ pub mod contracts {
             token_mint: ctx.accounts.token_mint.key(),
             total_sol,
             winner_sol,
+            platform_sol,
             contributor_tokens,
             platform_tokens,
+            distributed_at: pool.distributed_at,
+        });
+
+        Ok(())
+    }
+
+    /// The proposed winner registers their token account for the launch mint.
+    /// Required before distribution when the pool sets `require_winner_payout`.
+    /// When the pool requires a winner bond, the first registration also deposits it.
+    pub fn register_winner_payout(ctx: Context<RegisterWinnerPayout>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        require!(pool.status == PoolStatus::Confirming, LaunchError::NotConfirming);
+
+        if pool.winner_bond_lamports > 0 && !pool.winner_bond_deposited {
+            let amount = pool.winner_bond_lamports;
+            let deadline = Clock::get()?.unix_timestamp + pool.milestone_period_secs;
+            let (Some(bond), Some(system)) =
+                (ctx.accounts.bond.as_mut(), ctx.accounts.system_program.as_ref())
+            else {
+                return err!(LaunchError::WinnerBondRequired);
+            };
+
+            system_program::transfer(
+                CpiContext::new(
+                    system.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.winner.to_account_info(),
+                        to: bond.to_account_info(),
+                    },
+                ),
+                amount,
+            )?;
+
+            bond.pool = ctx.accounts.pool.key();
+            bond.winner = ctx.accounts.winner.key();
+            bond.amount_lamports = amount;
+            bond.deadline = deadline;
+            bond.milestone_met = false;
+            bond.bump = ctx.bumps.bond.unwrap();
+
+            emit!(WinnerBondDeposited {
+                pool: bond.pool,
+                winner: bond.winner,
+                amount_lamports: amount,
+                deadline,
+            });
+            ctx.accounts.pool.winner_bond_deposited = true;
+        }
+
+        let pool = &mut ctx.accounts.pool;
+        pool.winner_payout = ctx.accounts.winner_payout.key();
+
+        emit!(WinnerPayoutRegistered {
+            pool: pool.key(),
+            winner: ctx.accounts.winner.key(),
+            winner_payout: pool.winner_payout,
         });
 
         Ok(())
     }
 
     /// Handle expired confirmation: if deadline passes without majority approve, auto-cancel.
+    /// A majority reject returns the pool to Funding instead while re-proposals remain.
     pub fn expire_confirmation(ctx: Context<ExpireConfirmation>) -> Result<()> {
         let pool = &ctx.accounts.pool;
         require!(pool.status == PoolStatus::Confirming, LaunchError::NotConfirming);
-        require!(Clock::get()?.unix_timestamp >= pool.confirm_deadline, LaunchError::ConfirmNotExpired);
+        let now = Clock::get()?.unix_timestamp;
+        require!(now >= pool.confirm_deadline, LaunchError::ConfirmNotExpired);
 
-        // If approve didn't win, cancel
-        if pool.approve_lamports <= pool.reject_lamports {
+        // Majority rejected and re-proposals remain: back to Funding for a new winner
+        if pool.reject_lamports > pool.approve_lamports
+            && pool.refinalize_count < pool.max_refinalizations
+        {
+            let pool = &mut ctx.accounts.pool;
+            pool.reset_for_refinalize();
+
+            emit!(FinalizationRejected {
+                pool: pool.key(),
+                refinalize_count: pool.refinalize_count,
+            });
+        } else if !pool.is_approved() {
+            // If approve didn't win, cancel
             let pool = &mut ctx.accounts.pool;
             pool.status = PoolStatus::Cancelled;
+            pool.cancelled_at = now;
+            pool.cancelled_by_expiry = true;
+            pool.cancelled_lamports = pool.current_lamports;
 
             emit!(PoolCancelled { pool: pool.key() });
         } else {
@@ -313,26 +949,105 @@ pub mod contracts {
         Ok(())
     }
 
+    /// The proposed winner turns the role down. With re-proposals left the pool
+    /// returns to Funding/Funded for `propose_finalize` to name a replacement
+    /// (never the declined wallet); otherwise it is cancelled so contributors can refund.
+    pub fn decline_win(ctx: Context<DeclineWin>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(pool.status == PoolStatus::Confirming, LaunchError::NotConfirming);
+
+        let winner = pool.winner;
+        pool.declined_winner = winner;
+        let refinalize = pool.refinalize_count < pool.max_refinalizations;
+        if refinalize {
+            pool.reset_for_refinalize();
+        } else {
+            pool.status = PoolStatus::Cancelled;
+            pool.cancelled_at = Clock::get()?.unix_timestamp;
+            pool.cancelled_by_expiry = false;
+            pool.cancelled_lamports = pool.current_lamports;
+        }
+
+        emit!(WinnerDeclined {
+            pool: pool.key(),
+            winner,
+            refinalize,
+        });
+        if !refinalize {
+            emit!(PoolCancelled { pool: pool.key() });
+        }
+        Ok(())
+    }
+
     /// Claim tokens as a contributor.
     pub fn claim(ctx: Context<Claim>) -> Result<()> {
+        let accounts = ctx.accounts;
+        settle_claim(
+            &mut accounts.pool,
+            &mut accounts.contribution,
+            &accounts.pool_token_account,
+            &accounts.contributor_token_account,
+            &accounts.token_program,
+            accounts.contributor.key(),
+            None,
+        )
+    }
+
+    /// Claim part of the allocation: `amount` tokens, at most what is still
+    /// unclaimed. The allocation is fixed by the first claim, so the rest can
+    /// be taken later with claim_amount or claim.
+    pub fn claim_amount(ctx: Context<Claim>, amount: u64) -> Result<()> {
+        require!(amount > 0, LaunchError::InvalidAmount);
+        let accounts = ctx.accounts;
+        settle_claim(
+            &mut accounts.pool,
+            &mut accounts.contribution,
+            &accounts.pool_token_account,
+            &accounts.contributor_token_account,
+            &accounts.token_program,
+            accounts.contributor.key(),
+            Some(amount),
+        )
+    }
+
+    /// Push a contributor's claim into their own token account. Anyone may call
+    /// (an operator airdrop); same guards as `claim`, and the destination must be
+    /// owned by the contributor, so the caller can't redirect the tokens.
+    pub fn claim_for(ctx: Context<ClaimFor>) -> Result<()> {
+        let accounts = ctx.accounts;
+        settle_claim(
+            &mut accounts.pool,
+            &mut accounts.contribution,
+            &accounts.pool_token_account,
+            &accounts.contributor_token_account,
+            &accounts.token_program,
+            accounts.caller.key(),
+            None,
+        )
+    }
+
+    /// Once every contributor has claimed, send the pool token account's excess
+    /// over the retained winner share (LaunchPool::retained_tokens, burned by
+    /// close_pool) to the platform. LaunchPool::claim_amount gives the last
+    /// claimant the remainder, so the excess is only tokens sent to the account
+    /// from outside. Anyone can call — the destination is fixed.
+    pub fn sweep_dust(ctx: Context<SweepDust>) -> Result<()> {
         let pool = &ctx.accounts.pool;
         require!(!pool.paused, LaunchError::PoolPaused);
         require!(
             pool.status == PoolStatus::Distributing || pool.status == PoolStatus::Complete,
             LaunchError::PoolNotDistributing
         );
-
-        let record = &mut ctx.accounts.contribution;
-        require!(!record.claimed, LaunchError::AlreadyClaimed);
-        require!(record.amount_lamports > 0, LaunchError::NoContribution);
-
-        let total_tokens = TOKEN_SUPPLY * 10u64.pow(TOKEN_DECIMALS as u32);
-        let contributor_tokens = total_tokens * CONTRIBUTOR_SHARE_BPS / 10000;
-        let user_tokens = (contributor_tokens as u128)
-            .checked_mul(record.amount_lamports as u128)
-            .unwrap()
-            .checked_div(pool.current_lamports as u128)
-            .unwrap() as u64;
+        require!(
+            pool.claimed_count == pool.distribution_contributor_count,
+            LaunchError::ClaimsOutstanding
+        );
+        let amount = ctx
+            .accounts
+            .pool_token_account
+            .amount
+            .saturating_sub(pool.retained_tokens()?);
+        require!(amount > 0, LaunchError::NoDust);
 
         let pool_id = pool.pool_id.clone();
         let authority = pool.authority;
@@ -345,56 +1060,126 @@ pub mod contracts {
                 ctx.accounts.token_program.to_account_info(),
                 token::Transfer {
                     from: ctx.accounts.pool_token_account.to_account_info(),
-                    to: ctx.accounts.contributor_token_account.to_account_info(),
+                    to: ctx.accounts.platform_token_account.to_account_info(),
                     authority: ctx.accounts.pool.to_account_info(),
                 },
                 signer_seeds,
             ),
-            user_tokens,
+            amount,
         )?;
 
-        record.claimed = true;
-
-        emit!(TokensClaimed {
-            pool: pool.key(),
-            contributor: ctx.accounts.contributor.key(),
-            tokens: user_tokens,
+        emit!(DustSwept {
+            pool: ctx.accounts.pool.key(),
+            amount,
+            total_claimed_tokens: ctx.accounts.pool.total_claimed_tokens,
         });
-
         Ok(())
     }
 
     /// Refund: if pool is cancelled or deadline passed without finalization.
-    /// Always available even when paused (#14).
+    /// Always available even when paused (#14). Past-deadline and expiry-cancelled
+    /// refunds withhold refund_penalty_bps for the platform wallet.
+    /// A pause that outlasts max_pause_secs also frees SOL in any status before
+    /// distribution; while Confirming the refunded weight leaves the vote tallies.
     pub fn refund(ctx: Context<Refund>) -> Result<()> {
-        let pool = &ctx.accounts.pool;
-        require!(
-            pool.status == PoolStatus::Cancelled
-                || (pool.status == PoolStatus::Funding
-                    && Clock::get()?.unix_timestamp > pool.deadline),
-            LaunchError::RefundNotAvailable
-        );
+        let reason = ctx.accounts.pool.refund_reason(Clock::get()?.unix_timestamp)?;
 
         let record = &mut ctx.accounts.contribution;
+        require!(!record.refunded, LaunchError::AlreadyRefunded);
         require!(!record.claimed, LaunchError::AlreadyClaimed);
         require!(record.amount_lamports > 0, LaunchError::NoContribution);
 
-        let refund_amount = record.amount_lamports;
-
-        **ctx.accounts.pool.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
-        **ctx.accounts.contributor.to_account_info().try_borrow_mut_lamports()? += refund_amount;
+        let platform_wallet = ctx.accounts.platform_wallet.as_ref().map(|w| w.to_account_info());
+        settle_refund(
+            &mut ctx.accounts.pool,
+            record,
+            &ctx.accounts.contributor.to_account_info(),
+            platform_wallet.as_ref(),
+            reason,
+        )
+    }
 
-        record.claimed = true;
+    /// Operator wind-down of a cancelled pool: refund many contributors in one
+    /// transaction. `remaining_accounts` holds (ContributionRecord, contributor)
+    /// pairs, both writable. Records already refunded or claimed are skipped.
+    /// Same penalty rules as refund. Requires multisig signer.
+    pub fn refund_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefundBatch<'info>>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.pool.status == PoolStatus::Cancelled,
+            LaunchError::RefundNotAvailable
+        );
+        require!(
+            !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len().is_multiple_of(2),
+            LaunchError::InvalidBatch
+        );
+        let reason = RefundReason::cancelled(&ctx.accounts.pool);
+        let platform_wallet = ctx.accounts.platform_wallet.as_ref().map(|w| w.to_account_info());
 
-        let pool = &mut ctx.accounts.pool;
-        pool.current_lamports -= refund_amount;
+        let pool_key = ctx.accounts.pool.key();
+        let lamports_before = ctx.accounts.pool.current_lamports;
+        let mut refunded: u32 = 0;
+        let mut skipped: u32 = 0;
+        for pair in ctx.remaining_accounts.chunks(2) {
+            let mut record = Account::<ContributionRecord>::try_from(&pair[0])?;
+            require!(
+                record.pool == pool_key && record.contributor == pair[1].key(),
+                LaunchError::InvalidBatch
+            );
+            if record.refunded || record.claimed || record.amount_lamports == 0 {
+                skipped += 1;
+                continue;
+            }
+            settle_refund(
+                &mut ctx.accounts.pool,
+                &mut record,
+                &pair[1],
+                platform_wallet.as_ref(),
+                reason,
+            )?;
+            // Written back now so a repeated pair is skipped
+            record.exit(&crate::ID)?;
+            refunded += 1;
+        }
 
-        emit!(ContributionRefunded {
-            pool: pool.key(),
-            contributor: ctx.accounts.contributor.key(),
-            amount_lamports: refund_amount,
+        emit!(BatchRefunded {
+            pool: pool_key,
+            refunded,
+            skipped,
+            total_lamports: lamports_before - ctx.accounts.pool.current_lamports,
         });
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Program-wide kill switch
+    // ═══════════════════════════════════════════════════
 
+    /// Create the ProgramConfig, administered by `multisig`. One per program;
+    /// only the program's upgrade authority may create it. Until it exists
+    /// the program counts as not paused.
+    pub fn initialize_program_config(ctx: Context<InitializeProgramConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.program_config;
+        config.admin = ctx.accounts.multisig.key();
+        config.paused = false;
+        config.bump = ctx.bumps.program_config;
+        Ok(())
+    }
+
+    /// Pause or resume contribute, propose_finalize, execute_distribution and
+    /// claim across every pool. Refunds stay open. Requires a signer of the
+    /// super-admin multisig.
+    pub fn set_program_pause(ctx: Context<SetProgramPause>, paused: bool) -> Result<()> {
+        let config = &mut ctx.accounts.program_config;
+        if paused {
+            require!(!config.paused, LaunchError::AlreadyPaused);
+        } else {
+            require!(config.paused, LaunchError::NotPaused);
+        }
+        config.paused = paused;
+
+        emit!(ProgramPauseSet { paused });
         Ok(())
     }
 
@@ -402,12 +1187,14 @@ pub mod contracts {
     // Emergency pause (#14)
     // ═══════════════════════════════════════════════════
 
-    /// Pause the pool. Blocks all operations except refund.
+    /// Pause the pool. Blocks all operations except refund; once the pause
+    /// outlasts max_pause_secs, contributors may refund whatever the status.
     /// Requires multisig signer.
     pub fn pause_pool(ctx: Context<MultisigAction>) -> Result<()> {
         let pool = &mut ctx.accounts.pool;
         require!(!pool.paused, LaunchError::AlreadyPaused);
         pool.paused = true;
+        pool.paused_at = Clock::get()?.unix_timestamp;
 
         emit!(PoolPaused { pool: pool.key() });
         Ok(())
@@ -418,33 +1205,419 @@ pub mod contracts {
         let pool = &mut ctx.accounts.pool;
         require!(pool.paused, LaunchError::NotPaused);
         pool.paused = false;
+        pool.paused_at = 0;
 
         emit!(PoolUnpaused { pool: pool.key() });
         Ok(())
     }
 
+    /// Replace the pool's metadata URI. Only before distribution. Requires
+    /// multisig signer.
+    pub fn update_metadata_uri(ctx: Context<MultisigAction>, metadata_uri: String) -> Result<()> {
+        require!(metadata_uri.len() <= MAX_METADATA_URI_LEN, LaunchError::MetadataTooLong);
+        let pool = &mut ctx.accounts.pool;
+        require!(!pool.distribution_executed, LaunchError::MetadataLocked);
+
+        pool.metadata_uri = metadata_uri;
+
+        emit!(MetadataUriUpdated {
+            pool: pool.key(),
+            metadata_uri: pool.metadata_uri.clone(),
+        });
+        Ok(())
+    }
+
+    /// Redirect the platform fee to a new treasury. Only before distribution,
+    /// so settled economics never change. Requires multisig signer.
+    pub fn set_platform_wallet(ctx: Context<SetPlatformWallet>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(
+            (pool.is_pre_finalize() || pool.status == PoolStatus::Confirming)
+                && !pool.distribution_executed,
+            LaunchError::PlatformWalletLocked
+        );
+
+        let old_wallet = pool.platform_wallet;
+        pool.platform_wallet = ctx.accounts.new_platform_wallet.key();
+
+        emit!(PlatformWalletUpdated {
+            pool: pool.key(),
+            old_wallet,
+            new_wallet: pool.platform_wallet,
+        });
+        Ok(())
+    }
+
+    /// Change the confirmation window a future propose_finalize will open.
+    /// Only before finalization (Funding or Funded); same bounds as create_pool.
+    /// Requires multisig signer.
+    pub fn set_confirm_duration(ctx: Context<MultisigAction>, confirm_duration_secs: i64) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        require!(pool.is_pre_finalize(), LaunchError::PoolNotFunding);
+        require!(confirm_duration_secs >= MIN_CONFIRM_SECS, LaunchError::ConfirmTooShort);
+        require!(confirm_duration_secs <= MAX_CONFIRM_SECS, LaunchError::ConfirmTooLong);
+
+        let old_duration_secs = pool.confirm_duration_secs;
+        pool.confirm_duration_secs = confirm_duration_secs;
+
+        emit!(ConfirmDurationUpdated {
+            pool: pool.key(),
+            old_duration_secs,
+            new_duration_secs: confirm_duration_secs,
+        });
+        Ok(())
+    }
+
     /// Cancel a pool. Requires multisig signer.
     pub fn cancel_pool(ctx: Context<MultisigAction>) -> Result<()> {
         let pool = &mut ctx.accounts.pool;
         require!(
-            pool.status == PoolStatus::Funding || pool.status == PoolStatus::Confirming,
+            pool.is_pre_finalize() || pool.status == PoolStatus::Confirming,
             LaunchError::PoolNotFunding
         );
 
         pool.status = PoolStatus::Cancelled;
+        pool.cancelled_at = Clock::get()?.unix_timestamp;
+        pool.cancelled_by_expiry = false;
+        pool.cancelled_lamports = pool.current_lamports;
         emit!(PoolCancelled { pool: pool.key() });
         Ok(())
     }
 
-    // ═══════════════════════════════════════════════════
-    // Complete + burn mint authority (#16)
-    // ═══════════════════════════════════════════════════
+    /// Reopen confirmation on a pool that expired without approval (e.g. low turnout).
+    /// Only within REOPEN_GRACE_SECS of the cancellation, only if no refunds have been
+    /// taken since, and only if contributors didn't reject outright. Requires multisig signer.
+    pub fn reopen_confirmation(ctx: Context<MultisigAction>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut ctx.accounts.pool;
+        require!(pool.can_reopen(now), LaunchError::ReopenNotAllowed);
+
+        pool.status = PoolStatus::Confirming;
+        pool.confirm_deadline = now + pool.confirm_duration_secs;
+        pool.approve_lamports = 0;
+        pool.reject_lamports = 0;
+        pool.approve_count = 0;
+        pool.reject_count = 0;
+        pool.majority_reached = false;
+        pool.proposal_epoch += 1;
+        pool.cancelled_at = 0;
+        pool.cancelled_by_expiry = false;
+
+        emit!(ConfirmationReopened {
+            pool: pool.key(),
+            confirm_deadline: pool.confirm_deadline,
+        });
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Winner bond — accountability for the operations share
+    // ═══════════════════════════════════════════════════
+
+    /// Multisig attests the winner hit a milestone. The bond (if any) becomes
+    /// releasable, and on escrow pools each milestone unlocks one winner tranche.
+    pub fn record_milestone(ctx: Context<RecordMilestone>) -> Result<()> {
+        let winner = match ctx.accounts.bond.as_mut() {
+            Some(bond) => {
+                bond.milestone_met = true;
+                bond.winner
+            }
+            None => {
+                require!(ctx.accounts.pool.escrow_winner_sol, LaunchError::WinnerBondRequired);
+                ctx.accounts.pool.winner
+            }
+        };
+        let pool = &mut ctx.accounts.pool;
+        pool.milestone_count = pool.milestone_count.checked_add(1).ok_or(LaunchError::MathOverflow)?;
+
+        emit!(MilestoneRecorded {
+            pool: pool.key(),
+            winner,
+            milestone_count: pool.milestone_count,
+        });
+        Ok(())
+    }
+
+    /// Milestone missed: after the deadline the multisig forfeits the bond to the platform.
+    /// Only the bond of the winner the pool distributed to, once it has.
+    pub fn slash_winner_bond(ctx: Context<SlashWinnerBond>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let bond = &ctx.accounts.bond;
+        require!(pool.status == PoolStatus::Distributing, LaunchError::PoolNotDistributing);
+        require!(bond.winner == pool.winner, LaunchError::WrongWinner);
+        require!(!bond.milestone_met, LaunchError::MilestoneMet);
+        require!(
+            Clock::get()?.unix_timestamp > bond.deadline,
+            LaunchError::MilestoneDeadlineActive
+        );
+        ctx.accounts.pool.winner_bond_deposited = false;
+
+        emit!(WinnerBondSlashed {
+            pool: bond.pool,
+            winner: bond.winner,
+            amount_lamports: bond.amount_lamports,
+        });
+        // Account closed to the platform wallet (see `close =`)
+        Ok(())
+    }
+
+    /// Return the bond to the winner once the milestone is met, or if this
+    /// winner's finalization never went through.
+    pub fn release_winner_bond(ctx: Context<ReleaseWinnerBond>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let bond = &ctx.accounts.bond;
+        require!(
+            bond.milestone_met
+                || pool.winner != bond.winner
+                || pool.status == PoolStatus::Cancelled,
+            LaunchError::BondNotReleasable
+        );
+
+        emit!(WinnerBondReleased {
+            pool: bond.pool,
+            winner: bond.winner,
+            amount_lamports: bond.amount_lamports,
+        });
+        // Account closed to the winner (see `close =`)
+        Ok(())
+    }
+
+    /// Release part of the escrowed winner SOL, as bps of the winner's share.
+    /// Multisig-gated; each tranche needs its own record_milestone first.
+    /// Never exceeds the share.
+    pub fn release_winner_tranche(ctx: Context<ReleaseWinnerTranche>, tranche_bps: u16) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        require!(pool.escrow_winner_sol, LaunchError::WinnerEscrowRequired);
+        require!(tranche_bps > 0, LaunchError::InvalidAmount);
+        require!(pool.tranche_count < pool.milestone_count, LaunchError::MilestoneNotRecorded);
+
+        let amount = (pool.winner_escrow_lamports as u128)
+            .checked_mul(tranche_bps as u128)
+            .ok_or(LaunchError::MathOverflow)?
+            / 10000;
+        let amount = u64::try_from(amount).map_err(|_| LaunchError::MathOverflow)?;
+        let released = pool
+            .winner_released_lamports
+            .checked_add(amount)
+            .ok_or(LaunchError::MathOverflow)?;
+        require!(released <= pool.winner_escrow_lamports, LaunchError::WinnerOverRelease);
+
+        debit_pool(
+            &ctx.accounts.winner_escrow.to_account_info(),
+            &ctx.accounts.winner.to_account_info(),
+            amount,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.winner_released_lamports = released;
+        pool.tranche_count = pool.tranche_count.checked_add(1).ok_or(LaunchError::MathOverflow)?;
+
+        emit!(WinnerTrancheReleased {
+            pool: pool.key(),
+            winner: pool.winner,
+            amount_lamports: amount,
+            released_lamports: released,
+            total_lamports: pool.winner_escrow_lamports,
+        });
+        Ok(())
+    }
+
+    /// Release the whole escrowed winner SOL once chant_audit has declared a
+    /// Champion matching the pool's winner and merkle root. Anyone can call
+    /// this — the Champion account is the proof.
+    pub fn release_to_winner(ctx: Context<ReleaseToWinner>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        require!(!pool.paused, LaunchError::PoolPaused);
+        require!(pool.require_champion_proof, LaunchError::WinnerEscrowRequired);
+        check_champion(
+            pool,
+            &ctx.accounts.chant,
+            &ctx.accounts.champion,
+            &pool.winner,
+            &pool.merkle_root,
+        )?;
+
+        let amount = pool.winner_escrow_lamports - pool.winner_released_lamports;
+        require!(amount > 0, LaunchError::WinnerOverRelease);
+
+        debit_pool(
+            &ctx.accounts.winner_escrow.to_account_info(),
+            &ctx.accounts.winner.to_account_info(),
+            amount,
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.winner_released_lamports = pool.winner_escrow_lamports;
+
+        emit!(WinnerReleasedByChampion {
+            pool: pool.key(),
+            winner: pool.winner,
+            champion: ctx.accounts.champion.key(),
+            amount_lamports: amount,
+        });
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Token metadata (Metaplex)
+    // ═══════════════════════════════════════════════════
+
+    /// Attach name / symbol / uri to pool.token_mint through Metaplex Token
+    /// Metadata, with the pool PDA as mint and update authority. Only while
+    /// Distributing: the mint is final and complete_pool hasn't burned its
+    /// authority yet. Requires multisig signer, who pays the metadata rent.
+    pub fn create_token_metadata(
+        ctx: Context<CreateTokenMetadata>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        require!(
+            name.len() <= MAX_TOKEN_NAME_LEN
+                && symbol.len() <= MAX_TOKEN_SYMBOL_LEN
+                && uri.len() <= MAX_TOKEN_URI_LEN,
+            LaunchError::TokenMetadataTooLong
+        );
+        let pool = &ctx.accounts.pool;
+        require!(pool.status == PoolStatus::Distributing, LaunchError::PoolNotDistributing);
+
+        let pool_id = pool.pool_id.clone();
+        let authority = pool.authority;
+        let bump = pool.bump;
+        let seeds = &[b"pool" as &[u8], authority.as_ref(), pool_id.as_bytes(), &[bump]];
+
+        let accounts = &ctx.accounts;
+        create_metadata_account(
+            MetadataCpi {
+                metadata: accounts.metadata.to_account_info(),
+                mint: accounts.token_mint.to_account_info(),
+                pool: accounts.pool.to_account_info(),
+                payer: accounts.signer.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+                token_metadata_program: accounts.token_metadata_program.to_account_info(),
+            },
+            &name,
+            &symbol,
+            &uri,
+            &[&seeds[..]],
+        )?;
+
+        emit!(TokenMetadataCreated {
+            pool: ctx.accounts.pool.key(),
+            token_mint: ctx.accounts.token_mint.key(),
+            metadata: ctx.accounts.metadata.key(),
+            name,
+            symbol,
+            uri,
+        });
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Contribution receipts
+    // ═══════════════════════════════════════════════════
+
+    /// Mint the contributor a 0-decimal, supply-1 receipt NFT for their
+    /// ContributionRecord (mint PDA ["receipt", record]), e.g. right after
+    /// contribute in the same transaction. With the optional Metaplex accounts
+    /// the receipt gets metadata naming the amount at mint time ("<n> lamports") and pointing
+    /// at the pool's metadata_uri. One receipt per record; it is informational
+    /// only — claim and refund still go through the record, not the NFT.
+    pub fn mint_contribution_receipt(ctx: Context<MintContributionReceipt>) -> Result<()> {
+        let record = &ctx.accounts.contribution;
+        let receipt_name = record.receipt_name()?;
+        let amount_lamports = record.amount_lamports;
 
-    /// Mark pool as complete and permanently burn the token mint authority.
-    /// After this, no more tokens can ever be minted. Supply is fixed forever.
-    pub fn complete_pool(ctx: Context<CompletePool>) -> Result<()> {
         let pool = &ctx.accounts.pool;
+        let pool_id = pool.pool_id.clone();
+        let authority = pool.authority;
+        let bump = pool.bump;
+        let seeds = &[b"pool" as &[u8], authority.as_ref(), pool_id.as_bytes(), &[bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.receipt_mint.to_account_info(),
+                    to: ctx.accounts.receipt_token_account.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            1,
+        )?;
+
+        // Metadata needs the mint authority, so it goes before the authority is burned
+        let accounts = &ctx.accounts;
+        if let (Some(metadata), Some(token_metadata_program)) =
+            (&accounts.metadata, &accounts.token_metadata_program)
+        {
+            create_metadata_account(
+                MetadataCpi {
+                    metadata: metadata.to_account_info(),
+                    mint: accounts.receipt_mint.to_account_info(),
+                    pool: accounts.pool.to_account_info(),
+                    payer: accounts.contributor.to_account_info(),
+                    system_program: accounts.system_program.to_account_info(),
+                    token_metadata_program: token_metadata_program.to_account_info(),
+                },
+                &receipt_name,
+                RECEIPT_SYMBOL,
+                &accounts.pool.metadata_uri,
+                signer_seeds,
+            )?;
+        }
+
+        // Fix supply at 1
+        token::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: ctx.accounts.pool.to_account_info(),
+                    account_or_mint: ctx.accounts.receipt_mint.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+
+        emit!(ContributionReceiptMinted {
+            pool: ctx.accounts.pool.key(),
+            contributor: ctx.accounts.contributor.key(),
+            receipt_mint: ctx.accounts.receipt_mint.key(),
+            amount_lamports,
+        });
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Complete + burn mint authority (#16)
+    // ═══════════════════════════════════════════════════
+
+    /// Start the cooling-off period before complete_pool may burn the mint
+    /// authority, giving contributors time to react.
+    pub fn propose_complete(ctx: Context<MultisigAction>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
         require!(pool.status == PoolStatus::Distributing, LaunchError::PoolNotDistributing);
+        require!(pool.complete_eligible_at == 0, LaunchError::CompleteTimelockActive);
+
+        pool.complete_eligible_at = Clock::get()?.unix_timestamp + pool.complete_delay_secs;
+
+        emit!(CompleteProposed {
+            pool: pool.key(),
+            eligible_at: pool.complete_eligible_at,
+        });
+        Ok(())
+    }
+
+    /// Mark pool as complete and permanently burn the token mint authority.
+    /// After this, no more tokens can ever be minted. Supply is fixed forever.
+    /// Only after propose_complete's timelock has elapsed.
+    pub fn complete_pool(ctx: Context<CompletePool>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        pool.check_completable(Clock::get()?.unix_timestamp)?;
 
         let pool_id = pool.pool_id.clone();
         let authority = pool.authority;
@@ -476,6 +1649,502 @@ pub mod contracts {
 
         Ok(())
     }
+
+    /// Alternative terminal action to complete_pool: hand mint authority to
+    /// `new_authority` (e.g. a DAO governance program) instead of burning it.
+    /// The pool PDA can never mint again. Same timelock as complete_pool.
+    pub fn transfer_mint_authority(ctx: Context<CompletePool>, new_authority: Pubkey) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        pool.check_completable(Clock::get()?.unix_timestamp)?;
+        // Burning is complete_pool's job; keeping it on the pool would be a no-op
+        require!(
+            new_authority != Pubkey::default() && new_authority != pool.key(),
+            LaunchError::InvalidNewMintAuthority
+        );
+
+        let pool_id = pool.pool_id.clone();
+        let authority = pool.authority;
+        let bump = pool.bump;
+        let seeds = &[b"pool" as &[u8], authority.as_ref(), pool_id.as_bytes(), &[bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        token::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    account_or_mint: ctx.accounts.token_mint.to_account_info(),
+                    current_authority: ctx.accounts.pool.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            AuthorityType::MintTokens,
+            Some(new_authority),
+        )?;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.status = PoolStatus::Complete;
+
+        emit!(MintAuthorityTransferred {
+            pool: pool.key(),
+            token_mint: pool.token_mint,
+            new_authority,
+        });
+        emit!(PoolCompleted {
+            pool: pool.key(),
+            mint_authority_burned: false,
+        });
+
+        Ok(())
+    }
+
+    /// Close a settled pool and return its rent to the multisig. Only once the
+    /// pool is Complete, every contributor has claimed and the pool token
+    /// account holds no more than the retained winner share (see sweep_dust).
+    /// That share was never allocated — the winner is paid in SOL — so it is
+    /// burned, and the token account is closed to the multisig as well.
+    /// Requires multisig signer.
+    /// The pool's ContributionRecords outlive it, so its pool_id must not be
+    /// reused for a new pool under the same multisig.
+    pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let retained = ctx.accounts.pool_token_account.amount;
+        require!(
+            pool.status == PoolStatus::Complete
+                && pool.claimed_count == pool.distribution_contributor_count
+                && retained <= pool.retained_tokens()?,
+            LaunchError::PoolNotCloseable
+        );
+
+        let pool_id = pool.pool_id.clone();
+        let authority = pool.authority;
+        let bump = pool.bump;
+        let seeds = &[b"pool" as &[u8], authority.as_ref(), pool_id.as_bytes(), &[bump]];
+        let signer_seeds = &[&seeds[..]];
+
+        if retained > 0 {
+            token::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Burn {
+                        mint: ctx.accounts.token_mint.to_account_info(),
+                        from: ctx.accounts.pool_token_account.to_account_info(),
+                        authority: ctx.accounts.pool.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                retained,
+            )?;
+        }
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.pool_token_account.to_account_info(),
+                destination: ctx.accounts.multisig.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        let pool = &ctx.accounts.pool;
+        emit!(PoolClosed {
+            pool: pool.key(),
+            lamports_returned: pool.to_account_info().lamports(),
+            tokens_burned: retained,
+        });
+        // Account closed to the multisig (see `close =`)
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════
+    // Views
+    // ═══════════════════════════════════════════════════
+
+    /// Bitmask of ACTION_* flags for instructions currently valid on this pool,
+    /// given its status, pause state and timing. Signer checks are not considered.
+    pub fn allowed_actions(ctx: Context<ViewPool>) -> Result<u16> {
+        let now = Clock::get()?.unix_timestamp;
+        Ok(ctx.accounts.pool.allowed_actions(now))
+    }
+
+    /// Unique contributors vs. contribution transactions, plus the largest
+    /// single contributor's total — for spotting concentration — and turnout
+    /// of the current confirmation round.
+    pub fn get_participation_stats(ctx: Context<ViewPool>) -> Result<ParticipationStats> {
+        let pool = &ctx.accounts.pool;
+        Ok(ParticipationStats {
+            contributor_count: pool.contributor_count,
+            contribution_tx_count: pool.contribution_tx_count,
+            total_lamports: pool.current_lamports,
+            max_single_contributor_lamports: pool.max_single_contributor_lamports,
+            total_eligible_lamports: pool.total_eligible_lamports,
+            turnout_bps: pool.turnout_bps(),
+        })
+    }
+
+    /// Tokens a contributor gets from `claim`. Before execute_distribution this is
+    /// an estimate against the live pool, flagged provisional — later
+    /// contributions and refunds move it.
+    pub fn get_my_allocation(ctx: Context<ViewClaim>) -> Result<MyAllocation> {
+        ctx.accounts.pool.my_allocation(&ctx.accounts.contribution)
+    }
+
+    /// A contributor's token allocation, what they've claimed, and what `claim`
+    /// would pay right now. Tokens unlock all at once at distribution, so
+    /// currently_claimable is the whole unclaimed allocation while claims are open.
+    pub fn get_claimable(ctx: Context<ViewClaim>) -> Result<Claimable> {
+        ctx.accounts.pool.claimable(&ctx.accounts.contribution)
+    }
+
+    /// What execute_distribution would pay out if called now, from current pool
+    /// state and the pool's split settings. Needs no token accounts.
+    pub fn preview_distribution(ctx: Context<ViewPool>) -> Result<DistributionPreview> {
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts.pool.preview_distribution(now)
+    }
+
+    /// Current confirmation round, by weight and by head count.
+    pub fn get_vote_tally(ctx: Context<ViewPool>) -> Result<VoteTally> {
+        let pool = &ctx.accounts.pool;
+        Ok(VoteTally {
+            proposal_epoch: pool.proposal_epoch,
+            approve_lamports: pool.approve_lamports,
+            reject_lamports: pool.reject_lamports,
+            approve_count: pool.approve_count,
+            reject_count: pool.reject_count,
+            contributor_count: pool.contributor_count,
+        })
+    }
+
+    /// A multisig's signers, threshold and nonce.
+    pub fn get_multisig_info(ctx: Context<ViewMultisig>) -> Result<MultisigInfo> {
+        Ok(ctx.accounts.multisig.info())
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════
+// Helpers
+// ═══════════════════════════════════════════════════════════════
+
+/// The Champion must be declared for the chant whose chant_id == pool_id, run
+/// by the pool's chant_authority (anyone can create a chant under any id), and
+/// name the same winner and vote root.
+fn check_champion(
+    pool: &LaunchPool,
+    chant: &Account<Chant>,
+    champion: &Champion,
+    winner: &Pubkey,
+    merkle_root: &[u8; 32],
+) -> Result<()> {
+    require!(
+        pool.chant_authority != Pubkey::default() && chant.authority == pool.chant_authority,
+        LaunchError::UntrustedChant
+    );
+    let expected =
+        Pubkey::try_find_program_address(&[b"chant", pool.pool_id.as_bytes()], &chant_audit::ID)
+            .map(|(key, _)| key);
+    require!(expected == Some(chant.key()), LaunchError::ChampionMismatch);
+    require!(champion.chant == chant.key(), LaunchError::ChampionMismatch);
+    require!(champion.winner_wallet == *winner, LaunchError::ChampionMismatch);
+    require!(champion.vote_root == *merkle_root, LaunchError::ChampionMismatch);
+    Ok(())
+}
+
+/// Token Metadata's CreateMetadataAccountV3 for `mint`. Built by hand — the
+/// instruction is small and this avoids pulling in the Metaplex crate. The
+/// pool signs as both mint authority and update authority.
+pub fn create_metadata_instruction(
+    metadata: Pubkey,
+    mint: Pubkey,
+    pool: Pubkey,
+    payer: Pubkey,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+) -> Result<anchor_lang::solana_program::instruction::Instruction> {
+    let mut data = vec![CREATE_METADATA_ACCOUNT_V3];
+    // DataV2: name, symbol, uri, seller_fee_basis_points
+    (name, symbol, uri, 0u16).serialize(&mut data)?;
+    // creators, collection, uses: None; is_mutable: true; collection_details: None
+    data.extend_from_slice(&[0, 0, 0, 1, 0]);
+
+    Ok(anchor_lang::solana_program::instruction::Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(metadata, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(pool, true),  // mint authority
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(pool, true),  // update authority
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data,
+    })
+}
+
+/// CPI with create_metadata_instruction, the pool signing.
+fn create_metadata_account(
+    accounts: MetadataCpi,
+    name: &str,
+    symbol: &str,
+    uri: &str,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let MetadataCpi { metadata, mint, pool, payer, system_program, token_metadata_program } = accounts;
+    let ix = create_metadata_instruction(
+        metadata.key(),
+        mint.key(),
+        pool.key(),
+        payer.key(),
+        name,
+        symbol,
+        uri,
+    )?;
+    anchor_lang::solana_program::program::invoke_signed(
+        &ix,
+        &[
+            metadata,
+            mint,
+            pool,
+            payer,
+            system_program,
+            token_metadata_program,
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+/// Accounts for create_metadata_account; pool is mint and update authority.
+struct MetadataCpi<'info> {
+    metadata: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    pool: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    token_metadata_program: AccountInfo<'info>,
+}
+
+/// propose_finalize / reveal_finalize: check the proposal and open confirmation.
+fn open_confirmation(accounts: &mut ProposeFinalize, merkle_root: [u8; 32]) -> Result<()> {
+    let pool = &accounts.pool;
+    require!(!pool.paused, LaunchError::PoolPaused);
+    require!(pool.is_pre_finalize(), LaunchError::PoolNotFunding);
+    require!(pool.current_lamports > 0, LaunchError::NoContributions);
+    require!(pool.soft_cap_reached(), LaunchError::SoftCapNotReached);
+    require!(
+        pool.contributor_count >= pool.min_contributors,
+        LaunchError::NotEnoughContributors
+    );
+    // Back in Funding/Funded after an earlier proposal means a rejected finalization
+    let is_refinalize = pool.proposal_epoch > 0;
+    require!(
+        !is_refinalize || pool.refinalize_count < pool.max_refinalizations,
+        LaunchError::MaxRefinalizationsReached
+    );
+    require!(
+        accounts.token_mint.decimals == pool.token_decimals,
+        LaunchError::DecimalsMismatch
+    );
+    require!(
+        accounts.winner.key() != pool.declined_winner,
+        LaunchError::WinnerAlreadyDeclined
+    );
+    require!(
+        !pool.forbid_signer_winner || !accounts.multisig.is_signer(&accounts.winner.key()),
+        LaunchError::WinnerIsSigner
+    );
+
+    // Optional binding to the on-chain deliberation result (chant_audit)
+    if let Some(champion) = &accounts.champion {
+        let chant = accounts.chant.as_ref().ok_or(LaunchError::UntrustedChant)?;
+        check_champion(pool, chant, champion, &accounts.winner.key(), &merkle_root)?;
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let confirm_deadline = now + pool.confirm_duration_secs;
+
+    let pool = &mut accounts.pool;
+    pool.status = PoolStatus::Confirming;
+    pool.winner = accounts.winner.key();
+    pool.token_mint = accounts.token_mint.key();
+    pool.merkle_root = merkle_root;
+    pool.confirm_deadline = confirm_deadline;
+    pool.approve_lamports = 0;
+    pool.reject_lamports = 0;
+    pool.approve_count = 0;
+    pool.reject_count = 0;
+    pool.majority_reached = false;
+    pool.winner_payout = Pubkey::default();
+    pool.proposal_epoch += 1;
+    pool.total_eligible_lamports = pool.current_lamports;
+    if is_refinalize {
+        pool.refinalize_count += 1;
+    }
+
+    emit!(pool.finalize_proposed(pool.key()));
+
+    Ok(())
+}
+
+/// Commitment reveal_finalize checks: keccak(winner ++ merkle_root).
+pub fn finalize_param_hash(winner: &Pubkey, merkle_root: &[u8; 32]) -> [u8; 32] {
+    solana_keccak_hasher::hashv(&[winner.as_ref(), merkle_root]).to_bytes()
+}
+
+/// Pay a record's contribution back, less the pool's penalty when `reason` is
+/// penalized (which then needs the platform wallet). Shared by refund and refund_batch;
+/// callers check the record is refundable.
+fn settle_refund<'info>(
+    pool: &mut Account<'info, LaunchPool>,
+    record: &mut ContributionRecord,
+    contributor: &AccountInfo<'info>,
+    platform_wallet: Option<&AccountInfo<'info>>,
+    reason: RefundReason,
+) -> Result<()> {
+    let contributed = record.amount_lamports;
+    let penalty = if reason.is_penalized() {
+        pool.refund_penalty(contributed)
+    } else {
+        0
+    };
+    let refund_amount = contributed - penalty;
+
+    debit_pool(&pool.to_account_info(), contributor, refund_amount)?;
+    if penalty > 0 {
+        let platform_wallet = platform_wallet.ok_or(LaunchError::WrongPlatformWallet)?;
+        debit_pool(&pool.to_account_info(), platform_wallet, penalty)?;
+    }
+
+    record.refunded = true;
+
+    // A refunded wallet no longer counts toward the floor or the last claim
+    pool.current_lamports -= contributed;
+    pool.contributor_count -= 1;
+
+    // Mid-confirmation (pause timeout): the SOL that left can't keep voting
+    if pool.status == PoolStatus::Confirming {
+        pool.total_eligible_lamports = pool.total_eligible_lamports.saturating_sub(contributed);
+        if record.voted_epoch == pool.proposal_epoch {
+            if record.voted_approve {
+                pool.approve_lamports -= contributed;
+                pool.approve_count -= 1;
+            } else {
+                pool.reject_lamports -= contributed;
+                pool.reject_count -= 1;
+            }
+        }
+    }
+    // Below the ceiling again, so contributions reopen
+    if pool.status == PoolStatus::Funded && pool.current_lamports < pool.funding_ceiling() {
+        pool.status = PoolStatus::Funding;
+    }
+
+    emit!(ContributionRefunded {
+        pool: pool.key(),
+        contributor: contributor.key(),
+        amount_lamports: refund_amount,
+        penalty_lamports: penalty,
+        reason,
+    });
+
+    Ok(())
+}
+
+/// Move escrowed SOL out of the pool PDA.
+/// The pool is program-owned and holds data, so the system program can't debit it;
+/// lamports are moved directly, but never below the pool's rent-exempt reserve.
+fn debit_pool<'info>(pool: &AccountInfo<'info>, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+    let rent_reserve = Rent::get()?.minimum_balance(pool.data_len());
+    let available = pool.lamports().saturating_sub(rent_reserve);
+    require!(amount <= available, LaunchError::InsufficientPoolBalance);
+
+    **pool.try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+/// Pay a contributor's token share from the pool: `amount` tokens (claim_amount)
+/// or everything still unclaimed (claim / claim_for).
+fn settle_claim<'info>(
+    pool: &mut Account<'info, LaunchPool>,
+    record: &mut Account<'info, ContributionRecord>,
+    pool_token_account: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    claimed_by: Pubkey,
+    amount: Option<u64>,
+) -> Result<()> {
+    require!(!pool.paused, LaunchError::PoolPaused);
+    require!(
+        pool.status == PoolStatus::Distributing || pool.status == PoolStatus::Complete,
+        LaunchError::PoolNotDistributing
+    );
+
+    require!(!record.claimed, LaunchError::AlreadyClaimed);
+    require!(!record.refunded, LaunchError::AlreadyRefunded);
+    require!(record.amount_lamports > 0, LaunchError::NoContribution);
+
+    let allocation = pool.claim_amount(record)?;
+    let unclaimed = allocation
+        .checked_sub(record.claimed_tokens)
+        .ok_or(LaunchError::MathOverflow)?;
+    let user_tokens = match amount {
+        Some(amount) => {
+            require!(amount <= unclaimed, LaunchError::ClaimExceedsAllocation);
+            amount
+        }
+        None => unclaimed,
+    };
+
+    let pool_id = pool.pool_id.clone();
+    let authority = pool.authority;
+    let bump = pool.bump;
+    let seeds = &[b"pool" as &[u8], authority.as_ref(), pool_id.as_bytes(), &[bump]];
+    let signer_seeds = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: pool_token_account.to_account_info(),
+                to: to.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        user_tokens,
+    )?;
+
+    if record.allocation_tokens.is_none() {
+        record.allocation_tokens = Some(allocation);
+        pool.allocated_count = pool.allocated_count.checked_add(1).ok_or(LaunchError::MathOverflow)?;
+        pool.total_allocated_tokens = pool
+            .total_allocated_tokens
+            .checked_add(allocation)
+            .ok_or(LaunchError::MathOverflow)?;
+    }
+    record.claimed_tokens = record
+        .claimed_tokens
+        .checked_add(user_tokens)
+        .ok_or(LaunchError::MathOverflow)?;
+    pool.total_claimed_tokens = pool
+        .total_claimed_tokens
+        .checked_add(user_tokens)
+        .ok_or(LaunchError::MathOverflow)?;
+    if record.claimed_tokens == allocation {
+        record.claimed = true;
+        pool.claimed_count = pool.claimed_count.checked_add(1).ok_or(LaunchError::MathOverflow)?;
+    }
+
+    emit!(TokensClaimed {
+        pool: pool.key(),
+        contributor: record.contributor,
+        tokens: user_tokens,
+        claimed_by,
+        remaining_tokens: allocation - record.claimed_tokens,
+    });
+
+    Ok(())
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -502,16 +2171,16 @@ pub struct CreateMultisig<'info> {
 #[derive(Accounts)]
 #[instruction(target_lamports: u64, deadline: i64, pool_id: String)]
 pub struct CreatePool<'info> {
+    /// CHECK: Pool PDA, created by create_pool after checking the id is unused.
     #[account(
-        init,
-        payer = payer,
-        space = LaunchPool::space(&pool_id),
+        mut,
         seeds = [b"pool", multisig.key().as_ref(), pool_id.as_bytes()],
         bump,
     )]
-    pub pool: Account<'info, LaunchPool>,
+    pub pool: UncheckedAccount<'info>,
 
-    /// Multisig authority that controls this pool.
+    /// Multisig authority that controls this pool; its pool counter is bumped.
+    #[account(mut)]
     pub multisig: Account<'info, Multisig>,
 
     /// One of the multisig signers must pay for pool creation.
@@ -536,6 +2205,14 @@ pub struct Contribute<'info> {
     )]
     pub pool: Account<'info, LaunchPool>,
 
+    /// CHECK: ProgramConfig PDA; may not be initialized yet (= not paused).
+    #[account(
+        seeds = [b"program_config"],
+        bump,
+        constraint = !ProgramConfig::is_paused(&program_config)? @ LaunchError::ProgramPaused,
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
     #[account(
         init_if_needed,
         payer = contributor,
@@ -548,6 +2225,22 @@ pub struct Contribute<'info> {
     #[account(mut)]
     pub contributor: Signer<'info>,
 
+    /// CHECK: ReferralStats PDA of the contribution's referrer, created on first use.
+    /// Required once the contribution has a referrer; address checked in contribute.
+    #[account(mut)]
+    pub referral_stats: Option<UncheckedAccount<'info>>,
+
+    /// Contributor's wSOL account holding exactly amount_lamports; closed
+    /// (unwrapped) to the contributor before the transfer. Needs token_program.
+    #[account(
+        mut,
+        constraint = wsol_account.mint == native_mint::ID @ LaunchError::InvalidTokenAccount,
+        constraint = wsol_account.owner == contributor.key() @ LaunchError::InvalidTokenAccount,
+    )]
+    pub wsol_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -560,6 +2253,14 @@ pub struct ProposeFinalize<'info> {
     )]
     pub pool: Account<'info, LaunchPool>,
 
+    /// CHECK: ProgramConfig PDA; may not be initialized yet (= not paused).
+    #[account(
+        seeds = [b"program_config"],
+        bump,
+        constraint = !ProgramConfig::is_paused(&program_config)? @ LaunchError::ProgramPaused,
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
     #[account(
         constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
     )]
@@ -578,6 +2279,13 @@ pub struct ProposeFinalize<'info> {
         constraint = token_mint.mint_authority.unwrap() == pool.key() @ LaunchError::InvalidMintAuthority,
     )]
     pub token_mint: Account<'info, Mint>,
+
+    /// Champion of the chant whose chant_id == pool_id. When passed, winner and
+    /// merkle_root must match what the deliberation declared.
+    pub champion: Option<Account<'info, Champion>>,
+
+    /// That chant; required with champion, and run by pool.chant_authority.
+    pub chant: Option<Box<Account<'info, Chant>>>,
 }
 
 #[derive(Accounts)]
@@ -590,6 +2298,7 @@ pub struct ConfirmVote<'info> {
     pub pool: Account<'info, LaunchPool>,
 
     #[account(
+        mut,
         seeds = [b"contribution", pool.key().as_ref(), contributor.key().as_ref()],
         bump = contribution.bump,
         has_one = contributor,
@@ -600,7 +2309,12 @@ pub struct ConfirmVote<'info> {
         init_if_needed,
         payer = contributor,
         space = ConfirmationVoteRecord::SPACE,
-        seeds = [b"confirm_vote", pool.key().as_ref(), contributor.key().as_ref()],
+        seeds = [
+            b"confirm_vote",
+            pool.key().as_ref(),
+            contributor.key().as_ref(),
+            &pool.proposal_epoch.to_le_bytes(),
+        ],
         bump,
     )]
     pub confirmation_vote: Account<'info, ConfirmationVoteRecord>,
@@ -611,6 +2325,47 @@ pub struct ConfirmVote<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct DelegateVote<'info> {
+    #[account(
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", pool.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump,
+        has_one = contributor,
+    )]
+    pub contribution: Account<'info, ContributionRecord>,
+
+    /// CHECK: Delegator's confirmation vote PDA for the current epoch; may not exist yet.
+    #[account(
+        seeds = [
+            b"confirm_vote",
+            pool.key().as_ref(),
+            contributor.key().as_ref(),
+            &pool.proposal_epoch.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub confirmation_vote: UncheckedAccount<'info>,
+
+    /// CHECK: Delegate wallet — must itself be a contributor (see below).
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", pool.key().as_ref(), delegate.key().as_ref()],
+        bump = delegate_contribution.bump,
+    )]
+    pub delegate_contribution: Account<'info, ContributionRecord>,
+
+    pub contributor: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct ExecuteDistribution<'info> {
     #[account(
@@ -620,6 +2375,14 @@ pub struct ExecuteDistribution<'info> {
     )]
     pub pool: Account<'info, LaunchPool>,
 
+    /// CHECK: ProgramConfig PDA; may not be initialized yet (= not paused).
+    #[account(
+        seeds = [b"program_config"],
+        bump,
+        constraint = !ProgramConfig::is_paused(&program_config)? @ LaunchError::ProgramPaused,
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
     /// CHECK: Must match pool.winner
     #[account(
         mut,
@@ -637,22 +2400,243 @@ pub struct ExecuteDistribution<'info> {
         mut,
         constraint = pool_token_account.owner == pool.key() @ LaunchError::InvalidTokenAccount,
         constraint = pool_token_account.mint == token_mint.key() @ LaunchError::InvalidTokenAccount,
+        constraint = pool_token_account.key() == get_associated_token_address(&pool.key(), &token_mint.key())
+            @ LaunchError::InvalidAssociatedAccount,
     )]
     pub pool_token_account: Account<'info, TokenAccount>,
 
+    /// Receives the 1% token fee. Not needed when the pool takes its fee in SOL.
     #[account(
         mut,
+        constraint = platform_token_account.owner == pool.platform_wallet @ LaunchError::WrongPlatformWallet,
         constraint = platform_token_account.mint == token_mint.key() @ LaunchError::InvalidTokenAccount,
     )]
-    pub platform_token_account: Account<'info, TokenAccount>,
+    pub platform_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Must match pool.platform_wallet. Receives the fee in SOL fee mode.
+    #[account(
+        mut,
+        constraint = platform_wallet.key() == pool.platform_wallet @ LaunchError::WrongPlatformWallet,
+    )]
+    pub platform_wallet: UncheckedAccount<'info>,
+
+    /// Holds the winner's SOL for tranche or champion-proof release. Only for
+    /// pools that escrow it.
+    #[account(
+        init,
+        payer = caller,
+        space = WinnerEscrow::SPACE,
+        seeds = [b"winner_escrow", pool.key().as_ref()],
+        bump,
+    )]
+    pub winner_escrow: Option<Account<'info, WinnerEscrow>>,
 
     /// Anyone can call this — no signer restriction. The contract enforces the rules.
+    /// Pays for the winner escrow when one is created.
+    #[account(mut)]
     pub caller: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct ReleaseWinnerTranche<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"winner_escrow", pool.key().as_ref()],
+        bump = winner_escrow.bump,
+        has_one = pool,
+        has_one = winner,
+    )]
+    pub winner_escrow: Account<'info, WinnerEscrow>,
+
+    /// CHECK: Must match the escrow's winner
+    #[account(mut)]
+    pub winner: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseToWinner<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        mut,
+        seeds = [b"winner_escrow", pool.key().as_ref()],
+        bump = winner_escrow.bump,
+        has_one = pool,
+        has_one = winner,
+    )]
+    pub winner_escrow: Account<'info, WinnerEscrow>,
+
+    /// CHECK: Must match the escrow's winner
+    #[account(mut)]
+    pub winner: UncheckedAccount<'info>,
+
+    /// Owned by chant_audit (Account checks the owner and discriminator).
+    pub champion: Account<'info, Champion>,
+
+    /// The champion's chant, run by pool.chant_authority.
+    pub chant: Box<Account<'info, Chant>>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterWinnerPayout<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        mut,
+        constraint = winner.key() == pool.winner @ LaunchError::WrongWinner,
+    )]
+    pub winner: Signer<'info>,
+
+    #[account(
+        constraint = winner_payout.mint == pool.token_mint @ LaunchError::InvalidTokenAccount,
+        constraint = winner_payout.owner == winner.key() @ LaunchError::InvalidTokenAccount,
+    )]
+    pub winner_payout: Account<'info, TokenAccount>,
+
+    /// Only when the pool requires a bond and it isn't deposited yet.
+    #[account(
+        init,
+        payer = winner,
+        space = WinnerBond::SPACE,
+        seeds = [b"bond", pool.key().as_ref(), winner.key().as_ref()],
+        bump,
+    )]
+    pub bond: Option<Account<'info, WinnerBond>>,
+
+    pub system_program: Option<Program<'info, System>>,
+}
+
+#[derive(Accounts)]
+pub struct RecordMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", pool.key().as_ref(), bond.winner.as_ref()],
+        bump = bond.bump,
+        has_one = pool,
+    )]
+    pub bond: Option<Account<'info, WinnerBond>>, // None on escrow pools without a bond
+}
+
+#[derive(Accounts)]
+pub struct SlashWinnerBond<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", pool.key().as_ref(), bond.winner.as_ref()],
+        bump = bond.bump,
+        has_one = pool,
+        close = platform_wallet,
+    )]
+    pub bond: Account<'info, WinnerBond>,
+
+    /// CHECK: Receives the slashed bond — must be the pool's platform wallet.
+    #[account(
+        mut,
+        constraint = platform_wallet.key() == pool.platform_wallet @ LaunchError::WrongPlatformWallet,
+    )]
+    pub platform_wallet: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseWinnerBond<'info> {
+    #[account(
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        mut,
+        seeds = [b"bond", pool.key().as_ref(), winner.key().as_ref()],
+        bump = bond.bump,
+        has_one = pool,
+        has_one = winner,
+        close = winner,
+    )]
+    pub bond: Account<'info, WinnerBond>,
+
+    #[account(mut)]
+    pub winner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DeclineWin<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        constraint = winner.key() == pool.winner @ LaunchError::WrongWinner,
+    )]
+    pub winner: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct ExpireConfirmation<'info> {
     #[account(
@@ -669,11 +2653,20 @@ pub struct ExpireConfirmation<'info> {
 #[derive(Accounts)]
 pub struct Claim<'info> {
     #[account(
+        mut,
         seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
         bump = pool.bump,
     )]
     pub pool: Account<'info, LaunchPool>,
 
+    /// CHECK: ProgramConfig PDA; may not be initialized yet (= not paused).
+    #[account(
+        seeds = [b"program_config"],
+        bump,
+        constraint = !ProgramConfig::is_paused(&program_config)? @ LaunchError::ProgramPaused,
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [b"contribution", pool.key().as_ref(), contributor.key().as_ref()],
@@ -689,6 +2682,8 @@ pub struct Claim<'info> {
         mut,
         constraint = pool_token_account.owner == pool.key() @ LaunchError::InvalidTokenAccount,
         constraint = pool_token_account.mint == pool.token_mint @ LaunchError::InvalidTokenAccount,
+        constraint = pool_token_account.key() == get_associated_token_address(&pool.key(), &pool.token_mint)
+            @ LaunchError::InvalidAssociatedAccount,
     )]
     pub pool_token_account: Account<'info, TokenAccount>,
 
@@ -702,7 +2697,7 @@ pub struct Claim<'info> {
 }
 
 #[derive(Accounts)]
-pub struct Refund<'info> {
+pub struct ClaimFor<'info> {
     #[account(
         mut,
         seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
@@ -710,6 +2705,14 @@ pub struct Refund<'info> {
     )]
     pub pool: Account<'info, LaunchPool>,
 
+    /// CHECK: ProgramConfig PDA; may not be initialized yet (= not paused).
+    #[account(
+        seeds = [b"program_config"],
+        bump,
+        constraint = !ProgramConfig::is_paused(&program_config)? @ LaunchError::ProgramPaused,
+    )]
+    pub program_config: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [b"contribution", pool.key().as_ref(), contributor.key().as_ref()],
@@ -718,152 +2721,1277 @@ pub struct Refund<'info> {
     )]
     pub contribution: Account<'info, ContributionRecord>,
 
-    #[account(mut)]
-    pub contributor: Signer<'info>,
+    /// CHECK: Contributor being paid; doesn't sign. Only used to derive the
+    /// contribution PDA and to check the destination's owner.
+    pub contributor: UncheckedAccount<'info>,
 
-    pub system_program: Program<'info, System>,
+    #[account(
+        mut,
+        constraint = pool_token_account.owner == pool.key() @ LaunchError::InvalidTokenAccount,
+        constraint = pool_token_account.mint == pool.token_mint @ LaunchError::InvalidTokenAccount,
+        constraint = pool_token_account.key() == get_associated_token_address(&pool.key(), &pool.token_mint)
+            @ LaunchError::InvalidAssociatedAccount,
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+
+    /// Must already exist and belong to the contributor.
+    #[account(
+        mut,
+        constraint = contributor_token_account.mint == pool.token_mint @ LaunchError::InvalidTokenAccount,
+        constraint = contributor_token_account.owner == contributor.key() @ LaunchError::InvalidTokenAccount,
+    )]
+    pub contributor_token_account: Account<'info, TokenAccount>,
+
+    /// Anyone — an operator or the multisig — may push the claim.
+    pub caller: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
 }
 
-/// Multisig-gated action (pause, unpause, cancel).
 #[derive(Accounts)]
-pub struct MultisigAction<'info> {
+pub struct SweepDust<'info> {
     #[account(
-        mut,
         seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
         bump = pool.bump,
     )]
     pub pool: Account<'info, LaunchPool>,
 
     #[account(
-        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
+        mut,
+        constraint = pool_token_account.owner == pool.key() @ LaunchError::InvalidTokenAccount,
+        constraint = pool_token_account.mint == pool.token_mint @ LaunchError::InvalidTokenAccount,
+        constraint = pool_token_account.key() == get_associated_token_address(&pool.key(), &pool.token_mint)
+            @ LaunchError::InvalidAssociatedAccount,
     )]
-    pub multisig: Account<'info, Multisig>,
+    pub pool_token_account: Account<'info, TokenAccount>,
 
     #[account(
-        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+        mut,
+        constraint = platform_token_account.owner == pool.platform_wallet @ LaunchError::WrongPlatformWallet,
+        constraint = platform_token_account.mint == pool.token_mint @ LaunchError::InvalidTokenAccount,
     )]
-    pub signer: Signer<'info>,
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
 }
 
 #[derive(Accounts)]
-pub struct CompletePool<'info> {
+pub struct Refund<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        mut,
+        seeds = [b"contribution", pool.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump,
+        has_one = contributor,
+    )]
+    pub contribution: Account<'info, ContributionRecord>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    /// CHECK: Receives the refund penalty — only needed when one applies.
+    #[account(
+        mut,
+        constraint = platform_wallet.key() == pool.platform_wallet @ LaunchError::WrongPlatformWallet,
+    )]
+    pub platform_wallet: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RefundBatch<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+
+    /// CHECK: Receives refund penalties — only needed when one applies.
+    #[account(
+        mut,
+        constraint = platform_wallet.key() == pool.platform_wallet @ LaunchError::WrongPlatformWallet,
+    )]
+    pub platform_wallet: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct ViewPool<'info> {
+    #[account(
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+}
+
+#[derive(Accounts)]
+pub struct ViewMultisig<'info> {
+    pub multisig: Account<'info, Multisig>,
+}
+
+#[derive(Accounts)]
+pub struct ViewClaim<'info> {
+    #[account(
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        seeds = [b"contribution", pool.key().as_ref(), contribution.contributor.as_ref()],
+        bump = contribution.bump,
+    )]
+    pub contribution: Account<'info, ContributionRecord>,
+}
+
+/// Multisig-gated action (pause, unpause, cancel).
+#[derive(Accounts)]
+pub struct MultisigAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeProgramConfig<'info> {
+    #[account(
+        init,
+        payer = signer,
+        space = ProgramConfig::SPACE,
+        seeds = [b"program_config"],
+        bump,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ LaunchError::NotUpgradeAuthority,
+    )]
+    pub program: Program<'info, crate::program::Contracts>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(signer.key())
+            @ LaunchError::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetProgramPause<'info> {
+    #[account(
+        mut,
+        seeds = [b"program_config"],
+        bump = program_config.bump,
+        constraint = program_config.admin == multisig.key() @ LaunchError::WrongAuthority,
+    )]
+    pub program_config: Account<'info, ProgramConfig>,
+
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPlatformWallet<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+
+    /// CHECK: New platform treasury — any wallet.
+    pub new_platform_wallet: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateTokenMetadata<'info> {
+    #[account(
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        mut,
+        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        constraint = token_mint.key() == pool.token_mint @ LaunchError::InvalidTokenAccount,
+        constraint = token_mint.mint_authority == Some(pool.key()).into() @ LaunchError::InvalidMintAuthority,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    /// CHECK: Metadata PDA of token_mint; created by the Token Metadata program.
+    #[account(
+        mut,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), token_mint.key().as_ref()],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Address-checked Metaplex Token Metadata program.
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintContributionReceipt<'info> {
+    #[account(
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Box<Account<'info, LaunchPool>>,
+
+    #[account(
+        seeds = [b"contribution", pool.key().as_ref(), contributor.key().as_ref()],
+        bump = contribution.bump,
+    )]
+    pub contribution: Box<Account<'info, ContributionRecord>>,
+
+    #[account(mut)]
+    pub contributor: Signer<'info>,
+
+    #[account(
+        init,
+        payer = contributor,
+        seeds = [b"receipt", contribution.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = pool,
+    )]
+    pub receipt_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = contributor,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = contributor,
+    )]
+    pub receipt_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Metadata PDA of receipt_mint; created by the Token Metadata program.
+    #[account(
+        mut,
+        seeds = [b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), receipt_mint.key().as_ref()],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Address-checked Metaplex Token Metadata program.
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CompletePool<'info> {
+    #[account(
+        mut,
+        seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
+        bump = pool.bump,
+    )]
+    pub pool: Account<'info, LaunchPool>,
+
+    #[account(
+        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = token_mint.key() == pool.token_mint @ LaunchError::InvalidTokenAccount,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePool<'info> {
     #[account(
         mut,
         seeds = [b"pool", pool.authority.as_ref(), pool.pool_id.as_bytes()],
         bump = pool.bump,
+        close = multisig,
     )]
     pub pool: Account<'info, LaunchPool>,
 
-    #[account(
-        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
-    )]
-    pub multisig: Account<'info, Multisig>,
+    #[account(
+        mut,
+        constraint = multisig.key() == pool.authority @ LaunchError::WrongAuthority,
+    )]
+    pub multisig: Account<'info, Multisig>,
+
+    #[account(
+        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = pool_token_account.key() == get_associated_token_address(&pool.key(), &pool.token_mint)
+            @ LaunchError::InvalidAssociatedAccount,
+    )]
+    pub pool_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = token_mint.key() == pool.token_mint @ LaunchError::InvalidTokenAccount,
+    )]
+    pub token_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ═══════════════════════════════════════════════════════════════
+// State
+// ═══════════════════════════════════════════════════════════════
+//
+// Layout break: every account below gained fields in the middle of the
+// layout the first devnet deployment wrote. Multisigs, pools, contribution
+// records and vote records created by that deployment don't deserialize here
+// (AccountDidNotDeserialize) and are not migrated — they are abandoned, along
+// with any devnet SOL they escrow. Fields added once this layout is deployed
+// go after `bump`, with a realloc migration instruction.
+
+/// 2-of-3 multisig authority (#11)
+#[account]
+pub struct Multisig {
+    pub signers: [Pubkey; 3],
+    pub threshold: u8,
+    pub nonce: u64,
+    pub pool_count: u64,                // Pools created under this multisig
+    pub last_pool_id: String,           // pool_id of the most recent create_pool
+    pub bump: u8,
+}
+
+impl Multisig {
+    pub const SPACE: usize = 8 + (32 * 3) + 1 + 8 + 8 + (4 + MAX_POOL_ID_LEN) + 1;
+
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+
+    /// get_multisig_info's typed view of the signer set.
+    pub fn info(&self) -> MultisigInfo {
+        MultisigInfo {
+            signers: self.signers,
+            threshold: self.threshold,
+            nonce: self.nonce,
+        }
+    }
+
+    /// Count a pool create_pool just made under this multisig.
+    pub fn record_pool(&mut self, pool_id: &str) -> Result<()> {
+        self.pool_count = self.pool_count.checked_add(1).ok_or(LaunchError::MathOverflow)?;
+        self.last_pool_id = pool_id.to_string();
+        Ok(())
+    }
+}
+
+/// Program-wide settings. Single PDA at [b"program_config"].
+#[account]
+pub struct ProgramConfig {
+    pub admin: Pubkey,                  // Super-admin multisig
+    pub paused: bool,                   // Global kill switch (refunds unaffected)
+    pub bump: u8,
+}
+
+impl ProgramConfig {
+    pub const SPACE: usize = 8 + 32 + 1 + 1;
+
+    /// Kill-switch state at the config PDA. Not initialized yet = not paused.
+    pub fn is_paused(info: &AccountInfo) -> Result<bool> {
+        if info.data_is_empty() {
+            return Ok(false);
+        }
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+        Ok(ProgramConfig::try_deserialize(&mut &info.try_borrow_data()?[..])?.paused)
+    }
+}
+
+#[account]
+pub struct LaunchPool {
+    pub authority: Pubkey,              // Multisig PDA
+    pub pool_id: String,                // Maps to UC deliberation ID
+    pub target_lamports: u64,
+    pub current_lamports: u64,
+    pub deadline: i64,
+    pub status: PoolStatus,
+    pub winner: Pubkey,
+    pub platform_wallet: Pubkey,
+    pub token_mint: Pubkey,
+    pub merkle_root: [u8; 32],          // Merkle root of deliberation votes (#13)
+    pub confirm_deadline: i64,          // When confirmation window ends (#15)
+    pub confirm_duration_secs: i64,     // Configurable confirmation duration
+    pub approve_lamports: u64,          // SOL-weighted approve votes (#12)
+    pub reject_lamports: u64,           // SOL-weighted reject votes (#12)
+    pub contributor_count: u32,
+    pub paused: bool,                   // Emergency pause (#14)
+    pub require_memo: bool,             // Contributions must carry a memo
+    pub total_claimed_tokens: u64,      // Contributor tokens claimed so far
+    pub claimed_count: u32,             // Contributors who have claimed in full
+    pub min_approving_contributors: u32, // Distinct approvers required to distribute
+    pub approve_count: u32,             // Distinct contributors who voted approve
+    pub token_supply: u64,              // Whole tokens minted on distribution
+    pub token_decimals: u8,             // Must match the mint's decimals
+    pub target_reached: bool,           // TargetReached already emitted
+    pub require_winner_payout: bool,    // Winner must register a payout account first
+    pub winner_payout: Pubkey,          // Winner's token account, set by the winner
+    pub distribution_executed: bool,    // execute_distribution has run (mint guard)
+    pub platform_fee_in_sol: bool,      // Platform takes its 1% in SOL, not tokens
+    pub proposal_epoch: u32,            // Confirmation round; votes from older rounds don't count
+    pub cancelled_at: i64,
+    pub cancelled_by_expiry: bool,      // Cancelled by expire_confirmation, not the multisig
+    pub cancelled_lamports: u64,        // current_lamports at cancellation
+    pub contribution_tx_count: u64,     // Every contribute call, incl. repeat wallets
+    pub max_single_contributor_lamports: u64, // Largest cumulative contribution by one wallet
+    pub total_eligible_lamports: u64,   // current_lamports snapshotted at propose_finalize
+    pub soft_cap_lamports: u64,         // Minimum to finalize (0 = none)
+    pub hard_cap_lamports: u64,         // Maximum accepted (0 = unlimited)
+    pub complete_delay_secs: i64,       // propose_complete → complete_pool timelock
+    pub complete_eligible_at: i64,      // 0 = completion not proposed
+    pub max_refinalizations: u8,        // Re-proposals allowed after a rejection (0 = none)
+    pub refinalize_count: u8,           // Re-proposals made so far
+    pub min_contributors: u32,          // Distinct wallets required to finalize (0 = none)
+    pub winner_bond_lamports: u64,      // Bond the winner must post (0 = none)
+    pub milestone_period_secs: i64,     // Deposit → milestone deadline
+    pub winner_bond_deposited: bool,    // Current winner has posted their bond
+    pub reject_count: u32,              // Distinct contributors who voted reject
+    pub escrow_winner_sol: bool,        // Winner SOL goes to escrow, released in tranches
+    pub winner_escrow_lamports: u64,    // Winner share placed in escrow
+    pub winner_released_lamports: u64,  // Released from escrow so far
+    pub min_distribution_lamports: u64, // Pool size floor for execute_distribution (0 = none)
+    pub distribution_snapshot_lamports: u64, // current_lamports at execute_distribution — claim denominator
+    pub distribution_contributor_count: u32, // contributor_count at execute_distribution
+    pub paused_at: i64,                 // When the current pause began (0 = not paused)
+    pub max_pause_secs: i64,            // Pause length after which refunds open up
+    pub declined_winner: Pubkey,        // Last winner to decline_win; can't be re-proposed
+    pub distributed_at: i64,            // execute_distribution time (0 = not yet) — vesting / grace reference
+    pub refund_penalty_bps: u16,        // Withheld to platform_wallet on past-deadline / expiry refunds
+    pub require_champion_proof: bool,   // Winner SOL escrowed until release_to_winner sees the Champion
+    pub allowlist_required: bool,       // Contributors must prove membership in allowlist_root
+    pub allowlist_root: [u8; 32],       // Merkle root of approved wallets (see is_allowlisted)
+    pub metadata_uri: String,           // Project name / logo / description (≤ MAX_METADATA_URI_LEN)
+    pub majority_reached: bool,         // ConfirmationThresholdCrossed fired this epoch
+    pub max_contributor_bps: u16,       // One wallet's cap as bps of target_lamports (0 = none)
+    pub finalize_commitment: [u8; 32],  // commit_finalize hash awaiting reveal (zero = none)
+    pub approval_threshold_bps: u16,    // Approving share of voted SOL needed (5000 = majority)
+    pub contribution_cutoff_secs: i64,  // Quiet period before the deadline with no contributions
+    pub allocated_count: u32,           // Records whose allocation is fixed (first claim)
+    pub total_allocated_tokens: u64,    // Sum of those fixed allocations
+    pub forbid_signer_winner: bool,     // Winner may not be a multisig signer
+    pub chant_authority: Pubkey,        // Authority of the chant champions must come from (default = none)
+    pub require_finalize_commit: bool,  // Finalize only via commit_finalize + reveal_finalize
+    pub milestone_count: u16,           // record_milestone calls so far
+    pub tranche_count: u16,             // release_winner_tranche calls so far (≤ milestone_count)
+    pub next_sequence: u32,             // Sequence the next new contributor gets; never decreases
+    pub bump: u8,
+}
+
+impl LaunchPool {
+    /// Pool PDA — must match the `seeds` on every pool account constraint.
+    pub fn pda(authority: &Pubkey, pool_id: &str) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"pool", authority.as_ref(), pool_id.as_bytes()], &crate::ID)
+    }
+
+    pub fn space(pool_id: &str) -> usize {
+        8 +                         // discriminator
+        32 +                        // authority
+        4 + pool_id.len() +         // pool_id
+        8 +                         // target_lamports
+        8 +                         // current_lamports
+        8 +                         // deadline
+        1 +                         // status
+        32 +                        // winner
+        32 +                        // platform_wallet
+        32 +                        // token_mint
+        32 +                        // merkle_root
+        8 +                         // confirm_deadline
+        8 +                         // confirm_duration_secs
+        8 +                         // approve_lamports
+        8 +                         // reject_lamports
+        4 +                         // contributor_count
+        1 +                         // paused
+        1 +                         // require_memo
+        8 +                         // total_claimed_tokens
+        4 +                         // claimed_count
+        4 +                         // min_approving_contributors
+        4 +                         // approve_count
+        8 +                         // token_supply
+        1 +                         // token_decimals
+        1 +                         // target_reached
+        1 +                         // require_winner_payout
+        32 +                        // winner_payout
+        1 +                         // distribution_executed
+        1 +                         // platform_fee_in_sol
+        4 +                         // proposal_epoch
+        8 +                         // cancelled_at
+        1 +                         // cancelled_by_expiry
+        8 +                         // cancelled_lamports
+        8 +                         // contribution_tx_count
+        8 +                         // max_single_contributor_lamports
+        8 +                         // total_eligible_lamports
+        8 +                         // soft_cap_lamports
+        8 +                         // hard_cap_lamports
+        8 +                         // complete_delay_secs
+        8 +                         // complete_eligible_at
+        1 +                         // max_refinalizations
+        1 +                         // refinalize_count
+        4 +                         // min_contributors
+        8 +                         // winner_bond_lamports
+        8 +                         // milestone_period_secs
+        1 +                         // winner_bond_deposited
+        4 +                         // reject_count
+        1 +                         // escrow_winner_sol
+        8 +                         // winner_escrow_lamports
+        8 +                         // winner_released_lamports
+        8 +                         // min_distribution_lamports
+        8 +                         // distribution_snapshot_lamports
+        4 +                         // distribution_contributor_count
+        8 +                         // paused_at
+        8 +                         // max_pause_secs
+        32 +                        // declined_winner
+        8 +                         // distributed_at
+        2 +                         // refund_penalty_bps
+        1 +                         // require_champion_proof
+        1 +                         // allowlist_required
+        32 +                        // allowlist_root
+        4 + MAX_METADATA_URI_LEN +  // metadata_uri (room for update_metadata_uri)
+        1 +                         // majority_reached
+        2 +                         // max_contributor_bps
+        32 +                        // finalize_commitment
+        2 +                         // approval_threshold_bps
+        8 +                         // contribution_cutoff_secs
+        4 +                         // allocated_count
+        8 +                         // total_allocated_tokens
+        1 +                         // forbid_signer_winner
+        32 +                        // chant_authority
+        1 +                         // require_finalize_commit
+        2 +                         // milestone_count
+        2 +                         // tranche_count
+        4 +                         // next_sequence
+        1                           // bump
+    }
+
+    /// Total base units minted: token_supply * 10^token_decimals.
+    pub fn total_tokens(&self) -> Result<u64> {
+        Self::checked_total_tokens(self.token_supply, self.token_decimals)
+    }
+
+    /// supply * 10^decimals, or SupplyOverflow if it doesn't fit in u64
+    /// (e.g. more than ~1.8e13 whole tokens at 6 decimals).
+    pub fn checked_total_tokens(supply: u64, decimals: u8) -> Result<u64> {
+        10u64
+            .checked_pow(decimals as u32)
+            .and_then(|scale| supply.checked_mul(scale))
+            .ok_or_else(|| error!(LaunchError::SupplyOverflow))
+    }
+
+    /// Tokens claimable by contributors. When the platform fee is paid in SOL,
+    /// the platform's token share goes to contributors instead.
+    pub fn contributor_tokens(&self) -> Result<u64> {
+        let share_bps = if self.platform_fee_in_sol {
+            CONTRIBUTOR_SHARE_BPS + PLATFORM_SHARE_BPS
+        } else {
+            CONTRIBUTOR_SHARE_BPS
+        };
+        Ok((self.total_tokens()? as u128 * share_bps as u128 / 10000) as u64)
+    }
+
+    /// The platform's 1% of the supply, in token fee mode only.
+    pub fn platform_tokens(&self) -> Result<u64> {
+        if self.platform_fee_in_sol {
+            return Ok(0);
+        }
+        Ok((self.total_tokens()? as u128 * PLATFORM_SHARE_BPS as u128 / 10000) as u64)
+    }
+
+    /// Tokens minted with the supply but never allocated: the winner's 5%,
+    /// which is paid in SOL instead. They sit in the pool token account until
+    /// close_pool burns them.
+    pub fn retained_tokens(&self) -> Result<u64> {
+        Ok(self.total_tokens()? - self.contributor_tokens()? - self.platform_tokens()?)
+    }
+
+    /// SOL and token splits for distributing the pool as it stands.
+    pub fn preview_distribution(&self, now: i64) -> Result<DistributionPreview> {
+        let total_sol = self.current_lamports;
+        let total_tokens = self.total_tokens()?;
+        Ok(DistributionPreview {
+            total_sol,
+            winner_sol: total_sol * WINNER_SHARE_BPS / 10000,
+            platform_sol: if self.platform_fee_in_sol {
+                total_sol * PLATFORM_SHARE_BPS / 10000
+            } else {
+                0
+            },
+            total_tokens,
+            contributor_tokens: self.contributor_tokens()?,
+            platform_tokens: self.platform_tokens()?,
+            executable: self.allowed_actions(now) & ACTION_EXECUTE_DISTRIBUTION != 0,
+        })
+    }
+
+    /// A record's total token allocation: fixed once it has claimed, otherwise
+    /// what it would be allocated now. The last record to be allocated takes
+    /// whatever is left, so flooring never strands dust. Both sides come from
+    /// the execute_distribution snapshot, not live counters.
+    pub fn claim_amount(&self, record: &ContributionRecord) -> Result<u64> {
+        if let Some(allocation) = record.allocation_tokens {
+            return Ok(allocation);
+        }
+        let contributor_tokens = self.contributor_tokens()?;
+        if self.allocated_count.checked_add(1) == Some(self.distribution_contributor_count) {
+            return contributor_tokens
+                .checked_sub(self.total_allocated_tokens)
+                .ok_or_else(|| error!(LaunchError::MathOverflow));
+        }
+        let share = (contributor_tokens as u128)
+            .checked_mul(record.amount_lamports as u128)
+            .ok_or(LaunchError::MathOverflow)?
+            .checked_div(self.distribution_snapshot_lamports as u128)
+            .ok_or(LaunchError::MathOverflow)?;
+        Ok(share as u64)
+    }
+
+    /// FinalizeProposed for the proposal just opened, with the turnout
+    /// denominator it's tallied against.
+    pub fn finalize_proposed(&self, pool: Pubkey) -> FinalizeProposed {
+        FinalizeProposed {
+            pool,
+            winner: self.winner,
+            token_mint: self.token_mint,
+            merkle_root: self.merkle_root,
+            confirm_deadline: self.confirm_deadline,
+            total_eligible_lamports: self.total_eligible_lamports,
+            contributor_count: self.contributor_count,
+        }
+    }
+
+    /// get_my_allocation's answer for a record.
+    pub fn my_allocation(&self, record: &ContributionRecord) -> Result<MyAllocation> {
+        let (user_tokens, provisional) = if record.claimed {
+            (record.claimed_tokens, false)
+        } else if record.refunded || record.amount_lamports == 0 {
+            (0, false)
+        } else if self.distribution_executed {
+            (self.claim_amount(record)?, false)
+        } else {
+            (self.estimate_claim_amount(record)?, true)
+        };
+
+        Ok(MyAllocation { user_tokens, provisional })
+    }
+
+    /// get_claimable's numbers for a record.
+    pub fn claimable(&self, record: &ContributionRecord) -> Result<Claimable> {
+        let (total_allocation, already_claimed) =
+            if record.refunded || record.amount_lamports == 0 || !self.distribution_executed {
+                (0, 0)
+            } else {
+                (self.claim_amount(record)?, record.claimed_tokens)
+            };
+        let claims_open = !self.paused
+            && (self.status == PoolStatus::Distributing || self.status == PoolStatus::Complete);
+
+        Ok(Claimable {
+            total_allocation,
+            already_claimed,
+            currently_claimable: if claims_open {
+                total_allocation - already_claimed
+            } else {
+                0
+            },
+        })
+    }
+
+    /// claim_amount's pro-rata share, taken against current_lamports as if the
+    /// pool were distributed now.
+    pub fn estimate_claim_amount(&self, record: &ContributionRecord) -> Result<u64> {
+        if self.current_lamports == 0 {
+            return Ok(0);
+        }
+        Ok((self.contributor_tokens()? as u128 * record.amount_lamports as u128
+            / self.current_lamports as u128) as u64)
+    }
+
+    /// Walks `proof` from keccak(ALLOWLIST_TAG ++ wallet) up to allowlist_root.
+    /// Pairs are hashed in sorted order, so proofs carry no left/right flags.
+    pub fn is_allowlisted(&self, wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        let mut node = hash::tagged_hash(ALLOWLIST_TAG, &[wallet.as_ref()]);
+        for sibling in proof {
+            node = if node <= *sibling {
+                hash::node_hash(&node, sibling)
+            } else {
+                hash::node_hash(sibling, &node)
+            };
+        }
+        node == self.allowlist_root
+    }
+
+    /// Winner SOL goes to the WinnerEscrow PDA rather than straight to the winner.
+    pub fn escrows_winner_sol(&self) -> bool {
+        self.escrow_winner_sol || self.require_champion_proof
+    }
+
+    /// Whether execute_distribution would pass its winner payout check.
+    pub fn winner_payout_ready(&self) -> bool {
+        !self.require_winner_payout || self.winner_payout != Pubkey::default()
+    }
+
+    /// Whether execute_distribution would pass its winner bond check.
+    pub fn winner_bond_ready(&self) -> bool {
+        self.winner_bond_lamports == 0 || self.winner_bond_deposited
+    }
+
+    /// Expiry-cancelled, still within the grace window, untouched by refunds,
+    /// and not rejected by an outright majority of contributed SOL.
+    pub fn can_reopen(&self, now: i64) -> bool {
+        self.status == PoolStatus::Cancelled
+            && self.cancelled_by_expiry
+            && now <= self.cancelled_at + REOPEN_GRACE_SECS
+            && self.current_lamports == self.cancelled_lamports
+            && (self.reject_lamports as u128) * 2 <= self.total_eligible_lamports as u128
+    }
+
+    /// Back to Funding (or Funded if full) with no winner, ready for a new
+    /// propose_finalize. Votes go stale via proposal_epoch.
+    pub fn reset_for_refinalize(&mut self) {
+        self.status = if self.current_lamports >= self.funding_ceiling() {
+            PoolStatus::Funded
+        } else {
+            PoolStatus::Funding
+        };
+        self.winner = Pubkey::default();
+        self.merkle_root = [0u8; 32];
+        self.confirm_deadline = 0;
+        self.winner_bond_deposited = false;
+    }
+
+    /// complete_pool / transfer_mint_authority guards: distributing, and
+    /// propose_complete's timelock has run out.
+    pub fn check_completable(&self, now: i64) -> Result<()> {
+        require!(self.status == PoolStatus::Distributing, LaunchError::PoolNotDistributing);
+        require!(self.complete_eligible_at != 0, LaunchError::CompleteNotProposed);
+        require!(now >= self.complete_eligible_at, LaunchError::CompleteTimelockActive);
+        Ok(())
+    }
+
+    /// End of the contribution window: the deadline less the quiet period.
+    pub fn contributions_close_at(&self) -> i64 {
+        self.deadline - self.contribution_cutoff_secs
+    }
+
+    /// Most one wallet may contribute in total, or None without a share cap.
+    pub fn max_contributor_lamports(&self) -> Option<u64> {
+        if self.max_contributor_bps == 0 {
+            return None;
+        }
+        Some((self.target_lamports as u128 * self.max_contributor_bps as u128 / 10000) as u64)
+    }
+
+    /// Portion of a refund withheld for the platform wallet.
+    pub fn refund_penalty(&self, amount: u64) -> u64 {
+        (amount as u128 * self.refund_penalty_bps as u128 / 10000) as u64
+    }
+
+    /// Paused for longer than max_pause_secs.
+    pub fn pause_expired(&self, now: i64) -> bool {
+        self.paused && now > self.paused_at + self.max_pause_secs
+    }
+
+    /// Why a refund is open at `now`, or RefundNotAvailable / PauseNotExpired.
+    pub fn refund_reason(&self, now: i64) -> Result<RefundReason> {
+        if self.status == PoolStatus::Cancelled {
+            Ok(RefundReason::cancelled(self))
+        } else if self.is_pre_finalize() && now > self.deadline {
+            Ok(RefundReason::DeadlinePassed)
+        } else {
+            // Safety valve: a pause that outlasts max_pause_secs frees contributor SOL
+            require!(
+                self.paused && !self.distribution_executed,
+                LaunchError::RefundNotAvailable
+            );
+            require!(self.pause_expired(now), LaunchError::PauseNotExpired);
+            Ok(RefundReason::PauseTimeout)
+        }
+    }
+
+    /// Join order for a new contributor. Refunds lower contributor_count but
+    /// never this, so no two records share a sequence.
+    pub fn take_sequence(&mut self) -> Result<u32> {
+        let sequence = self.next_sequence;
+        self.next_sequence = sequence.checked_add(1).ok_or(LaunchError::MathOverflow)?;
+        Ok(sequence)
+    }
+
+    /// Funding or Funded — contributions collected, no proposal in flight.
+    pub fn is_pre_finalize(&self) -> bool {
+        self.status == PoolStatus::Funding || self.status == PoolStatus::Funded
+    }
+
+    /// Credit `amount_lamports` to the pool and `record`: a new contributor
+    /// (and sequence number) on their first contribution, plus the transaction
+    /// count and largest-contributor tracker.
+    pub fn record_contribution(&mut self, record: &mut ContributionRecord, amount_lamports: u64) -> Result<()> {
+        if record.amount_lamports == 0 {
+            self.contributor_count =
+                self.contributor_count.checked_add(1).ok_or(LaunchError::MathOverflow)?;
+            record.sequence = self.take_sequence()?;
+        }
+        record.amount_lamports = record
+            .amount_lamports
+            .checked_add(amount_lamports)
+            .ok_or(LaunchError::MathOverflow)?;
+        self.current_lamports = self
+            .current_lamports
+            .checked_add(amount_lamports)
+            .ok_or(LaunchError::MathOverflow)?;
+        self.contribution_tx_count =
+            self.contribution_tx_count.checked_add(1).ok_or(LaunchError::MathOverflow)?;
+        self.max_single_contributor_lamports =
+            self.max_single_contributor_lamports.max(record.amount_lamports);
+        Ok(())
+    }
+
+    /// True for the first contribution that brings the pool to target, so
+    /// TargetReached fires once however far past target the pool goes.
+    pub fn mark_target_reached(&mut self) -> bool {
+        if self.target_reached || self.current_lamports < self.target_lamports {
+            return false;
+        }
+        self.target_reached = true;
+        true
+    }
+
+    /// Moves the pool to Distributing once the payouts and mint have gone out,
+    /// snapshotting the split's inputs and stamping `distributed_at` with `now`.
+    pub fn record_distribution(&mut self, total_sol: u64, winner_sol: u64, now: i64) {
+        self.status = PoolStatus::Distributing;
+        self.distribution_executed = true;
+        self.distribution_snapshot_lamports = total_sol;
+        self.distribution_contributor_count = self.contributor_count;
+        self.distributed_at = now;
+        if self.escrows_winner_sol() {
+            self.winner_escrow_lamports = winner_sol;
+        }
+    }
+
+    /// True for the vote that first makes the pool approvable, so
+    /// ConfirmationThresholdCrossed fires once per proposal.
+    pub fn mark_majority_reached(&mut self) -> bool {
+        if self.majority_reached || !self.is_approved() {
+            return false;
+        }
+        self.majority_reached = true;
+        true
+    }
+
+    /// Balance at which the pool moves to Funded: the hard cap if set, else the target.
+    pub fn funding_ceiling(&self) -> u64 {
+        if self.hard_cap_lamports > 0 {
+            self.hard_cap_lamports
+        } else {
+            self.target_lamports
+        }
+    }
+
+    /// Room left under the hard cap, or None when the pool is uncapped.
+    pub fn hard_cap_remaining(&self) -> Option<u64> {
+        (self.hard_cap_lamports > 0)
+            .then(|| self.hard_cap_lamports.saturating_sub(self.current_lamports))
+    }
+
+    pub fn soft_cap_reached(&self) -> bool {
+        self.current_lamports >= self.soft_cap_lamports
+    }
+
+    /// Share of the eligible weight (snapshotted at propose_finalize) that has
+    /// voted in the current round, in basis points.
+    pub fn turnout_bps(&self) -> u16 {
+        if self.total_eligible_lamports == 0 {
+            return 0;
+        }
+        let voted = self.approve_lamports as u128 + self.reject_lamports as u128;
+        (voted * 10000 / self.total_eligible_lamports as u128).min(10000) as u16
+    }
+
+    /// Approving SOL is at least approval_threshold_bps of all voted SOL.
+    pub fn supermajority_met(&self) -> bool {
+        let voted = self.approve_lamports as u128 + self.reject_lamports as u128;
+        self.approve_lamports as u128 * 10000 >= voted * self.approval_threshold_bps as u128
+    }
+
+    /// Stake majority (by the pool's threshold) approved and enough distinct
+    /// contributors approved.
+    pub fn is_approved(&self) -> bool {
+        self.approve_lamports > self.reject_lamports
+            && self.supermajority_met()
+            && self.approve_count >= self.min_approving_contributors
+    }
+
+    /// Mirrors the status/pause/timing guards of each instruction. Keep in sync.
+    pub fn allowed_actions(&self, now: i64) -> u16 {
+        let mut actions = 0u16;
+        let mut allow = |action: u16, ok: bool| {
+            if ok {
+                actions |= action;
+            }
+        };
+
+        let funding = self.status == PoolStatus::Funding;
+        let pre_finalize = self.is_pre_finalize();
+        let confirming = self.status == PoolStatus::Confirming;
+
+        allow(
+            ACTION_CONTRIBUTE,
+            !self.paused
+                && funding
+                && now < self.contributions_close_at()
+                && self.hard_cap_remaining() != Some(0),
+        );
+        allow(
+            ACTION_PROPOSE_FINALIZE,
+            !self.paused
+                && pre_finalize
+                && self.current_lamports > 0
+                && self.soft_cap_reached()
+                && self.contributor_count >= self.min_contributors
+                && (self.proposal_epoch == 0 || self.refinalize_count < self.max_refinalizations)
+                && self.finalize_commitment == [0u8; 32]
+                && !self.require_finalize_commit,
+        );
+        allow(ACTION_CONFIRM_VOTE, confirming && now < self.confirm_deadline);
+        allow(
+            ACTION_EXECUTE_DISTRIBUTION,
+            !self.distribution_executed
+                && !self.paused
+                && confirming
+                && self.soft_cap_reached()
+                && self.contributor_count >= self.min_contributors
+                && self.is_approved()
+                && self.winner_payout_ready()
+                && self.winner_bond_ready()
+                && self.current_lamports >= self.min_distribution_lamports,
+        );
+        allow(ACTION_EXPIRE_CONFIRMATION, confirming && now >= self.confirm_deadline);
+        allow(
+            ACTION_CLAIM,
+            !self.paused
+                && (self.status == PoolStatus::Distributing || self.status == PoolStatus::Complete),
+        );
+        allow(
+            ACTION_REFUND,
+            self.status == PoolStatus::Cancelled
+                || (pre_finalize && now > self.deadline)
+                || (self.pause_expired(now) && !self.distribution_executed),
+        );
+        allow(ACTION_PAUSE, !self.paused);
+        allow(ACTION_UNPAUSE, self.paused);
+        allow(ACTION_CANCEL, pre_finalize || confirming);
+        allow(
+            ACTION_PROPOSE_COMPLETE,
+            self.status == PoolStatus::Distributing && self.complete_eligible_at == 0,
+        );
+        allow(
+            ACTION_COMPLETE,
+            self.status == PoolStatus::Distributing
+                && self.complete_eligible_at != 0
+                && now >= self.complete_eligible_at,
+        );
+        allow(ACTION_REOPEN_CONFIRMATION, self.can_reopen(now));
+        allow(ACTION_DELEGATE_VOTE, pre_finalize || confirming);
+        allow(ACTION_DECLINE_WIN, confirming);
+
+        actions
+    }
+}
+
+#[account]
+pub struct ContributionRecord {
+    pub pool: Pubkey,
+    pub contributor: Pubkey,
+    pub amount_lamports: u64,
+    pub claimed: bool,                  // Full allocation claimed — excludes refund
+    pub refunded: bool,                 // SOL refunded — excludes claim
+    pub delegate: Pubkey,               // Confirmation vote delegated to (default = none)
+    pub memo: String,                   // Set on first contribution (referral / attribution)
+    pub referrer: Pubkey,               // First referrer wins (default = none)
+    pub claimed_tokens: u64,            // Tokens paid out by claim / claim_for / claim_amount
+    pub sequence: u32,                  // Join order (1 = first wallet in); set once
+    pub allocation_tokens: Option<u64>, // Total allocation, fixed by the first claim
+    pub voted_epoch: u32,               // proposal_epoch whose tally holds this weight (0 = none)
+    pub voted_approve: bool,            // Side of that tally
+    pub delegator_count: u32,           // Contributors who delegated to this one
+    pub bump: u8,
+}
+
+impl ContributionRecord {
+    pub const SPACE: usize =
+        8 + 32 + 32 + 8 + 1 + 1 + 32 + (4 + MAX_MEMO_LEN) + 32 + 8 + 4 + 9 + 4 + 1 + 4 + 1;
+
+    /// Stake that still counts: zero once refunded, whatever amount_lamports says.
+    pub fn live_lamports(&self) -> u64 {
+        if self.refunded {
+            0
+        } else {
+            self.amount_lamports
+        }
+    }
 
-    #[account(
-        constraint = multisig.is_signer(signer.key) @ LaunchError::NotMultisigSigner,
-    )]
-    pub signer: Signer<'info>,
+    /// Record `referrer` unless one is already set (first referrer wins).
+    /// True if it was recorded now.
+    pub fn set_referrer(&mut self, referrer: Pubkey) -> bool {
+        if self.referrer != Pubkey::default() {
+            return false;
+        }
+        self.referrer = referrer;
+        true
+    }
 
-    #[account(
-        mut,
-        constraint = token_mint.key() == pool.token_mint @ LaunchError::InvalidTokenAccount,
-    )]
-    pub token_mint: Account<'info, Mint>,
+    /// Receipt NFT name: the amount at mint time ("<n> lamports"). Even
+    /// u64::MAX stays within the 32-char name limit.
+    pub fn receipt_name(&self) -> Result<String> {
+        require!(!self.refunded, LaunchError::AlreadyRefunded);
+        require!(self.amount_lamports > 0, LaunchError::NoContribution);
+        Ok(format!("{} lamports", self.amount_lamports))
+    }
 
-    pub token_program: Program<'info, Token>,
+    pub fn pda(pool: &Pubkey, contributor: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"contribution", pool.as_ref(), contributor.as_ref()],
+            &crate::ID,
+        )
+    }
 }
 
-// ═══════════════════════════════════════════════════════════════
-// State
-// ═══════════════════════════════════════════════════════════════
-
-/// 2-of-3 multisig authority (#11)
+/// Contributor's confirmation vote (#12)
 #[account]
-pub struct Multisig {
-    pub signers: [Pubkey; 3],
-    pub threshold: u8,
-    pub nonce: u64,
+pub struct ConfirmationVoteRecord {
+    pub pool: Pubkey,
+    pub contributor: Pubkey,
+    pub approve: bool,
+    pub weight: u64,
+    pub has_voted: bool,
+    pub epoch: u32,                     // pool.proposal_epoch this vote was cast in
     pub bump: u8,
 }
 
-impl Multisig {
-    pub const SPACE: usize = 8 + (32 * 3) + 1 + 8 + 1;
-
-    pub fn is_signer(&self, key: &Pubkey) -> bool {
-        self.signers.contains(key)
+impl ConfirmationVoteRecord {
+    pub const SPACE: usize = 8 + 32 + 32 + 1 + 8 + 1 + 4 + 1;
+
+    /// One record per contributor per confirmation round.
+    pub fn pda(pool: &Pubkey, contributor: &Pubkey, epoch: u32) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[b"confirm_vote", pool.as_ref(), contributor.as_ref(), &epoch.to_le_bytes()],
+            &crate::ID,
+        )
     }
 }
 
+/// Winner's bond escrow. Closed to the winner on release or to the platform on slash.
 #[account]
-pub struct LaunchPool {
-    pub authority: Pubkey,              // Multisig PDA
-    pub pool_id: String,                // Maps to UC deliberation ID
-    pub target_lamports: u64,
-    pub current_lamports: u64,
-    pub deadline: i64,
-    pub status: PoolStatus,
+pub struct WinnerBond {
+    pub pool: Pubkey,
     pub winner: Pubkey,
-    pub platform_wallet: Pubkey,
-    pub token_mint: Pubkey,
-    pub merkle_root: [u8; 32],          // Merkle root of deliberation votes (#13)
-    pub confirm_deadline: i64,          // When confirmation window ends (#15)
-    pub confirm_duration_secs: i64,     // Configurable confirmation duration
-    pub approve_lamports: u64,          // SOL-weighted approve votes (#12)
-    pub reject_lamports: u64,           // SOL-weighted reject votes (#12)
-    pub contributor_count: u32,
-    pub paused: bool,                   // Emergency pause (#14)
+    pub amount_lamports: u64,
+    pub deadline: i64,                  // Milestone must be recorded by this time
+    pub milestone_met: bool,
     pub bump: u8,
 }
 
-impl LaunchPool {
-    pub fn space(pool_id: &str) -> usize {
-        8 +                         // discriminator
-        32 +                        // authority
-        4 + pool_id.len() +         // pool_id
-        8 +                         // target_lamports
-        8 +                         // current_lamports
-        8 +                         // deadline
-        1 +                         // status
-        32 +                        // winner
-        32 +                        // platform_wallet
-        32 +                        // token_mint
-        32 +                        // merkle_root
-        8 +                         // confirm_deadline
-        8 +                         // confirm_duration_secs
-        8 +                         // approve_lamports
-        8 +                         // reject_lamports
-        4 +                         // contributor_count
-        1 +                         // paused
-        1                           // bump
-    }
+impl WinnerBond {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 8 + 1 + 1;
 }
 
+/// Escrow for the winner's SOL share when it's released in tranches.
 #[account]
-pub struct ContributionRecord {
+pub struct WinnerEscrow {
     pub pool: Pubkey,
-    pub contributor: Pubkey,
-    pub amount_lamports: u64,
-    pub claimed: bool,
+    pub winner: Pubkey,
     pub bump: u8,
 }
 
-impl ContributionRecord {
-    pub const SPACE: usize = 8 + 32 + 32 + 8 + 1 + 1;
+impl WinnerEscrow {
+    pub const SPACE: usize = 8 + 32 + 32 + 1;
 }
 
-/// Contributor's confirmation vote (#12)
+/// Lamports contributed to a pool under one referrer.
 #[account]
-pub struct ConfirmationVoteRecord {
+pub struct ReferralStats {
     pub pool: Pubkey,
-    pub contributor: Pubkey,
-    pub approve: bool,
-    pub weight: u64,
-    pub has_voted: bool,
+    pub referrer: Pubkey,
+    pub referred_lamports: u64,         // Includes later top-ups by referred contributors
+    pub referred_count: u32,            // Contributors who named this referrer
     pub bump: u8,
 }
 
-impl ConfirmationVoteRecord {
-    pub const SPACE: usize = 8 + 32 + 32 + 1 + 8 + 1 + 1;
+impl ReferralStats {
+    pub const SPACE: usize = 8 + 32 + 32 + 8 + 4 + 1;
+
+    pub fn pda(pool: &Pubkey, referrer: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"referral", pool.as_ref(), referrer.as_ref()], &crate::ID)
+    }
+}
+
+/// Optional launch parameters for create_pool. Zero / false keeps today's behaviour.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct PoolConfig {
+    pub require_memo: bool,
+    pub min_approving_contributors: u32,
+    pub token_supply: u64,              // 0 = default supply and decimals
+    pub token_decimals: u8,             // Only read when token_supply != 0
+    pub require_winner_payout: bool,
+    pub platform_fee_in_sol: bool,
+    pub soft_cap_lamports: u64,         // 0 = no soft cap
+    pub hard_cap_lamports: u64,         // 0 = no hard cap
+    pub complete_delay_secs: i64,       // 0 = DEFAULT_COMPLETE_DELAY_SECS
+    pub max_refinalizations: u8,        // 0 = a rejected finalization cancels the pool
+    pub min_contributors: u32,          // 0 = no floor
+    pub winner_bond_lamports: u64,      // 0 = no bond
+    pub milestone_period_secs: i64,     // <= 0 = DEFAULT_MILESTONE_SECS; else >= MIN_MILESTONE_SECS
+    pub escrow_winner_sol: bool,        // Release winner SOL in multisig tranches
+    pub min_distribution_lamports: u64, // 0 = none; else at least MIN_SPLIT_LAMPORTS
+    pub max_pause_secs: i64,            // <= 0 = DEFAULT_MAX_PAUSE_SECS
+    pub refund_penalty_bps: u16,        // 0 = full refunds; at most MAX_REFUND_PENALTY_BPS
+    pub require_champion_proof: bool,   // Hold winner SOL until the chant_audit Champion exists
+    pub allowlist_required: bool,       // Only wallets proven against allowlist_root may contribute
+    pub allowlist_root: [u8; 32],       // Required non-zero when allowlist_required
+    pub metadata_uri: String,           // Empty = none; at most MAX_METADATA_URI_LEN
+    pub max_contributor_bps: u16,       // 0 = no per-wallet share cap; at most 10000
+    pub approval_threshold_bps: u16,    // 0 = simple majority; else 5000..=10000 (6667 = two-thirds)
+    pub contribution_cutoff_secs: i64,  // 0 = contributions until the deadline
+    pub forbid_signer_winner: bool,     // Reject a winner who is a multisig signer
+    pub chant_authority: Pubkey,        // Trusted chant authority; required for require_champion_proof
+    pub require_finalize_commit: bool,  // Disable one-step propose_finalize (front-running protection)
+}
+
+/// Returned by get_participation_stats.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ParticipationStats {
+    pub contributor_count: u32,
+    pub contribution_tx_count: u64,
+    pub total_lamports: u64,
+    pub max_single_contributor_lamports: u64,
+    pub total_eligible_lamports: u64,
+    pub turnout_bps: u16,
+}
+
+/// Returned by get_vote_tally.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VoteTally {
+    pub proposal_epoch: u32,
+    pub approve_lamports: u64,
+    pub reject_lamports: u64,
+    pub approve_count: u32,
+    pub reject_count: u32,
+    pub contributor_count: u32,
+}
+
+/// Returned by get_multisig_info.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MultisigInfo {
+    pub signers: [Pubkey; 3],
+    pub threshold: u8,
+    pub nonce: u64,
+}
+
+/// Returned by preview_distribution. Token amounts in base units.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DistributionPreview {
+    pub total_sol: u64,
+    pub winner_sol: u64,                // To the winner, or the winner escrow
+    pub platform_sol: u64,              // SOL fee mode only
+    pub total_tokens: u64,
+    pub contributor_tokens: u64,
+    pub platform_tokens: u64,           // Token fee mode only
+    pub executable: bool,               // execute_distribution would pass its guards now
+}
+
+/// Returned by get_claimable. Token amounts in base units.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Claimable {
+    pub total_allocation: u64,          // 0 until distribution executes (or once refunded)
+    pub already_claimed: u64,
+    pub currently_claimable: u64,
+}
+
+/// Returned by get_my_allocation. Token amounts in base units.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MyAllocation {
+    pub user_tokens: u64,
+    pub provisional: bool,              // Estimate — distribution hasn't executed yet
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -873,6 +4001,31 @@ pub enum PoolStatus {
     Distributing,   // Confirmed, tokens minted, claims open
     Complete,       // All claimed, mint authority burned (#16)
     Cancelled,
+    Funded,         // Reached its ceiling; contributions closed, awaiting finalize
+}
+
+/// Why refund / refund_batch paid a contributor back (ContributionRefunded).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RefundReason {
+    Cancelled,            // Multisig cancel or declined winner — refunded in full
+    ConfirmationExpired,  // Cancelled by expire_confirmation
+    DeadlinePassed,       // Funding deadline passed without finalization
+    PauseTimeout,         // Pause outlasted max_pause_secs — refunded in full
+}
+
+impl RefundReason {
+    pub fn cancelled(pool: &LaunchPool) -> Self {
+        if pool.cancelled_by_expiry {
+            RefundReason::ConfirmationExpired
+        } else {
+            RefundReason::Cancelled
+        }
+    }
+
+    /// Expiry and deadline refunds pay the pool's refund penalty.
+    pub fn is_penalized(self) -> bool {
+        matches!(self, RefundReason::ConfirmationExpired | RefundReason::DeadlinePassed)
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════
@@ -886,6 +4039,12 @@ pub struct PoolCreated {
     pub target_lamports: u64,
     pub deadline: i64,
     pub confirm_duration_secs: i64,
+    pub require_memo: bool,
+    pub min_approving_contributors: u32,
+    pub token_supply: u64,
+    pub token_decimals: u8,
+    pub soft_cap_lamports: u64,
+    pub hard_cap_lamports: u64,
 }
 
 #[event]
@@ -894,6 +4053,28 @@ pub struct ContributionMade {
     pub contributor: Pubkey,
     pub amount_lamports: u64,
     pub total_lamports: u64,
+    pub memo: String,
+    pub sequence: u32,                  // Contributor's join order
+}
+
+#[event]
+pub struct ReferralRecorded {
+    pub pool: Pubkey,
+    pub contributor: Pubkey,
+    pub referrer: Pubkey,
+}
+
+#[event]
+pub struct TargetReached {
+    pub pool: Pubkey,
+    pub total_lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FinalizeCommitted {
+    pub pool: Pubkey,
+    pub param_hash: [u8; 32],
 }
 
 #[event]
@@ -903,6 +4084,17 @@ pub struct FinalizeProposed {
     pub token_mint: Pubkey,
     pub merkle_root: [u8; 32],
     pub confirm_deadline: i64,
+    pub total_eligible_lamports: u64,   // Confirmation vote denominator for this proposal
+    pub contributor_count: u32,
+}
+
+#[event]
+pub struct ConfirmationThresholdCrossed {
+    pub pool: Pubkey,
+    pub epoch: u32,
+    pub total_approve: u64,
+    pub total_reject: u64,
+    pub approve_count: u32,
 }
 
 #[event]
@@ -911,10 +4103,136 @@ pub struct ConfirmationVoteCast {
     pub contributor: Pubkey,
     pub approve: bool,
     pub weight: u64,
+    pub delegators: u32,
     pub total_approve: u64,
     pub total_reject: u64,
 }
 
+#[event]
+pub struct WinnerPayoutRegistered {
+    pub pool: Pubkey,
+    pub winner: Pubkey,
+    pub winner_payout: Pubkey,
+}
+
+#[event]
+pub struct MintAuthorityTransferred {
+    pub pool: Pubkey,
+    pub token_mint: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct ConfirmDurationUpdated {
+    pub pool: Pubkey,
+    pub old_duration_secs: i64,
+    pub new_duration_secs: i64,
+}
+
+#[event]
+pub struct TokenMetadataCreated {
+    pub pool: Pubkey,
+    pub token_mint: Pubkey,
+    pub metadata: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct ContributionReceiptMinted {
+    pub pool: Pubkey,
+    pub contributor: Pubkey,
+    pub receipt_mint: Pubkey,
+    pub amount_lamports: u64,
+}
+
+#[event]
+pub struct MetadataUriUpdated {
+    pub pool: Pubkey,
+    pub metadata_uri: String,
+}
+
+#[event]
+pub struct PlatformWalletUpdated {
+    pub pool: Pubkey,
+    pub old_wallet: Pubkey,
+    pub new_wallet: Pubkey,
+}
+
+#[event]
+pub struct WinnerReleasedByChampion {
+    pub pool: Pubkey,
+    pub winner: Pubkey,
+    pub champion: Pubkey,
+    pub amount_lamports: u64,
+}
+
+#[event]
+pub struct WinnerTrancheReleased {
+    pub pool: Pubkey,
+    pub winner: Pubkey,
+    pub amount_lamports: u64,
+    pub released_lamports: u64,
+    pub total_lamports: u64,
+}
+
+#[event]
+pub struct WinnerBondDeposited {
+    pub pool: Pubkey,
+    pub winner: Pubkey,
+    pub amount_lamports: u64,
+    pub deadline: i64,
+}
+
+#[event]
+pub struct MilestoneRecorded {
+    pub pool: Pubkey,
+    pub winner: Pubkey,
+    pub milestone_count: u16,
+}
+
+#[event]
+pub struct WinnerBondSlashed {
+    pub pool: Pubkey,
+    pub winner: Pubkey,
+    pub amount_lamports: u64,
+}
+
+#[event]
+pub struct WinnerBondReleased {
+    pub pool: Pubkey,
+    pub winner: Pubkey,
+    pub amount_lamports: u64,
+}
+
+#[event]
+pub struct WinnerDeclined {
+    pub pool: Pubkey,
+    pub winner: Pubkey,
+    pub refinalize: bool,               // false = no re-proposals left, pool cancelled
+}
+
+#[event]
+pub struct FinalizationRejected {
+    pub pool: Pubkey,
+    pub refinalize_count: u8,
+}
+
+#[event]
+pub struct CompleteProposed {
+    pub pool: Pubkey,
+    pub eligible_at: i64,
+}
+
+#[event]
+pub struct VoteDelegated {
+    pub pool: Pubkey,
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub weight: u64,
+}
+
 #[event]
 pub struct PoolFinalized {
     pub pool: Pubkey,
@@ -922,8 +4240,10 @@ pub struct PoolFinalized {
     pub token_mint: Pubkey,
     pub total_sol: u64,
     pub winner_sol: u64,
+    pub platform_sol: u64,
     pub contributor_tokens: u64,
     pub platform_tokens: u64,
+    pub distributed_at: i64,
 }
 
 #[event]
@@ -931,6 +4251,30 @@ pub struct TokensClaimed {
     pub pool: Pubkey,
     pub contributor: Pubkey,
     pub tokens: u64,
+    pub claimed_by: Pubkey,             // contributor, or the claim_for caller
+    pub remaining_tokens: u64,          // Allocation still unclaimed after this payout
+}
+
+#[event]
+pub struct PoolClosed {
+    pub pool: Pubkey,
+    pub lamports_returned: u64,         // Everything left in the pool PDA, rent included
+    pub tokens_burned: u64,             // Retained winner share burned from the pool token account
+}
+
+#[event]
+pub struct DustSwept {
+    pub pool: Pubkey,
+    pub amount: u64,                    // Token base units above the retained share, sent to the platform
+    pub total_claimed_tokens: u64,
+}
+
+#[event]
+pub struct BatchRefunded {
+    pub pool: Pubkey,
+    pub refunded: u32,
+    pub skipped: u32,                   // Already refunded or claimed
+    pub total_lamports: u64,            // Drop in current_lamports (refunds + penalties)
 }
 
 #[event]
@@ -938,6 +4282,8 @@ pub struct ContributionRefunded {
     pub pool: Pubkey,
     pub contributor: Pubkey,
     pub amount_lamports: u64,
+    pub penalty_lamports: u64,
+    pub reason: RefundReason,
 }
 
 #[event]
@@ -945,6 +4291,12 @@ pub struct PoolCancelled {
     pub pool: Pubkey,
 }
 
+#[event]
+pub struct ConfirmationReopened {
+    pub pool: Pubkey,
+    pub confirm_deadline: i64,
+}
+
 #[event]
 pub struct PoolCompleted {
     pub pool: Pubkey,
@@ -961,6 +4313,11 @@ pub struct PoolUnpaused {
     pub pool: Pubkey,
 }
 
+#[event]
+pub struct ProgramPauseSet {
+    pub paused: bool,
+}
+
 // ═══════════════════════════════════════════════════════════════
 // Errors
 // ═══════════════════════════════════════════════════════════════
@@ -1021,4 +4378,140 @@ pub enum LaunchError {
     AlreadyPaused,
     #[msg("Pool is not paused")]
     NotPaused,
+    #[msg("Memo too long (max 64 chars)")]
+    MemoTooLong,
+    #[msg("This pool requires a contribution memo")]
+    MemoRequired,
+    #[msg("Champion does not match the proposed winner or merkle root")]
+    ChampionMismatch,
+    #[msg("Not enough distinct contributors approved")]
+    NotEnoughApprovers,
+    #[msg("Pool balance would drop below rent exemption")]
+    InsufficientPoolBalance,
+    #[msg("Token decimals too large (max 18)")]
+    InvalidDecimals,
+    #[msg("Mint decimals do not match the pool's token decimals")]
+    DecimalsMismatch,
+    #[msg("Already refunded")]
+    AlreadyRefunded,
+    #[msg("Winner has not registered a payout account")]
+    WinnerPayoutNotRegistered,
+    #[msg("Distribution has already been executed")]
+    AlreadyDistributed,
+    #[msg("Wrong platform wallet address")]
+    WrongPlatformWallet,
+    #[msg("Confirmation cannot be reopened")]
+    ReopenNotAllowed,
+    #[msg("Confirmation vote already delegated")]
+    AlreadyDelegated,
+    #[msg("Delegation conflicts with an existing vote or delegation")]
+    DelegationConflict,
+    #[msg("Soft cap not reached")]
+    SoftCapNotReached,
+    #[msg("Hard cap reached")]
+    HardCapExceeded,
+    #[msg("Completion timelock is active")]
+    CompleteTimelockActive,
+    #[msg("Completion has not been proposed")]
+    CompleteNotProposed,
+    #[msg("Completion delay out of range")]
+    InvalidCompleteDelay,
+    #[msg("No re-finalizations left")]
+    MaxRefinalizationsReached,
+    #[msg("Not enough contributors to finalize")]
+    NotEnoughContributors,
+    #[msg("Winner bond has not been deposited")]
+    WinnerBondRequired,
+    #[msg("Milestone was met; bond can't be slashed")]
+    MilestoneMet,
+    #[msg("Milestone deadline has not passed")]
+    MilestoneDeadlineActive,
+    #[msg("Bond can't be released yet")]
+    BondNotReleasable,
+    #[msg("Winner escrow missing or not enabled for this pool")]
+    WinnerEscrowRequired,
+    #[msg("Release exceeds the winner's share")]
+    WinnerOverRelease,
+    #[msg("Pool is below its distribution floor")]
+    PoolTooSmallToDistribute,
+    #[msg("Platform wallet can no longer be changed")]
+    PlatformWalletLocked,
+    #[msg("A pool with this pool_id already exists under this multisig")]
+    PoolIdAlreadyUsed,
+    #[msg("Contributors cannot refer themselves")]
+    SelfReferral,
+    #[msg("The referrer's ReferralStats account is required")]
+    ReferralStatsRequired,
+    #[msg("Pool has not been paused long enough to allow refunds")]
+    PauseNotExpired,
+    #[msg("token_supply * 10^token_decimals does not fit in u64")]
+    SupplyOverflow,
+    #[msg("This winner declined and cannot be proposed again")]
+    WinnerAlreadyDeclined,
+    #[msg("New mint authority must be a real key other than the pool")]
+    InvalidNewMintAuthority,
+    #[msg("Refund penalty exceeds the maximum")]
+    InvalidRefundPenalty,
+    #[msg("Pool token account must be the pool's associated token account")]
+    InvalidAssociatedAccount,
+    #[msg("Tranche escrow and champion-proof escrow can't both be enabled")]
+    ConflictingWinnerEscrow,
+    #[msg("Contributor is not on the pool's allow-list")]
+    NotAllowlisted,
+    #[msg("Allow-list required but no root given")]
+    InvalidAllowlistRoot,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Not every contributor has claimed yet")]
+    ClaimsOutstanding,
+    #[msg("No tokens left to sweep")]
+    NoDust,
+    #[msg("Metadata URI too long (max 200 chars)")]
+    MetadataTooLong,
+    #[msg("Metadata can't change after distribution")]
+    MetadataLocked,
+    #[msg("Token name, symbol or URI exceeds Metaplex limits (32 / 10 / 200)")]
+    TokenMetadataTooLong,
+    #[msg("Contribution would exceed the per-contributor share of the target")]
+    ContributorShareTooLarge,
+    #[msg("Revealed winner and merkle root don't match the commitment")]
+    FinalizeRevealMismatch,
+    #[msg("No finalize commitment to reveal")]
+    FinalizeNotCommitted,
+    #[msg("A finalize commitment is pending — use reveal_finalize")]
+    FinalizeCommitPending,
+    #[msg("Approving share is below the pool's approval threshold")]
+    SupermajorityNotMet,
+    #[msg("Batch accounts must be (contribution, contributor) pairs for this pool")]
+    InvalidBatch,
+    #[msg("Contributions are closed for the pre-deadline quiet period")]
+    ContributionWindowClosed,
+    #[msg("Pool must be complete, fully claimed and hold no tokens to close")]
+    PoolNotCloseable,
+    #[msg("Claim amount exceeds the unclaimed allocation")]
+    ClaimExceedsAllocation,
+    #[msg("Winner is a multisig signer")]
+    WinnerIsSigner,
+    #[msg("Only the program upgrade authority can do this")]
+    NotUpgradeAuthority,
+    #[msg("Chant isn't run by the pool's chant authority")]
+    UntrustedChant,
+    #[msg("Pool requires commit_finalize / reveal_finalize")]
+    FinalizeCommitRequired,
+    #[msg("wSOL account balance must equal the contribution amount")]
+    WsolAmountMismatch,
+    #[msg("Soft cap must not exceed the hard cap")]
+    InvalidCapOrder,
+    #[msg("Minimum distribution must be 0 or at least MIN_SPLIT_LAMPORTS")]
+    InvalidMinDistribution,
+    #[msg("Max contributor share must be at most 10000 bps")]
+    InvalidMaxContributorBps,
+    #[msg("Approval threshold must be 0 or 5000..=10000 bps")]
+    InvalidApprovalThreshold,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Each winner tranche needs a recorded milestone")]
+    MilestoneNotRecorded,
+    #[msg("Milestone period must be 0 or at least MIN_MILESTONE_SECS")]
+    MilestonePeriodTooShort,
 }
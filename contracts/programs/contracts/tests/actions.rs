@@ -0,0 +1,90 @@
+//! allowed_actions: the ACTION_* mask mirrors each instruction's guards.
+
+mod common;
+
+use common::*;
+use contracts::{
+    LaunchError, ACTION_CANCEL, ACTION_CLAIM, ACTION_CONFIRM_VOTE, ACTION_CONTRIBUTE, ACTION_DECLINE_WIN,
+    ACTION_DELEGATE_VOTE, ACTION_EXECUTE_DISTRIBUTION, ACTION_EXPIRE_CONFIRMATION, ACTION_PAUSE,
+    ACTION_PROPOSE_COMPLETE, ACTION_PROPOSE_FINALIZE, ACTION_REFUND, ACTION_UNPAUSE,
+};
+
+fn actions(launch: &Launch) -> u16 {
+    launch.state().allowed_actions(launch.bank.now())
+}
+
+#[test]
+fn funding_pool_takes_contributions() {
+    let mut launch = Launch::new();
+    assert_eq!(
+        actions(&launch),
+        ACTION_CONTRIBUTE | ACTION_PAUSE | ACTION_CANCEL | ACTION_DELEGATE_VOTE
+    );
+    launch
+        .bank
+        .process(ix(contracts::instruction::AllowedActions {}, contracts::accounts::ViewPool { pool: launch.pool }))
+        .unwrap();
+
+    // Finalizing needs something to finalize
+    launch.contributor(SOL);
+    assert_ne!(actions(&launch) & ACTION_PROPOSE_FINALIZE, 0);
+}
+
+#[test]
+fn mask_agrees_with_contribute() {
+    let mut launch = Launch::new();
+    let a = launch.bank.wallet(2 * SOL);
+    assert_ne!(actions(&launch) & ACTION_CONTRIBUTE, 0);
+    assert_reaches_cpi(launch.contribute(&a, SOL, ""));
+
+    launch.pause().unwrap();
+    assert_eq!(actions(&launch) & (ACTION_CONTRIBUTE | ACTION_PAUSE), 0);
+    assert_ne!(actions(&launch) & ACTION_UNPAUSE, 0);
+    assert_error(launch.contribute(&a, SOL, ""), LaunchError::PoolPaused);
+
+    launch.unpause().unwrap();
+    launch.bank.set_time(DEADLINE);
+    assert_eq!(actions(&launch) & ACTION_CONTRIBUTE, 0);
+    assert_error(launch.contribute(&a, SOL, ""), LaunchError::DeadlinePassed);
+}
+
+#[test]
+fn expired_pool_refunds() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    assert_eq!(actions(&launch) & ACTION_REFUND, 0);
+    launch.bank.set_time(DEADLINE + 1);
+    assert_ne!(actions(&launch) & ACTION_REFUND, 0);
+}
+
+#[test]
+fn confirming_pool_votes_until_the_deadline() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+    let confirming = actions(&launch);
+    assert_ne!(confirming & ACTION_CONFIRM_VOTE, 0);
+    assert_ne!(confirming & ACTION_DECLINE_WIN, 0);
+    assert_eq!(confirming & (ACTION_CONTRIBUTE | ACTION_PROPOSE_FINALIZE | ACTION_EXECUTE_DISTRIBUTION), 0);
+
+    // Approved: distribution opens
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    assert_ne!(actions(&launch) & ACTION_EXECUTE_DISTRIBUTION, 0);
+
+    launch.bank.set_time(launch.state().confirm_deadline);
+    let expired = actions(&launch);
+    assert_eq!(expired & ACTION_CONFIRM_VOTE, 0);
+    assert_ne!(expired & ACTION_EXPIRE_CONFIRMATION, 0);
+}
+
+#[test]
+fn distributed_pool_claims() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.distribute();
+    let distributing = actions(&launch);
+    assert_ne!(distributing & ACTION_CLAIM, 0);
+    assert_ne!(distributing & ACTION_PROPOSE_COMPLETE, 0);
+    assert_eq!(distributing & (ACTION_CANCEL | ACTION_EXECUTE_DISTRIBUTION | ACTION_REFUND), 0);
+}
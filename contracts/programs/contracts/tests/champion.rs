@@ -0,0 +1,198 @@
+//! Launch pool ↔ chant_audit: the pool trusts a Champion only from the chant
+//! named after it, run by its chant_authority. The chant_audit accounts are
+//! seeded as that program leaves them (see common).
+
+mod common;
+
+use anchor_lang::prelude::*;
+use chant_audit::{Champion, Chant};
+use common::*;
+use contracts::LaunchError;
+
+const ROOT: [u8; 32] = [1; 32];
+
+struct Deliberation {
+    chant: Pubkey,
+    champion: Pubkey,
+}
+
+fn chant_pda(chant_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"chant", chant_id.as_bytes()], &chant_audit::ID)
+}
+
+/// A chant under `chant_id` run by `authority`, whose Champion names `winner` and `vote_root`.
+fn declare(launch: &mut Launch, chant_id: &str, authority: Pubkey, winner: Pubkey, vote_root: [u8; 32]) -> Deliberation {
+    let (chant, bump) = chant_pda(chant_id);
+    let question = "What should we build?";
+    let state = Chant {
+        authority,
+        chant_id: chant_id.to_string(),
+        question: question.to_string(),
+        cell_size: 5,
+        continuous_flow: false,
+        phase: 2,
+        current_tier: 1,
+        idea_count: 5,
+        cell_count: 1,
+        submission_deadline: 0,
+        vote_budget: 10,
+        paused: false,
+        max_ideas_per_author: 0,
+        live_idea_count: 5,
+        disqualified_indices: vec![],
+        open_tier: 1,
+        tier_in_progress: false,
+        secret_ballot: false,
+        tier_cell_counts: [0; 32],
+        max_ideas: 0,
+        transcript_hash: [0; 32],
+        tier_started_at: [0; 32],
+        tier_last_batch: [0; 32],
+        tier_closed: [false; 32],
+        created_at: T0,
+        bump,
+    };
+    launch.bank.put(chant, chant_audit::ID, &state, Chant::space(chant_id, question), 0);
+
+    let (champion, bump) = Pubkey::find_program_address(&[b"champion", chant.as_ref()], &chant_audit::ID);
+    let state = Champion {
+        chant,
+        idea_index: 2,
+        text_hash: [7; 32],
+        total_tiers: 1,
+        total_voters: 5,
+        winner_wallet: winner,
+        vote_root,
+        declared_at: T0,
+        winning_xp: 30,
+        runner_up_xp: 12,
+        bump,
+    };
+    launch.bank.put(champion, chant_audit::ID, &state, Champion::SPACE, 0);
+    Deliberation { chant, champion }
+}
+
+/// A distributed champion-proof pool trusting `chant_authority`, winner SOL in escrow.
+fn escrowed_launch(chant_authority: Pubkey) -> Launch {
+    let mut launch = Launch::new();
+    launch.update(|pool| {
+        pool.require_champion_proof = true;
+        pool.chant_authority = chant_authority;
+    });
+    launch.contributor(10 * SOL);
+    launch.distribute();
+    launch
+}
+
+fn release(launch: &mut Launch, chant: Pubkey, champion: Pubkey) -> TxResult {
+    launch.bank.process(ix(
+        contracts::instruction::ReleaseToWinner {},
+        contracts::accounts::ReleaseToWinner {
+            pool: launch.pool,
+            winner_escrow: launch.winner_escrow(),
+            winner: launch.winner,
+            champion,
+            chant,
+        },
+    ))
+}
+
+#[test]
+fn matching_champion_releases_the_escrow() {
+    let authority = Pubkey::new_unique();
+    let mut launch = escrowed_launch(authority);
+    let winner = launch.winner;
+    let deliberation = declare(&mut launch, Launch::POOL_ID, authority, winner, ROOT);
+    let escrowed = launch.state().winner_escrow_lamports;
+    let winner_before = launch.bank.lamports(&winner);
+
+    release(&mut launch, deliberation.chant, deliberation.champion).unwrap();
+    assert_eq!(launch.bank.lamports(&winner), winner_before + escrowed);
+    assert_eq!(launch.state().winner_released_lamports, escrowed);
+    assert_error(
+        release(&mut launch, deliberation.chant, deliberation.champion),
+        LaunchError::WinnerOverRelease,
+    );
+}
+
+#[test]
+fn champion_from_an_untrusted_chant_is_rejected() {
+    let mut launch = escrowed_launch(Pubkey::new_unique());
+    let winner = launch.winner;
+    // Same chant id, same result, but anyone can create a chant
+    let impostor = declare(&mut launch, Launch::POOL_ID, Pubkey::new_unique(), winner, ROOT);
+    assert_error(release(&mut launch, impostor.chant, impostor.champion), LaunchError::UntrustedChant);
+}
+
+#[test]
+fn champion_must_match_chant_winner_and_root() {
+    let authority = Pubkey::new_unique();
+    let mut launch = escrowed_launch(authority);
+    let winner = launch.winner;
+
+    // A trusted chant, but for another pool id
+    let other = declare(&mut launch, "chant-2", authority, winner, ROOT);
+    assert_error(release(&mut launch, other.chant, other.champion), LaunchError::ChampionMismatch);
+
+    // The right chant with another chant's champion
+    let right = declare(&mut launch, Launch::POOL_ID, authority, winner, ROOT);
+    assert_error(release(&mut launch, right.chant, other.champion), LaunchError::ChampionMismatch);
+
+    let someone_else = Pubkey::new_unique();
+    let wrong_winner = declare(&mut launch, Launch::POOL_ID, authority, someone_else, ROOT);
+    assert_error(
+        release(&mut launch, wrong_winner.chant, wrong_winner.champion),
+        LaunchError::ChampionMismatch,
+    );
+
+    let wrong_root = declare(&mut launch, Launch::POOL_ID, authority, winner, [2; 32]);
+    assert_error(
+        release(&mut launch, wrong_root.chant, wrong_root.champion),
+        LaunchError::ChampionMismatch,
+    );
+    assert_eq!(launch.state().winner_released_lamports, 0);
+}
+
+#[test]
+fn champion_must_be_owned_by_chant_audit() {
+    let authority = Pubkey::new_unique();
+    let mut launch = escrowed_launch(authority);
+    let winner = launch.winner;
+    let deliberation = declare(&mut launch, Launch::POOL_ID, authority, winner, ROOT);
+
+    // Byte-identical copy under another owner
+    let forged = Pubkey::new_unique();
+    let champion: Champion = launch.bank.get(&deliberation.champion);
+    launch.bank.put(forged, contracts::ID, &champion, Champion::SPACE, 0);
+    assert_error(
+        release(&mut launch, deliberation.chant, forged),
+        anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram,
+    );
+}
+
+#[test]
+fn propose_finalize_binds_to_the_champion() {
+    let authority = Pubkey::new_unique();
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.chant_authority = authority);
+    launch.contributor(SOL);
+    launch.create_mint();
+    let winner = launch.winner;
+    let deliberation = declare(&mut launch, Launch::POOL_ID, authority, winner, ROOT);
+    let propose = |launch: &mut Launch, merkle_root: [u8; 32], chant: Option<Pubkey>| {
+        let accounts = contracts::accounts::ProposeFinalize {
+            champion: Some(deliberation.champion),
+            chant,
+            ..launch.propose_finalize()
+        };
+        launch.bank.process(ix(contracts::instruction::ProposeFinalize { merkle_root }, accounts))
+    };
+
+    assert_error(propose(&mut launch, ROOT, None), LaunchError::UntrustedChant);
+    assert_error(propose(&mut launch, [2; 32], Some(deliberation.chant)), LaunchError::ChampionMismatch);
+    propose(&mut launch, ROOT, Some(deliberation.chant)).unwrap();
+
+    let state = launch.state();
+    assert_eq!(state.winner, winner);
+    assert_eq!(state.merkle_root, ROOT);
+}
@@ -0,0 +1,297 @@
+//! Claims. Token transfers happen over CPI, so claim, claim_amount and claim_for are
+//! covered through their guards and the allocation math they rely on.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use contracts::{ContributionRecord, LaunchError, LaunchPool, PoolConfig};
+
+#[test]
+fn last_claimant_takes_the_remainder() {
+    let mut launch = Launch::new();
+    let contributors = [launch.contributor(SOL), launch.contributor(SOL), launch.contributor(SOL)];
+    launch.distribute();
+
+    // Fix allocations in claim order, as settle_claim does
+    let mut pool = launch.state();
+    let contributor_tokens = pool.contributor_tokens().unwrap();
+    let mut allocations = Vec::new();
+    for contributor in &contributors {
+        let mut record = launch.record(contributor);
+        let allocation = pool.claim_amount(&record).unwrap();
+        record.allocation_tokens = Some(allocation);
+        pool.allocated_count += 1;
+        pool.total_allocated_tokens += allocation;
+        // A fixed allocation doesn't move when the counters do
+        assert_eq!(pool.claim_amount(&record).unwrap(), allocation);
+        allocations.push(allocation);
+    }
+
+    // 94% of the supply doesn't split evenly three ways
+    assert_ne!(contributor_tokens % 3, 0);
+    assert_eq!(allocations[0], contributor_tokens / 3);
+    assert_eq!(allocations[1], contributor_tokens / 3);
+    assert_eq!(allocations[2], contributor_tokens - 2 * (contributor_tokens / 3));
+    assert_eq!(allocations.iter().sum::<u64>(), contributor_tokens);
+}
+
+#[test]
+fn claim_amount_is_bounded_by_the_allocation() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    let b = launch.contributor(3 * SOL);
+    launch.distribute();
+    let allocation = launch.state().claim_amount(&launch.record(&a)).unwrap();
+
+    let a_tokens = launch.token_account(&a);
+    launch.bank.create_token_account(a_tokens, launch.mint, a, 0);
+    let claim_amount = |launch: &mut Launch, amount: u64| {
+        launch.bank.process(ix(
+            contracts::instruction::ClaimAmount { amount },
+            contracts::accounts::Claim {
+                pool: launch.pool,
+                program_config: program_config(),
+                contribution: launch.contribution(&a),
+                contributor: a,
+                pool_token_account: launch.pool_token_account(),
+                contributor_token_account: a_tokens,
+                token_program: anchor_spl::token::ID,
+            },
+        ))
+    };
+    assert_error(claim_amount(&mut launch, 0), LaunchError::InvalidAmount);
+    assert_error(claim_amount(&mut launch, allocation + 1), LaunchError::ClaimExceedsAllocation);
+
+    // A partially claimed record can't take more than what's left
+    let half = allocation / 2;
+    let a_record = launch.contribution(&a);
+    launch.bank.update(&a_record, |record: &mut contracts::ContributionRecord| {
+        record.allocation_tokens = Some(allocation);
+        record.claimed_tokens = half;
+    });
+    assert_error(claim_amount(&mut launch, allocation - half + 1), LaunchError::ClaimExceedsAllocation);
+    assert_eq!(launch.state().claim_amount(&launch.record(&b)).unwrap(), allocation * 3);
+}
+
+#[test]
+fn claim_amount_reports_bad_state_as_an_error() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.contributor(SOL);
+    launch.distribute();
+    let record = launch.record(&a);
+
+    // No snapshot to divide by: an error, not a panic
+    let mut pool = launch.state();
+    pool.distribution_snapshot_lamports = 0;
+    assert_eq!(pool.claim_amount(&record).unwrap_err(), LaunchError::MathOverflow.into());
+
+    // The last allocation can't go below zero
+    let mut pool = launch.state();
+    pool.allocated_count = pool.distribution_contributor_count - 1;
+    pool.total_allocated_tokens = pool.contributor_tokens().unwrap() + 1;
+    assert_eq!(pool.claim_amount(&record).unwrap_err(), LaunchError::MathOverflow.into());
+}
+
+#[test]
+fn claims_use_the_distribution_snapshot() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    let b = launch.contributor(3 * SOL);
+    launch.distribute();
+    let mut pool = launch.state();
+    let contributor_tokens = pool.contributor_tokens().unwrap();
+
+    // current_lamports moving after distribution doesn't change a share
+    let a_share = pool.claim_amount(&launch.record(&a)).unwrap();
+    pool.current_lamports /= 2;
+    assert_eq!(pool.claim_amount(&launch.record(&a)).unwrap(), a_share);
+
+    pool.allocated_count = 1;
+    pool.total_allocated_tokens = a_share;
+    let b_share = pool.claim_amount(&launch.record(&b)).unwrap();
+    assert_eq!(a_share + b_share, contributor_tokens);
+}
+
+#[test]
+fn custom_supply_sets_mint_and_claim_math() {
+    let mut launch = Launch::new();
+    launch.update(|pool| {
+        pool.token_supply = 500_000_000;
+        pool.token_decimals = 10;
+    });
+    let a = launch.contributor(SOL);
+    let b = launch.contributor(3 * SOL);
+    launch.distribute();
+
+    let pool = launch.state();
+    let total_tokens = 500_000_000 * 10u64.pow(10);
+    assert_eq!(pool.total_tokens().unwrap(), total_tokens);
+    assert_eq!(pool.contributor_tokens().unwrap(), total_tokens / 100 * 94);
+    assert_eq!(pool.platform_tokens().unwrap(), total_tokens / 100);
+    let a_share = pool.claim_amount(&launch.record(&a)).unwrap();
+    assert_eq!(a_share, pool.contributor_tokens().unwrap() / 4);
+    assert_eq!(
+        launch.bank.token_balance(&launch.pool_token_account()),
+        total_tokens - pool.platform_tokens().unwrap()
+    );
+    assert_eq!(pool.claim_amount(&launch.record(&b)).unwrap(), 3 * a_share);
+}
+
+#[test]
+fn supply_and_decimals_are_validated() {
+    let mut launch = Launch::new();
+    let config = PoolConfig { token_supply: 1, token_decimals: 19, ..Default::default() };
+    assert_error(launch.create_pool(config), LaunchError::InvalidDecimals);
+    // 500 million at 11 decimals is past u64::MAX base units
+    let config = PoolConfig { token_supply: 500_000_000, token_decimals: 11, ..Default::default() };
+    assert_error(launch.create_pool(config), LaunchError::SupplyOverflow);
+    let config = PoolConfig { token_supply: 500_000_000, token_decimals: 10, ..Default::default() };
+    assert_reaches_cpi(launch.create_pool(config));
+}
+
+#[test]
+fn mint_decimals_must_match_the_pool() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.token_decimals = 10);
+    launch.contributor(SOL);
+    let (mint, pool) = (launch.mint, launch.pool);
+    launch.bank.create_mint(mint, pool, 6, 0);
+    assert_error(launch.propose(), LaunchError::DecimalsMismatch);
+}
+
+#[test]
+fn total_supply_must_fit_in_u64() {
+    let max_supply = u64::MAX / 1_000_000;
+    assert_eq!(LaunchPool::checked_total_tokens(max_supply, 6).unwrap(), max_supply * 1_000_000);
+    assert_eq!(
+        LaunchPool::checked_total_tokens(max_supply + 1, 6).unwrap_err(),
+        LaunchError::SupplyOverflow.into()
+    );
+    // 10^20 alone is past u64::MAX, whatever the supply
+    assert_eq!(LaunchPool::checked_total_tokens(1, 20).unwrap_err(), LaunchError::SupplyOverflow.into());
+
+    let mut pool = Launch::new().state();
+    pool.token_supply = max_supply + 1;
+    assert_eq!(pool.total_tokens().unwrap_err(), LaunchError::SupplyOverflow.into());
+    assert_eq!(pool.contributor_tokens().unwrap_err(), LaunchError::SupplyOverflow.into());
+}
+
+fn claim_for(launch: &mut Launch, contributor: Pubkey, destination: Pubkey, caller: Pubkey) -> TxResult {
+    let accounts = contracts::accounts::ClaimFor {
+        pool: launch.pool,
+        program_config: program_config(),
+        contribution: launch.contribution(&contributor),
+        contributor,
+        pool_token_account: launch.pool_token_account(),
+        contributor_token_account: destination,
+        caller,
+        token_program: anchor_spl::token::ID,
+    };
+    launch.bank.process(ix(contracts::instruction::ClaimFor {}, accounts))
+}
+
+#[test]
+fn claim_for_pays_only_the_contributor() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.distribute();
+    let operator = launch.bank.wallet(SOL);
+    let a_tokens = launch.token_account(&a);
+    launch.bank.create_token_account(a_tokens, launch.mint, a, 0);
+    let operator_tokens = launch.token_account(&operator);
+    launch.bank.create_token_account(operator_tokens, launch.mint, operator, 0);
+
+    // The operator can't point the claim at their own account
+    assert_error(claim_for(&mut launch, a, operator_tokens, operator), LaunchError::InvalidTokenAccount);
+    assert_reaches_cpi(claim_for(&mut launch, a, a_tokens, operator));
+
+    // Same guards as claim
+    let a_record = launch.contribution(&a);
+    launch.bank.update(&a_record, |record: &mut ContributionRecord| record.claimed = true);
+    assert_error(claim_for(&mut launch, a, a_tokens, operator), LaunchError::AlreadyClaimed);
+}
+
+#[test]
+fn claimable_tracks_distribution_and_claims() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.contributor(SOL);
+    let nothing = launch.state().claimable(&launch.record(&a)).unwrap();
+    assert_eq!((nothing.total_allocation, nothing.already_claimed, nothing.currently_claimable), (0, 0, 0));
+
+    // No vesting: the whole allocation unlocks at distribution
+    launch.distribute();
+    let mut pool = launch.state();
+    let mut record = launch.record(&a);
+    let allocation = pool.claim_amount(&record).unwrap();
+    let open = pool.claimable(&record).unwrap();
+    assert_eq!((open.total_allocation, open.already_claimed, open.currently_claimable), (allocation, 0, allocation));
+
+    record.claimed_tokens = allocation / 4;
+    let partial = pool.claimable(&record).unwrap();
+    assert_eq!(partial.already_claimed, allocation / 4);
+    assert_eq!(partial.currently_claimable, allocation - allocation / 4);
+
+    // Paused claims report nothing claimable right now
+    pool.paused = true;
+    let paused = pool.claimable(&record).unwrap();
+    assert_eq!((paused.total_allocation, paused.currently_claimable), (allocation, 0));
+}
+
+#[test]
+fn claims_pay_out_of_the_pool_ata_only() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.distribute();
+    let a_tokens = launch.token_account(&a);
+    launch.bank.create_token_account(a_tokens, launch.mint, a, 0);
+
+    // Owned by the pool and on the right mint, but not its associated account
+    let side_account = Pubkey::new_unique();
+    launch.bank.create_token_account(side_account, launch.mint, launch.pool, 1_000);
+    let claim = |launch: &mut Launch, pool_token_account: Pubkey| {
+        launch.bank.process(ix(
+            contracts::instruction::Claim {},
+            contracts::accounts::Claim {
+                pool: launch.pool,
+                program_config: program_config(),
+                contribution: launch.contribution(&a),
+                contributor: a,
+                pool_token_account,
+                contributor_token_account: a_tokens,
+                token_program: anchor_spl::token::ID,
+            },
+        ))
+    };
+    assert_error(claim(&mut launch, side_account), LaunchError::InvalidAssociatedAccount);
+    let pool_ata = launch.pool_token_account();
+    assert_reaches_cpi(claim(&mut launch, pool_ata));
+}
+
+#[test]
+fn my_allocation_estimates_then_matches_the_claim() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    let b = launch.contributor(3 * SOL);
+
+    let estimate = launch.state().my_allocation(&launch.record(&a)).unwrap();
+    assert!(estimate.provisional);
+
+    launch.distribute();
+    let pool = launch.state();
+    let record = launch.record(&a);
+    let allocation = pool.my_allocation(&record).unwrap();
+    assert!(!allocation.provisional);
+    assert_eq!(allocation.user_tokens, pool.claim_amount(&record).unwrap());
+    // Nothing moved between the estimate and distribution, so it held
+    assert_eq!(estimate.user_tokens, allocation.user_tokens);
+    assert_eq!(pool.my_allocation(&launch.record(&b)).unwrap().user_tokens, 3 * allocation.user_tokens);
+
+    // After the claim it reports what was paid
+    let claimed = ContributionRecord { claimed: true, claimed_tokens: allocation.user_tokens, ..record };
+    assert_eq!(pool.my_allocation(&claimed).unwrap().user_tokens, allocation.user_tokens);
+    let refunded = ContributionRecord { refunded: true, ..launch.record(&b) };
+    assert_eq!(pool.my_allocation(&refunded).unwrap().user_tokens, 0);
+}
@@ -0,0 +1,69 @@
+//! The end of a pool: sweep_dust and close_pool once every claim is in. Both
+//! finish over token CPIs, so they are covered up to those calls.
+
+mod common;
+
+use common::*;
+use contracts::{LaunchError, PoolStatus};
+
+#[test]
+fn sweep_and_close_leave_the_retained_share() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.distribute();
+
+    let state = launch.state();
+    let total = state.total_tokens().unwrap();
+    let retained = state.retained_tokens().unwrap();
+    // The winner's 5% is minted but never allocated
+    assert_eq!(retained, total / 20);
+    assert_eq!(state.contributor_tokens().unwrap() + state.platform_tokens().unwrap() + retained, total);
+
+    let sweep = |launch: &mut Launch| {
+        launch.bank.process(ix(
+            contracts::instruction::SweepDust {},
+            contracts::accounts::SweepDust {
+                pool: launch.pool,
+                pool_token_account: launch.pool_token_account(),
+                platform_token_account: launch.token_account(&launch.platform_wallet),
+                token_program: anchor_spl::token::ID,
+            },
+        ))
+    };
+    let close = |launch: &mut Launch| {
+        launch.bank.process(ix(
+            contracts::instruction::ClosePool {},
+            contracts::accounts::ClosePool {
+                pool: launch.pool,
+                multisig: launch.multisig,
+                signer: launch.signers[0],
+                pool_token_account: launch.pool_token_account(),
+                token_mint: launch.mint,
+                token_program: anchor_spl::token::ID,
+            },
+        ))
+    };
+    assert_error(sweep(&mut launch), LaunchError::ClaimsOutstanding);
+
+    // Contributor claimed in full: only the retained share is left
+    let pool_tokens = launch.pool_token_account();
+    let contributor_tokens = state.contributor_tokens().unwrap();
+    launch.bank.create_token_account(pool_tokens, launch.mint, launch.pool, retained);
+    launch.bank.create_token_account(launch.token_account(&a), launch.mint, a, contributor_tokens);
+    launch.update(|pool| {
+        pool.claimed_count = 1;
+        pool.total_claimed_tokens = contributor_tokens;
+    });
+    assert_error(sweep(&mut launch), LaunchError::NoDust);
+    assert_error(close(&mut launch), LaunchError::PoolNotCloseable);
+
+    // Tokens sent in from outside are dust; close waits until they're swept
+    launch.update(|pool| pool.status = PoolStatus::Complete);
+    launch.bank.create_token_account(pool_tokens, launch.mint, launch.pool, retained + 1);
+    assert_error(close(&mut launch), LaunchError::PoolNotCloseable);
+    assert_reaches_cpi(sweep(&mut launch));
+
+    // Swept and fully claimed: the retained share burns and the pool closes
+    launch.bank.create_token_account(pool_tokens, launch.mint, launch.pool, retained);
+    assert_reaches_cpi(close(&mut launch));
+}
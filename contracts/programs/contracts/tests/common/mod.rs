@@ -0,0 +1,790 @@
+//! In-process harness for the launch pool tests.
+//!
+//! There's no validator here: accounts live in a map and each instruction runs
+//! through the program's Anchor `entry`, from an input buffer laid out the way
+//! the loader lays it out (so `close =` and realloc behave as on-chain). The
+//! syscall stubs only supply Clock and Rent. Anchor's CPI is unavailable off
+//! the SBF target, so accounts an instruction would create through one (pools,
+//! contribution records, bonds, escrows, mints) are seeded directly in the
+//! state that instruction leaves. An instruction that gets as far as a CPI
+//! fails with `REACHED_CPI` instead, so its checks are still exercised. A
+//! failed instruction leaves every account untouched; a successful one must
+//! conserve lamports.
+
+#![allow(dead_code)]
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::{deserialize, MAX_PERMITTED_DATA_INCREASE};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::solana_program::system_program;
+use anchor_lang::InstructionData;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::spl_token;
+use contracts::{
+    ConfirmationVoteRecord, ContributionRecord, LaunchPool, Multisig, PoolConfig, PoolStatus, WinnerBond,
+    WinnerEscrow,
+};
+use solana_sysvar::program_stubs::{set_syscall_stubs, SyscallStubs};
+use spl_token::state::{Account as TokenState, AccountState, Mint as MintState};
+
+pub type TxResult = std::result::Result<(), ProgramError>;
+
+/// Start of every test's clock.
+pub const T0: i64 = 1_700_000_000;
+pub const SOL: u64 = 1_000_000_000;
+pub const TARGET: u64 = 10 * SOL;
+pub const DEADLINE: i64 = T0 + 7 * 86_400;
+
+/// What `Bank::process` returns for an instruction that passed its checks and
+/// went on to a CPI (transfers, account creation, minting), which can't run here.
+pub const REACHED_CPI: u32 = u32::MAX;
+
+const CPI_PANIC: &str = "only supported with `target_os = \"solana\"";
+
+thread_local! {
+    static NOW: Cell<i64> = const { Cell::new(T0) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_log_data(&self, _fields: &[&[u8]]) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock { slot: 1, unix_timestamp: NOW.with(Cell::get), ..Clock::default() };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        0
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap_or("")
+}
+
+#[derive(Clone)]
+pub struct AccountData {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+    pub executable: bool,
+}
+
+impl Default for AccountData {
+    fn default() -> Self {
+        Self { lamports: 0, data: Vec::new(), owner: system_program::ID, executable: false }
+    }
+}
+
+/// Accounts by address. Programs are executable placeholders.
+pub struct Bank {
+    accounts: HashMap<Pubkey, AccountData>,
+}
+
+impl Bank {
+    pub fn new() -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            set_syscall_stubs(Box::new(Stubs));
+            // A CPI panic is an expected outcome (REACHED_CPI), not a failure to report
+            let default_hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                if !panic_message(info.payload()).contains(CPI_PANIC) {
+                    default_hook(info);
+                }
+            }));
+        });
+        NOW.with(|now| now.set(T0));
+
+        let mut bank = Bank { accounts: HashMap::new() };
+        for program in [contracts::ID, chant_audit::ID, system_program::ID, spl_token::ID, anchor_spl::associated_token::ID] {
+            bank.accounts.insert(
+                program,
+                AccountData { lamports: 1, executable: true, ..AccountData::default() },
+            );
+        }
+        bank
+    }
+
+    pub fn now(&self) -> i64 {
+        NOW.with(Cell::get)
+    }
+
+    pub fn set_time(&mut self, unix_timestamp: i64) {
+        NOW.with(|now| now.set(unix_timestamp));
+    }
+
+    pub fn rent(space: usize) -> u64 {
+        Rent::default().minimum_balance(space)
+    }
+
+    pub fn exists(&self, key: &Pubkey) -> bool {
+        self.accounts.get(key).is_some_and(|a| a.lamports > 0)
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.accounts.get(key).map_or(0, |a| a.lamports)
+    }
+
+    /// A funded system-owned wallet.
+    pub fn wallet(&mut self, lamports: u64) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.airdrop(&key, lamports);
+        key
+    }
+
+    pub fn airdrop(&mut self, key: &Pubkey, lamports: u64) {
+        self.accounts.entry(*key).or_default().lamports += lamports;
+    }
+
+    /// Move lamports between accounts outside any instruction (seeding only).
+    pub fn shift(&mut self, from: &Pubkey, to: &Pubkey, lamports: u64) {
+        self.accounts.get_mut(from).unwrap().lamports -= lamports;
+        self.airdrop(to, lamports);
+    }
+
+    /// Deserialize an Anchor account (discriminator checked).
+    pub fn get<T: AccountDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.accounts.get(key).unwrap_or_else(|| panic!("no account {key}"));
+        T::try_deserialize(&mut &account.data[..]).expect("account deserializes")
+    }
+
+    /// Write an Anchor account owned by `owner`: `space` bytes (zero-padded),
+    /// funded with `lamports` over its rent-exempt reserve.
+    pub fn put<T: AccountSerialize>(&mut self, key: Pubkey, owner: Pubkey, account: &T, space: usize, lamports: u64) {
+        let mut data = Vec::with_capacity(space);
+        account.try_serialize(&mut data).unwrap();
+        assert!(data.len() <= space, "account larger than its space");
+        data.resize(space, 0);
+        let lamports = Self::rent(space) + lamports;
+        self.accounts.insert(key, AccountData { lamports, data, owner, executable: false });
+    }
+
+    /// Rewrite an existing Anchor account in place, keeping its size and lamports.
+    pub fn update<T: AccountSerialize + AccountDeserialize>(&mut self, key: &Pubkey, f: impl FnOnce(&mut T)) {
+        let mut account: T = self.get(key);
+        f(&mut account);
+        let entry = self.accounts.get_mut(key).unwrap();
+        account.try_serialize(&mut &mut entry.data[..]).unwrap();
+    }
+
+    pub fn create_mint(&mut self, key: Pubkey, authority: Pubkey, decimals: u8, supply: u64) {
+        let mint = MintState {
+            mint_authority: COption::Some(authority),
+            supply,
+            decimals,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        self.pack(key, mint);
+    }
+
+    /// A token account; wrapped SOL (the native mint) also holds `amount` in lamports.
+    pub fn create_token_account(&mut self, key: Pubkey, mint: Pubkey, owner: Pubkey, amount: u64) {
+        let native = mint == spl_token::native_mint::ID;
+        let account = TokenState {
+            mint,
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            is_native: if native { COption::Some(Self::rent(TokenState::LEN)) } else { COption::None },
+            ..TokenState::default()
+        };
+        self.pack(key, account);
+        if native {
+            self.airdrop(&key, amount);
+        }
+    }
+
+    fn pack<T: Pack>(&mut self, key: Pubkey, state: T) {
+        let mut data = vec![0; T::LEN];
+        T::pack(state, &mut data).unwrap();
+        self.accounts.insert(
+            key,
+            AccountData { lamports: Self::rent(T::LEN), data, owner: spl_token::ID, executable: false },
+        );
+    }
+
+    pub fn token_balance(&self, key: &Pubkey) -> u64 {
+        TokenState::unpack(&self.accounts[key].data).unwrap().amount
+    }
+
+    /// Run one instruction as its own transaction. On error nothing changes.
+    pub fn process(&mut self, ix: Instruction) -> TxResult {
+        // Unique accounts in first-seen order, with merged signer / writable flags
+        let mut keys: Vec<Pubkey> = Vec::new();
+        let mut flags: Vec<(bool, bool)> = Vec::new();
+        let mut slots: Vec<usize> = Vec::new();
+        for meta in &ix.accounts {
+            match keys.iter().position(|k| *k == meta.pubkey) {
+                Some(i) => {
+                    flags[i].0 |= meta.is_signer;
+                    flags[i].1 |= meta.is_writable;
+                    slots.push(i);
+                }
+                None => {
+                    keys.push(meta.pubkey);
+                    flags.push((meta.is_signer, meta.is_writable));
+                    slots.push(keys.len() - 1);
+                }
+            }
+        }
+        let lamports_before: u64 = keys.iter().map(|k| self.lamports(k)).sum();
+
+        let mut input = Vec::<u8>::new();
+        input.extend_from_slice(&(ix.accounts.len() as u64).to_le_bytes());
+        let mut seen = vec![false; keys.len()];
+        for &i in &slots {
+            if std::mem::replace(&mut seen[i], true) {
+                input.push(i as u8);
+                input.extend_from_slice(&[0; 7]);
+                continue;
+            }
+            let account = self.accounts.get(&keys[i]).cloned().unwrap_or_default();
+            input.extend_from_slice(&[0xff, flags[i].0 as u8, flags[i].1 as u8, account.executable as u8]);
+            input.extend_from_slice(&[0; 4]);
+            input.extend_from_slice(keys[i].as_ref());
+            input.extend_from_slice(account.owner.as_ref());
+            input.extend_from_slice(&account.lamports.to_le_bytes());
+            input.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            input.extend_from_slice(&account.data);
+            input.resize(input.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            input.resize(input.len().next_multiple_of(8), 0);
+            input.extend_from_slice(&u64::MAX.to_le_bytes());
+        }
+        input.extend_from_slice(&(ix.data.len() as u64).to_le_bytes());
+        input.extend_from_slice(&ix.data);
+        input.extend_from_slice(ix.program_id.as_ref());
+
+        // u64-backed so every field the loader reads is aligned
+        let mut buffer = vec![0u64; input.len().div_ceil(8)];
+        let bytes = unsafe { std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, input.len()) };
+        bytes.copy_from_slice(&input);
+
+        let (program_id, infos, data) = unsafe { deserialize(buffer.as_mut_ptr() as *mut u8) };
+        let entry = if *program_id == contracts::ID {
+            contracts::entry
+        } else if *program_id == chant_audit::ID {
+            chant_audit::entry
+        } else {
+            return Err(ProgramError::IncorrectProgramId);
+        };
+        match panic::catch_unwind(AssertUnwindSafe(|| entry(program_id, &infos, data))) {
+            Ok(result) => result?,
+            Err(payload) if panic_message(payload.as_ref()).contains(CPI_PANIC) => {
+                return Err(ProgramError::Custom(REACHED_CPI));
+            }
+            Err(payload) => panic::resume_unwind(payload),
+        }
+
+        let mut seen = vec![false; keys.len()];
+        let mut updates = Vec::new();
+        for (info, &i) in infos.iter().zip(&slots) {
+            if std::mem::replace(&mut seen[i], true) {
+                continue;
+            }
+            let account = AccountData {
+                lamports: info.lamports(),
+                data: info.data.borrow().to_vec(),
+                owner: *info.owner,
+                executable: info.executable,
+            };
+            updates.push((keys[i], account));
+        }
+        let lamports_after: u64 = updates.iter().map(|(_, a)| a.lamports).sum();
+        assert_eq!(lamports_before, lamports_after, "instruction created or destroyed lamports");
+        for (key, account) in updates {
+            self.accounts.insert(key, account);
+        }
+        Ok(())
+    }
+}
+
+/// Assert that `result` failed with the given program error code.
+#[track_caller]
+pub fn assert_error(result: TxResult, code: impl Into<u32>) {
+    let code = code.into();
+    match result {
+        Err(ProgramError::Custom(actual)) if actual == code => {}
+        other => panic!("expected custom error {code}, got {other:?}"),
+    }
+}
+
+/// Assert that the instruction passed every check and went on to a CPI (see
+/// REACHED_CPI).
+#[track_caller]
+pub fn assert_reaches_cpi(result: TxResult) {
+    assert_error(result, REACHED_CPI);
+}
+
+pub fn ix(data: impl InstructionData, accounts: impl ToAccountMetas) -> Instruction {
+    Instruction { program_id: contracts::ID, accounts: accounts.to_account_metas(None), data: data.data() }
+}
+
+pub fn pda(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &contracts::ID)
+}
+
+pub fn program_config() -> Pubkey {
+    pda(&[b"program_config"]).0
+}
+
+/// A ContributionRecord as init_if_needed creates it, before contribute fills it in.
+pub fn blank_record(bump: u8) -> ContributionRecord {
+    ContributionRecord {
+        pool: Pubkey::default(),
+        contributor: Pubkey::default(),
+        amount_lamports: 0,
+        claimed: false,
+        refunded: false,
+        delegate: Pubkey::default(),
+        memo: String::new(),
+        referrer: Pubkey::default(),
+        claimed_tokens: 0,
+        sequence: 0,
+        allocation_tokens: None,
+        voted_epoch: 0,
+        voted_approve: false,
+        delegator_count: 0,
+        bump,
+    }
+}
+
+/// A Funding pool as create_pool leaves it with every option off.
+pub fn launch_pool(authority: Pubkey, pool_id: &str, platform_wallet: Pubkey, bump: u8) -> LaunchPool {
+    LaunchPool {
+        authority,
+        pool_id: pool_id.to_string(),
+        target_lamports: TARGET,
+        current_lamports: 0,
+        deadline: DEADLINE,
+        status: PoolStatus::Funding,
+        winner: Pubkey::default(),
+        platform_wallet,
+        contributor_count: 0,
+        token_mint: Pubkey::default(),
+        merkle_root: [0; 32],
+        confirm_deadline: 0,
+        confirm_duration_secs: 172_800,
+        approve_lamports: 0,
+        reject_lamports: 0,
+        paused: false,
+        require_memo: false,
+        min_approving_contributors: 0,
+        approve_count: 0,
+        reject_count: 0,
+        total_claimed_tokens: 0,
+        claimed_count: 0,
+        token_supply: 1_000_000_000,
+        token_decimals: 6,
+        target_reached: false,
+        require_winner_payout: false,
+        winner_payout: Pubkey::default(),
+        distribution_executed: false,
+        platform_fee_in_sol: false,
+        proposal_epoch: 0,
+        cancelled_at: 0,
+        cancelled_by_expiry: false,
+        cancelled_lamports: 0,
+        contribution_tx_count: 0,
+        max_single_contributor_lamports: 0,
+        total_eligible_lamports: 0,
+        soft_cap_lamports: 0,
+        hard_cap_lamports: 0,
+        complete_delay_secs: 86_400,
+        complete_eligible_at: 0,
+        max_refinalizations: 0,
+        refinalize_count: 0,
+        min_contributors: 0,
+        winner_bond_lamports: 0,
+        milestone_period_secs: 2_592_000,
+        winner_bond_deposited: false,
+        escrow_winner_sol: false,
+        min_distribution_lamports: 0,
+        distribution_snapshot_lamports: 0,
+        distribution_contributor_count: 0,
+        paused_at: 0,
+        max_pause_secs: 1_209_600,
+        declined_winner: Pubkey::default(),
+        distributed_at: 0,
+        refund_penalty_bps: 0,
+        require_champion_proof: false,
+        allowlist_required: false,
+        allowlist_root: [0; 32],
+        metadata_uri: String::new(),
+        majority_reached: false,
+        max_contributor_bps: 0,
+        finalize_commitment: [0; 32],
+        approval_threshold_bps: 5000,
+        contribution_cutoff_secs: 0,
+        allocated_count: 0,
+        total_allocated_tokens: 0,
+        forbid_signer_winner: false,
+        chant_authority: Pubkey::default(),
+        require_finalize_commit: false,
+        winner_escrow_lamports: 0,
+        winner_released_lamports: 0,
+        milestone_count: 0,
+        tranche_count: 0,
+        next_sequence: 1,
+        bump,
+    }
+}
+
+/// A pool under a 2-of-3 multisig, with helpers that seed the state CPI-bound
+/// instructions leave and run the CPI-free ones.
+pub struct Launch {
+    pub bank: Bank,
+    pub signers: [Pubkey; 3],
+    pub multisig: Pubkey,
+    pub pool: Pubkey,
+    pub platform_wallet: Pubkey,
+    pub winner: Pubkey,
+    pub mint: Pubkey,
+}
+
+impl Launch {
+    pub const POOL_ID: &'static str = "chant-1";
+
+    pub fn new() -> Self {
+        let mut bank = Bank::new();
+        let signers = [bank.wallet(SOL), bank.wallet(SOL), bank.wallet(SOL)];
+        let (multisig, multisig_bump) = pda(&[b"multisig", signers[0].as_ref()]);
+        let ms = Multisig {
+            signers,
+            threshold: 2,
+            nonce: 0,
+            pool_count: 1,
+            last_pool_id: Self::POOL_ID.to_string(),
+            bump: multisig_bump,
+        };
+        bank.put(multisig, contracts::ID, &ms, Multisig::SPACE, 0);
+
+        let (pool, pool_bump) = pda(&[b"pool", multisig.as_ref(), Self::POOL_ID.as_bytes()]);
+        let platform_wallet = bank.wallet(SOL);
+        let state = launch_pool(multisig, Self::POOL_ID, platform_wallet, pool_bump);
+        bank.put(pool, contracts::ID, &state, LaunchPool::space(Self::POOL_ID), 0);
+
+        let winner = bank.wallet(SOL);
+        Launch { bank, signers, multisig, pool, platform_wallet, winner, mint: Pubkey::new_unique() }
+    }
+
+    pub fn state(&self) -> LaunchPool {
+        self.bank.get(&self.pool)
+    }
+
+    pub fn update(&mut self, f: impl FnOnce(&mut LaunchPool)) {
+        self.bank.update(&self.pool, f);
+    }
+
+    pub fn contribution(&self, contributor: &Pubkey) -> Pubkey {
+        pda(&[b"contribution", self.pool.as_ref(), contributor.as_ref()]).0
+    }
+
+    pub fn record(&self, contributor: &Pubkey) -> ContributionRecord {
+        self.bank.get(&self.contribution(contributor))
+    }
+
+    pub fn bond(&self) -> Pubkey {
+        pda(&[b"bond", self.pool.as_ref(), self.winner.as_ref()]).0
+    }
+
+    pub fn winner_escrow(&self) -> Pubkey {
+        pda(&[b"winner_escrow", self.pool.as_ref()]).0
+    }
+
+    pub fn pool_token_account(&self) -> Pubkey {
+        get_associated_token_address(&self.pool, &self.mint)
+    }
+
+    pub fn token_account(&self, owner: &Pubkey) -> Pubkey {
+        get_associated_token_address(owner, &self.mint)
+    }
+
+    /// A new wallet whose contribution of `amount_lamports` is recorded and
+    /// escrowed in the pool, as contribute leaves it.
+    pub fn contributor(&mut self, amount_lamports: u64) -> Pubkey {
+        let contributor = self.bank.wallet(SOL);
+        let (key, bump) = pda(&[b"contribution", self.pool.as_ref(), contributor.as_ref()]);
+        let mut record = ContributionRecord { pool: self.pool, contributor, ..blank_record(bump) };
+        self.update(|pool| {
+            pool.record_contribution(&mut record, amount_lamports).unwrap();
+            pool.mark_target_reached();
+        });
+        self.bank.airdrop(&self.pool, amount_lamports);
+        self.bank.put(key, contracts::ID, &record, ContributionRecord::SPACE, 0);
+        contributor
+    }
+
+    /// contribute from `contributor`. The record is seeded blank the first time,
+    /// as init_if_needed would create it; a contribution that passes every
+    /// check then fails with REACHED_CPI at the SOL transfer.
+    pub fn contribute(&mut self, contributor: &Pubkey, amount_lamports: u64, memo: &str) -> TxResult {
+        let (contribution, bump) = pda(&[b"contribution", self.pool.as_ref(), contributor.as_ref()]);
+        if !self.bank.exists(&contribution) {
+            self.bank.put(contribution, contracts::ID, &blank_record(bump), ContributionRecord::SPACE, 0);
+        }
+        self.bank.process(ix(
+            contracts::instruction::Contribute {
+                amount_lamports,
+                memo: memo.to_string(),
+                referrer: None,
+                allowlist_proof: vec![],
+            },
+            contracts::accounts::Contribute {
+                pool: self.pool,
+                program_config: program_config(),
+                contribution,
+                contributor: *contributor,
+                referral_stats: None,
+                wsol_account: None,
+                token_program: None,
+                system_program: system_program::ID,
+            },
+        ))
+    }
+
+    /// The pool's launch mint, authority the pool, as propose_finalize expects.
+    pub fn create_mint(&mut self) {
+        let decimals = self.state().token_decimals;
+        self.bank.create_mint(self.mint, self.pool, decimals, 0);
+    }
+
+    /// State after an approved execute_distribution: the supply minted to the
+    /// pool's token account, the platform's 1% moved out, the winner's SOL sent
+    /// (or escrowed on escrow pools).
+    pub fn distribute(&mut self) {
+        let state = self.state();
+        let total_tokens = state.total_tokens().unwrap();
+        let platform_tokens = state.platform_tokens().unwrap();
+        self.bank.create_mint(self.mint, self.pool, state.token_decimals, total_tokens);
+        self.bank.create_token_account(self.pool_token_account(), self.mint, self.pool, total_tokens - platform_tokens);
+        let platform_token_account = self.token_account(&self.platform_wallet);
+        self.bank.create_token_account(platform_token_account, self.mint, self.platform_wallet, platform_tokens);
+
+        let winner_sol = state.preview_distribution(self.bank.now()).unwrap().winner_sol;
+        let (mint, winner, now) = (self.mint, self.winner, self.bank.now());
+        self.update(|pool| {
+            pool.token_mint = mint;
+            pool.winner = winner;
+            pool.merkle_root = [1; 32];
+            pool.record_distribution(pool.current_lamports, winner_sol, now);
+        });
+        if state.escrows_winner_sol() {
+            let bump = pda(&[b"winner_escrow", self.pool.as_ref()]).1;
+            let escrow = WinnerEscrow { pool: self.pool, winner: self.winner, bump };
+            self.bank.put(self.winner_escrow(), contracts::ID, &escrow, WinnerEscrow::SPACE, 0);
+            self.bank.shift(&self.pool, &self.winner_escrow(), winner_sol);
+        } else {
+            self.bank.shift(&self.pool, &self.winner, winner_sol);
+        }
+    }
+
+    /// The winner's bond as register_winner_payout deposits it.
+    pub fn deposit_bond(&mut self, amount_lamports: u64) {
+        let state = self.state();
+        let bump = pda(&[b"bond", self.pool.as_ref(), self.winner.as_ref()]).1;
+        let bond = WinnerBond {
+            pool: self.pool,
+            winner: self.winner,
+            amount_lamports,
+            deadline: self.bank.now() + state.milestone_period_secs,
+            milestone_met: false,
+            bump,
+        };
+        self.bank.put(self.bond(), contracts::ID, &bond, WinnerBond::SPACE, amount_lamports);
+        let winner = self.winner;
+        self.update(|pool| {
+            pool.winner = winner;
+            pool.winner_bond_lamports = amount_lamports;
+            pool.winner_bond_deposited = true;
+        });
+    }
+
+    pub fn multisig_action(&self) -> contracts::accounts::MultisigAction {
+        contracts::accounts::MultisigAction { pool: self.pool, multisig: self.multisig, signer: self.signers[1] }
+    }
+
+    pub fn propose_finalize(&self) -> contracts::accounts::ProposeFinalize {
+        contracts::accounts::ProposeFinalize {
+            pool: self.pool,
+            program_config: program_config(),
+            multisig: self.multisig,
+            signer: self.signers[1],
+            winner: self.winner,
+            token_mint: self.mint,
+            champion: None,
+            chant: None,
+        }
+    }
+
+    /// propose_finalize for `winner`, opening a confirmation round.
+    pub fn propose(&mut self) -> TxResult {
+        let merkle_root = [1u8; 32];
+        self.bank.process(ix(contracts::instruction::ProposeFinalize { merkle_root }, self.propose_finalize()))
+    }
+
+    /// confirm_vote for `contributor`, counting `delegators`' records. The
+    /// round's vote record is seeded empty, as init_if_needed would create it.
+    pub fn confirm_vote(&mut self, contributor: &Pubkey, approve: bool, delegators: &[Pubkey]) -> TxResult {
+        let epoch = self.state().proposal_epoch;
+        let (vote, bump) = pda(&[b"confirm_vote", self.pool.as_ref(), contributor.as_ref(), &epoch.to_le_bytes()]);
+        if !self.bank.exists(&vote) {
+            let record = ConfirmationVoteRecord {
+                pool: Pubkey::default(),
+                contributor: Pubkey::default(),
+                approve: false,
+                weight: 0,
+                has_voted: false,
+                epoch: 0,
+                bump,
+            };
+            self.bank.put(vote, contracts::ID, &record, ConfirmationVoteRecord::SPACE, 0);
+        }
+        let mut ix = ix(
+            contracts::instruction::ConfirmVote { approve },
+            contracts::accounts::ConfirmVote {
+                pool: self.pool,
+                contribution: self.contribution(contributor),
+                confirmation_vote: vote,
+                contributor: *contributor,
+                system_program: system_program::ID,
+            },
+        );
+        for delegator in delegators {
+            ix.accounts.push(AccountMeta::new(self.contribution(delegator), false));
+        }
+        self.bank.process(ix)
+    }
+
+    pub fn delegate_vote(&mut self, delegator: &Pubkey, delegate: &Pubkey) -> TxResult {
+        let epoch = self.state().proposal_epoch;
+        let vote = pda(&[b"confirm_vote", self.pool.as_ref(), delegator.as_ref(), &epoch.to_le_bytes()]).0;
+        self.bank.process(ix(
+            contracts::instruction::DelegateVote {},
+            contracts::accounts::DelegateVote {
+                pool: self.pool,
+                contribution: self.contribution(delegator),
+                confirmation_vote: vote,
+                delegate: *delegate,
+                delegate_contribution: self.contribution(delegate),
+                contributor: *delegator,
+            },
+        ))
+    }
+
+    /// execute_distribution with the mint and token accounts in place. Its
+    /// guards run in-process; an approved pool fails at the mint CPI.
+    pub fn execute_distribution(&mut self) -> TxResult {
+        let platform_token_account = self.token_account(&self.platform_wallet);
+        if !self.bank.exists(&platform_token_account) {
+            self.bank.create_token_account(platform_token_account, self.mint, self.platform_wallet, 0);
+        }
+        self.execute_distribution_to(platform_token_account)
+    }
+
+    /// execute_distribution paying the token fee into `platform_token_account`.
+    pub fn execute_distribution_to(&mut self, platform_token_account: Pubkey) -> TxResult {
+        if !self.bank.exists(&self.pool_token_account()) {
+            self.bank.create_token_account(self.pool_token_account(), self.mint, self.pool, 0);
+        }
+        let caller = self.signers[0];
+        self.bank.process(ix(
+            contracts::instruction::ExecuteDistribution {},
+            contracts::accounts::ExecuteDistribution {
+                pool: self.pool,
+                program_config: program_config(),
+                winner: self.winner,
+                token_mint: self.mint,
+                pool_token_account: self.pool_token_account(),
+                platform_token_account: Some(platform_token_account),
+                platform_wallet: self.platform_wallet,
+                winner_escrow: None,
+                caller,
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+        ))
+    }
+
+    pub fn pause(&mut self) -> TxResult {
+        self.bank.process(ix(contracts::instruction::PausePool {}, self.multisig_action()))
+    }
+
+    pub fn unpause(&mut self) -> TxResult {
+        self.bank.process(ix(contracts::instruction::UnpausePool {}, self.multisig_action()))
+    }
+
+    /// create_pool for a second pool under the same multisig. Pool creation
+    /// goes through a CPI, so only configs create_pool rejects can run here.
+    pub fn create_pool(&mut self, config: PoolConfig) -> TxResult {
+        self.create_pool_with_id("chant-2", config)
+    }
+
+    pub fn create_pool_with_id(&mut self, pool_id: &str, config: PoolConfig) -> TxResult {
+        let pool_id = pool_id.to_string();
+        let pool = pda(&[b"pool", self.multisig.as_ref(), pool_id.as_bytes()]).0;
+        self.bank.process(ix(
+            contracts::instruction::CreatePool {
+                target_lamports: TARGET,
+                deadline: DEADLINE,
+                pool_id,
+                confirm_duration_secs: 0,
+                config,
+            },
+            contracts::accounts::CreatePool {
+                pool,
+                multisig: self.multisig,
+                payer: self.signers[0],
+                platform_wallet: self.platform_wallet,
+                system_program: system_program::ID,
+            },
+        ))
+    }
+
+    pub fn cancel(&mut self) -> TxResult {
+        self.bank.process(ix(contracts::instruction::CancelPool {}, self.multisig_action()))
+    }
+
+    pub fn refund(&mut self, contributor: &Pubkey, with_platform_wallet: bool) -> TxResult {
+        self.bank.process(ix(
+            contracts::instruction::Refund {},
+            contracts::accounts::Refund {
+                pool: self.pool,
+                contribution: self.contribution(contributor),
+                contributor: *contributor,
+                platform_wallet: with_platform_wallet.then_some(self.platform_wallet),
+                system_program: system_program::ID,
+            },
+        ))
+    }
+
+    pub fn record_milestone(&mut self, with_bond: bool) -> TxResult {
+        self.bank.process(ix(
+            contracts::instruction::RecordMilestone {},
+            contracts::accounts::RecordMilestone {
+                pool: self.pool,
+                multisig: self.multisig,
+                signer: self.signers[1],
+                bond: with_bond.then(|| self.bond()),
+            },
+        ))
+    }
+}
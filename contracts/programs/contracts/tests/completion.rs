@@ -0,0 +1,77 @@
+//! propose_complete, then complete_pool or transfer_mint_authority: the timelock
+//! before the pool gives up its mint authority.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use contracts::LaunchError;
+
+fn propose_complete(launch: &mut Launch) -> TxResult {
+    let accounts = launch.multisig_action();
+    launch.bank.process(ix(contracts::instruction::ProposeComplete {}, accounts))
+}
+
+fn complete_accounts(launch: &Launch) -> contracts::accounts::CompletePool {
+    contracts::accounts::CompletePool {
+        pool: launch.pool,
+        multisig: launch.multisig,
+        signer: launch.signers[0],
+        token_mint: launch.mint,
+        token_program: anchor_spl::token::ID,
+    }
+}
+
+fn complete_pool(launch: &mut Launch) -> TxResult {
+    let accounts = complete_accounts(launch);
+    launch.bank.process(ix(contracts::instruction::CompletePool {}, accounts))
+}
+
+fn transfer_mint_authority(launch: &mut Launch, new_authority: Pubkey) -> TxResult {
+    let accounts = complete_accounts(launch);
+    launch.bank.process(ix(contracts::instruction::TransferMintAuthority { new_authority }, accounts))
+}
+
+#[test]
+fn mint_authority_burns_only_after_the_timelock() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.distribute();
+    assert_error(complete_pool(&mut launch), LaunchError::CompleteNotProposed);
+
+    propose_complete(&mut launch).unwrap();
+    let eligible_at = launch.state().complete_eligible_at;
+    assert_eq!(eligible_at, T0 + launch.state().complete_delay_secs);
+    assert_error(propose_complete(&mut launch), LaunchError::CompleteTimelockActive);
+
+    launch.bank.set_time(eligible_at - 1);
+    assert_error(complete_pool(&mut launch), LaunchError::CompleteTimelockActive);
+    launch.bank.set_time(eligible_at);
+    assert_reaches_cpi(complete_pool(&mut launch));
+}
+
+#[test]
+fn completion_waits_for_distribution() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    assert_error(propose_complete(&mut launch), LaunchError::PoolNotDistributing);
+}
+
+#[test]
+fn mint_authority_transfers_after_the_same_timelock() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.distribute();
+    let governance = Pubkey::new_unique();
+    assert_error(transfer_mint_authority(&mut launch, governance), LaunchError::CompleteNotProposed);
+
+    propose_complete(&mut launch).unwrap();
+    assert_error(transfer_mint_authority(&mut launch, governance), LaunchError::CompleteTimelockActive);
+    launch.bank.set_time(launch.state().complete_eligible_at);
+
+    // Handing it to nobody, or back to the pool, isn't a transfer
+    let pool = launch.pool;
+    assert_error(transfer_mint_authority(&mut launch, Pubkey::default()), LaunchError::InvalidNewMintAuthority);
+    assert_error(transfer_mint_authority(&mut launch, pool), LaunchError::InvalidNewMintAuthority);
+    assert_reaches_cpi(transfer_mint_authority(&mut launch, governance));
+}
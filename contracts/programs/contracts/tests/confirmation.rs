@@ -0,0 +1,264 @@
+//! The confirmation vote: weights, head counts, delegated weight and expiry.
+
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use contracts::{ConfirmationVoteRecord, LaunchError, PoolStatus};
+
+/// A pool of three contributors (4, 1 and 1 SOL) in its confirmation round.
+fn confirming() -> (Launch, [Pubkey; 3]) {
+    let mut launch = Launch::new();
+    let voters = [launch.contributor(4 * SOL), launch.contributor(SOL), launch.contributor(SOL)];
+    launch.create_mint();
+    launch.propose().unwrap();
+    (launch, voters)
+}
+
+#[test]
+fn head_counts_track_apart_from_weights() {
+    let (mut launch, [a, b, c]) = confirming();
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    launch.confirm_vote(&b, false, &[]).unwrap();
+    launch.confirm_vote(&c, false, &[]).unwrap();
+
+    // Approve wins by weight, reject by heads
+    let state = launch.state();
+    assert_eq!((state.approve_lamports, state.approve_count), (4 * SOL, 1));
+    assert_eq!((state.reject_lamports, state.reject_count), (2 * SOL, 2));
+    launch
+        .bank
+        .process(ix(contracts::instruction::GetVoteTally {}, contracts::accounts::ViewPool { pool: launch.pool }))
+        .unwrap();
+}
+
+#[test]
+fn delegators_count_as_heads() {
+    let (mut launch, [a, b, c]) = confirming();
+    launch.delegate_vote(&b, &a).unwrap();
+    launch.delegate_vote(&c, &a).unwrap();
+
+    launch.confirm_vote(&a, false, &[b, c]).unwrap();
+    let state = launch.state();
+    assert_eq!((state.reject_lamports, state.reject_count), (6 * SOL, 3));
+    assert_eq!((state.approve_lamports, state.approve_count), (0, 0));
+}
+
+#[test]
+fn tally_overflow_is_an_error() {
+    let (mut launch, [a, _, _]) = confirming();
+    launch.update(|pool| pool.approve_lamports = u64::MAX - SOL);
+    assert_error(launch.confirm_vote(&a, true, &[]), LaunchError::MathOverflow);
+
+    launch.update(|pool| {
+        pool.approve_lamports = 0;
+        pool.approve_count = u32::MAX;
+    });
+    assert_error(launch.confirm_vote(&a, true, &[]), LaunchError::MathOverflow);
+}
+
+fn expire(launch: &mut Launch) -> TxResult {
+    let accounts = contracts::accounts::ExpireConfirmation { pool: launch.pool, caller: launch.signers[0] };
+    launch.bank.process(ix(contracts::instruction::ExpireConfirmation {}, accounts))
+}
+
+fn reopen(launch: &mut Launch) -> TxResult {
+    let accounts = launch.multisig_action();
+    launch.bank.process(ix(contracts::instruction::ReopenConfirmation {}, accounts))
+}
+
+#[test]
+fn low_turnout_cancellation_reopens_within_the_grace_window() {
+    let (mut launch, _) = confirming();
+    let deadline = launch.state().confirm_deadline;
+    launch.bank.set_time(deadline);
+    expire(&mut launch).unwrap();
+    let state = launch.state();
+    assert!(state.status == PoolStatus::Cancelled);
+    assert_eq!(state.cancelled_at, deadline);
+
+    launch.bank.set_time(deadline + 86_400 + 1);
+    assert_error(reopen(&mut launch), LaunchError::ReopenNotAllowed);
+
+    launch.bank.set_time(deadline + 86_400);
+    reopen(&mut launch).unwrap();
+    let state = launch.state();
+    assert!(state.status == PoolStatus::Confirming);
+    assert_eq!(state.confirm_deadline, deadline + 86_400 + state.confirm_duration_secs);
+    assert_eq!((state.approve_lamports, state.reject_lamports), (0, 0));
+    assert_eq!(state.cancelled_at, 0);
+}
+
+#[test]
+fn majority_rejection_cannot_be_reopened() {
+    let (mut launch, [whale, a, _]) = confirming();
+    launch.confirm_vote(&whale, false, &[]).unwrap();
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    launch.bank.set_time(launch.state().confirm_deadline);
+    expire(&mut launch).unwrap();
+    assert!(launch.state().status == PoolStatus::Cancelled);
+    assert_error(reopen(&mut launch), LaunchError::ReopenNotAllowed);
+}
+
+#[test]
+fn turnout_is_measured_against_the_proposal_snapshot() {
+    let (mut launch, [whale, a, _]) = confirming();
+    assert_eq!(launch.state().total_eligible_lamports, 6 * SOL);
+
+    launch.confirm_vote(&whale, true, &[]).unwrap();
+    launch.confirm_vote(&a, false, &[]).unwrap();
+    // Lamports arriving after the proposal don't dilute turnout
+    launch.update(|pool| pool.current_lamports += 4 * SOL);
+    let state = launch.state();
+    assert_eq!(state.total_eligible_lamports, 6 * SOL);
+    assert_eq!(state.turnout_bps(), 5 * 10000 / 6);
+}
+
+#[test]
+fn refunded_contributor_has_no_vote_in_a_later_round() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.deadline = T0 + 30 * 86_400);
+    let a = launch.contributor(4 * SOL);
+    let b = launch.contributor(6 * SOL);
+
+    // a takes the pause-timeout refund while the pool is still funding
+    launch.pause().unwrap();
+    let max_pause_secs = launch.state().max_pause_secs;
+    launch.bank.set_time(T0 + max_pause_secs + 1);
+    launch.refund(&a, false).unwrap();
+    launch.unpause().unwrap();
+    launch.create_mint();
+    launch.propose().unwrap();
+
+    assert_error(launch.confirm_vote(&a, false, &[]), LaunchError::NoContribution);
+    launch.confirm_vote(&b, true, &[]).unwrap();
+    let state = launch.state();
+    assert_eq!(state.total_eligible_lamports, 6 * SOL);
+    assert_eq!((state.approve_lamports, state.reject_lamports), (6 * SOL, 0));
+}
+
+#[test]
+fn rejected_winner_is_replaced_by_a_second_proposal() {
+    let (mut launch, [whale, a, _]) = confirming();
+    launch.update(|pool| pool.max_refinalizations = 1);
+    launch.confirm_vote(&whale, false, &[]).unwrap();
+    launch.bank.set_time(launch.state().confirm_deadline);
+    expire(&mut launch).unwrap();
+    let state = launch.state();
+    assert!(state.status == PoolStatus::Funding);
+    assert_eq!(state.winner, Pubkey::default());
+
+    launch.winner = launch.bank.wallet(SOL);
+    launch.propose().unwrap();
+    let state = launch.state();
+    assert!(state.status == PoolStatus::Confirming);
+    assert_eq!(state.winner, launch.winner);
+    assert_eq!((state.refinalize_count, state.proposal_epoch), (1, 2));
+    // A fresh round: last round's voters vote again
+    launch.confirm_vote(&whale, true, &[]).unwrap();
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    assert_eq!(launch.state().approve_count, 2);
+
+    // No re-proposals left
+    launch.update(|pool| pool.reset_for_refinalize());
+    assert_error(launch.propose(), LaunchError::MaxRefinalizationsReached);
+}
+
+fn set_confirm_duration(launch: &mut Launch, confirm_duration_secs: i64) -> TxResult {
+    let accounts = launch.multisig_action();
+    launch.bank.process(ix(contracts::instruction::SetConfirmDuration { confirm_duration_secs }, accounts))
+}
+
+#[test]
+fn confirm_duration_is_adjustable_until_finalize() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    set_confirm_duration(&mut launch, 3 * 86_400).unwrap();
+    assert_eq!(launch.state().confirm_duration_secs, 3 * 86_400);
+    assert_error(set_confirm_duration(&mut launch, 86_399), LaunchError::ConfirmTooShort);
+    assert_error(set_confirm_duration(&mut launch, 604_801), LaunchError::ConfirmTooLong);
+
+    // The new window is the one propose_finalize opens
+    launch.create_mint();
+    launch.propose().unwrap();
+    assert_eq!(launch.state().confirm_deadline, launch.bank.now() + 3 * 86_400);
+    assert_error(set_confirm_duration(&mut launch, 86_400), LaunchError::PoolNotFunding);
+}
+
+#[test]
+fn last_rounds_votes_do_not_carry_over() {
+    let (mut launch, [whale, a, _]) = confirming();
+    launch.update(|pool| pool.max_refinalizations = 1);
+    launch.confirm_vote(&whale, true, &[]).unwrap();
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    let first_epoch = launch.state().proposal_epoch;
+    let old_vote = ConfirmationVoteRecord::pda(&launch.pool, &whale, first_epoch).0;
+
+    launch.update(|pool| pool.reset_for_refinalize());
+    launch.winner = launch.bank.wallet(SOL);
+    launch.propose().unwrap();
+    let state = launch.state();
+    assert_eq!(state.proposal_epoch, first_epoch + 1);
+    assert_eq!((state.approve_count, state.approve_lamports), (0, 0));
+
+    // The old round's record is still there but doesn't satisfy this round's seeds
+    assert!(launch.bank.exists(&old_vote));
+    let result = launch.bank.process(ix(
+        contracts::instruction::ConfirmVote { approve: true },
+        contracts::accounts::ConfirmVote {
+            pool: launch.pool,
+            contribution: launch.contribution(&whale),
+            confirmation_vote: old_vote,
+            contributor: whale,
+            system_program: anchor_lang::system_program::ID,
+        },
+    ));
+    assert_error(result, ErrorCode::ConstraintSeeds);
+
+    launch.confirm_vote(&whale, false, &[]).unwrap();
+    let state = launch.state();
+    assert_eq!((state.approve_count, state.reject_count), (0, 1));
+}
+
+#[test]
+fn finalize_proposed_carries_the_turnout_snapshot() {
+    let (mut launch, _) = confirming();
+    let event = launch.state().finalize_proposed(launch.pool);
+    assert_eq!((event.total_eligible_lamports, event.contributor_count), (6 * SOL, 3));
+    assert_eq!(event.winner, launch.winner);
+    assert_eq!(event.confirm_deadline, launch.state().confirm_deadline);
+
+    // Contributions after the proposal don't move the snapshot it was sent with
+    launch.update(|pool| pool.current_lamports += SOL);
+    assert_eq!(launch.state().finalize_proposed(launch.pool).total_eligible_lamports, 6 * SOL);
+}
+
+#[test]
+fn majority_is_announced_once_per_proposal() {
+    let (mut launch, [whale, a, b]) = confirming();
+    launch.update(|pool| pool.max_refinalizations = 1);
+    assert!(!launch.state().majority_reached);
+
+    // a's vote is the one that first makes the pool approvable
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    let mut pool = launch.state();
+    assert!(pool.is_approved() && pool.majority_reached);
+    assert!(!pool.mark_majority_reached());
+
+    // Losing the majority doesn't clear the mark, so later votes can't announce it again
+    launch.confirm_vote(&whale, false, &[]).unwrap();
+    assert!(!launch.state().is_approved());
+    launch.confirm_vote(&b, true, &[]).unwrap();
+    assert!(launch.state().majority_reached);
+
+    // A new proposal gets its own announcement
+    launch.update(|pool| pool.reset_for_refinalize());
+    launch.winner = launch.bank.wallet(SOL);
+    launch.propose().unwrap();
+    let mut pool = launch.state();
+    assert!(!pool.majority_reached);
+    pool.approve_lamports = SOL;
+    pool.approve_count = 1;
+    assert!(pool.mark_majority_reached());
+}
@@ -0,0 +1,245 @@
+//! Contributions and the records they leave. The SOL moves over a CPI, so
+//! contribute itself is covered through its guards.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::system_program;
+use chant_audit::hash;
+use common::*;
+use contracts::{ContributionRecord, LaunchError, PoolConfig, PoolStatus};
+
+/// contracts::MAX_MEMO_LEN
+const MAX_MEMO_LEN: usize = 64;
+
+#[test]
+fn sequence_stays_unique_after_a_refund() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.deadline = T0 + 30 * 86_400);
+    let a = launch.contributor(SOL);
+    let b = launch.contributor(SOL);
+
+    // A pause-timeout refund frees b's slot in contributor_count...
+    launch.pause().unwrap();
+    launch.bank.set_time(T0 + launch.state().max_pause_secs + 1);
+    launch.refund(&b, false).unwrap();
+    launch.unpause().unwrap();
+    assert_eq!(launch.state().contributor_count, 1);
+
+    // ...but not its sequence
+    let c = launch.contributor(SOL);
+    assert_eq!(launch.record(&a).sequence, 1);
+    assert_eq!(launch.record(&b).sequence, 2);
+    assert_eq!(launch.record(&c).sequence, 3);
+    assert_eq!(launch.state().next_sequence, 4);
+}
+
+#[test]
+fn memo_is_required_when_the_pool_asks() {
+    let mut launch = Launch::new();
+    let a = launch.bank.wallet(2 * SOL);
+    assert_reaches_cpi(launch.contribute(&a, SOL, ""));
+
+    launch.update(|pool| pool.require_memo = true);
+    assert_error(launch.contribute(&a, SOL, ""), LaunchError::MemoRequired);
+    assert_reaches_cpi(launch.contribute(&a, SOL, "ref:community-call"));
+}
+
+#[test]
+fn memo_has_a_length_limit() {
+    let mut launch = Launch::new();
+    let a = launch.bank.wallet(2 * SOL);
+    assert_reaches_cpi(launch.contribute(&a, SOL, &"m".repeat(MAX_MEMO_LEN)));
+    assert_error(launch.contribute(&a, SOL, &"m".repeat(MAX_MEMO_LEN + 1)), LaunchError::MemoTooLong);
+}
+
+#[test]
+fn target_is_reached_once() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.hard_cap_lamports = 3 * TARGET);
+    launch.contributor(TARGET - 1);
+    assert!(!launch.state().target_reached);
+
+    let mut pool = launch.state();
+    pool.current_lamports = TARGET - 1;
+    assert!(!pool.mark_target_reached());
+    pool.current_lamports = TARGET + SOL;
+    assert!(pool.mark_target_reached());
+    assert!(pool.target_reached);
+    // Later over-target contributions don't fire it again
+    pool.current_lamports = 2 * TARGET;
+    assert!(!pool.mark_target_reached());
+
+    launch.contributor(2 * SOL);
+    assert!(launch.state().target_reached);
+    assert!(!launch.state().mark_target_reached());
+}
+
+#[test]
+fn repeat_contributions_count_once_per_wallet() {
+    let mut launch = Launch::new();
+    let (a, b) = (launch.contributor(SOL), launch.contributor(2 * SOL));
+    let mut pool = launch.state();
+    let (mut a_record, mut b_record) = (launch.record(&a), launch.record(&b));
+
+    pool.record_contribution(&mut a_record, 3 * SOL).unwrap();
+    pool.record_contribution(&mut b_record, SOL / 2).unwrap();
+    pool.record_contribution(&mut a_record, SOL).unwrap();
+    let mut c_record = blank_record(0);
+    pool.record_contribution(&mut c_record, 4 * SOL).unwrap();
+
+    assert_eq!(pool.contributor_count, 3);
+    assert_eq!(pool.contribution_tx_count, 6);
+    assert_eq!(pool.current_lamports, 23 * SOL / 2);
+    // a's 5 SOL over three transactions beats c's single 4 SOL
+    assert_eq!(pool.max_single_contributor_lamports, 5 * SOL);
+    assert_eq!(a_record.amount_lamports, 5 * SOL);
+    assert_eq!((a_record.sequence, b_record.sequence, c_record.sequence), (1, 2, 3));
+}
+
+#[test]
+fn hard_cap_clamps_the_straddling_contribution() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.hard_cap_lamports = 12 * SOL);
+    launch.contributor(11 * SOL);
+    let late = launch.bank.wallet(5 * SOL);
+
+    // Only the 1 SOL of room is taken; the rest stays in the wallet
+    assert_eq!(launch.state().hard_cap_remaining(), Some(SOL));
+    assert_reaches_cpi(launch.contribute(&late, 3 * SOL, ""));
+
+    // A full pool normally stops at PoolNotFunding; the cap check stands behind it
+    launch.update(|pool| pool.current_lamports = 12 * SOL);
+    assert_error(launch.contribute(&late, SOL, ""), LaunchError::HardCapExceeded);
+    launch.update(|pool| pool.status = PoolStatus::Funded);
+    assert_error(launch.contribute(&late, SOL, ""), LaunchError::PoolNotFunding);
+}
+
+#[test]
+fn caps_are_validated_at_creation() {
+    let mut launch = Launch::new();
+    let config = PoolConfig { soft_cap_lamports: 13 * SOL, hard_cap_lamports: 12 * SOL, ..Default::default() };
+    assert_error(launch.create_pool(config), LaunchError::InvalidCapOrder);
+    let config = PoolConfig { soft_cap_lamports: 12 * SOL, hard_cap_lamports: 12 * SOL, ..Default::default() };
+    assert_reaches_cpi(launch.create_pool(config));
+}
+
+#[test]
+fn first_referrer_wins() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    let mut record = launch.record(&a);
+    let (first, second) = (launch.bank.wallet(0), launch.bank.wallet(0));
+
+    assert!(record.set_referrer(first));
+    assert!(!record.set_referrer(second));
+    assert_eq!(record.referrer, first);
+}
+
+#[test]
+fn contributor_cannot_refer_themselves() {
+    let mut launch = Launch::new();
+    let a = launch.bank.wallet(2 * SOL);
+    let (contribution, bump) = ContributionRecord::pda(&launch.pool, &a);
+    launch.bank.put(contribution, contracts::ID, &blank_record(bump), ContributionRecord::SPACE, 0);
+    let result = launch.bank.process(ix(
+        contracts::instruction::Contribute {
+            amount_lamports: SOL,
+            memo: String::new(),
+            referrer: Some(a),
+            allowlist_proof: vec![],
+        },
+        contracts::accounts::Contribute {
+            pool: launch.pool,
+            program_config: program_config(),
+            contribution,
+            contributor: a,
+            referral_stats: None,
+            wsol_account: None,
+            token_program: None,
+            system_program: system_program::ID,
+        },
+    ));
+    assert_error(result, LaunchError::SelfReferral);
+}
+
+fn contribute_with_proof(launch: &mut Launch, contributor: Pubkey, allowlist_proof: Vec<[u8; 32]>) -> TxResult {
+    let (contribution, bump) = ContributionRecord::pda(&launch.pool, &contributor);
+    if !launch.bank.exists(&contribution) {
+        launch.bank.put(contribution, contracts::ID, &blank_record(bump), ContributionRecord::SPACE, 0);
+    }
+    launch.bank.process(ix(
+        contracts::instruction::Contribute { amount_lamports: SOL, memo: String::new(), referrer: None, allowlist_proof },
+        contracts::accounts::Contribute {
+            pool: launch.pool,
+            program_config: program_config(),
+            contribution,
+            contributor,
+            referral_stats: None,
+            wsol_account: None,
+            token_program: None,
+            system_program: system_program::ID,
+        },
+    ))
+}
+
+#[test]
+fn allowlist_admits_only_proven_wallets() {
+    let mut launch = Launch::new();
+    let config = PoolConfig { allowlist_required: true, ..Default::default() };
+    assert_error(launch.create_pool(config), LaunchError::InvalidAllowlistRoot);
+
+    // Two approved wallets: each one's proof is the other's leaf
+    let (a, b, outsider) = (launch.bank.wallet(2 * SOL), launch.bank.wallet(2 * SOL), launch.bank.wallet(2 * SOL));
+    let leaf = |wallet: &Pubkey| hash::tagged_hash(b"UC:ALLOW", &[wallet.as_ref()]);
+    let (a_leaf, b_leaf) = (leaf(&a), leaf(&b));
+    let root = if a_leaf <= b_leaf { hash::node_hash(&a_leaf, &b_leaf) } else { hash::node_hash(&b_leaf, &a_leaf) };
+    launch.update(|pool| {
+        pool.allowlist_required = true;
+        pool.allowlist_root = root;
+    });
+
+    assert_reaches_cpi(contribute_with_proof(&mut launch, a, vec![b_leaf]));
+    assert_error(contribute_with_proof(&mut launch, b, vec![[7; 32]]), LaunchError::NotAllowlisted);
+    assert_error(contribute_with_proof(&mut launch, b, vec![]), LaunchError::NotAllowlisted);
+    assert_reaches_cpi(contribute_with_proof(&mut launch, b, vec![a_leaf]));
+    // Someone else's proof doesn't carry over to an off-list wallet
+    assert_error(contribute_with_proof(&mut launch, outsider, vec![b_leaf]), LaunchError::NotAllowlisted);
+
+    launch.update(|pool| pool.allowlist_required = false);
+    assert_reaches_cpi(contribute_with_proof(&mut launch, outsider, vec![]));
+}
+
+#[test]
+fn one_wallet_holds_at_most_its_share_of_the_target() {
+    let mut launch = Launch::new();
+    let config = PoolConfig { max_contributor_bps: 10_001, ..Default::default() };
+    assert_error(launch.create_pool(config), LaunchError::InvalidMaxContributorBps);
+
+    // 20% of a 10 SOL target
+    launch.update(|pool| pool.max_contributor_bps = 2000);
+    assert_eq!(launch.state().max_contributor_lamports(), Some(2 * SOL));
+    let fresh = launch.bank.wallet(5 * SOL);
+    assert_error(launch.contribute(&fresh, 2 * SOL + 1, ""), LaunchError::ContributorShareTooLarge);
+    assert_reaches_cpi(launch.contribute(&fresh, 2 * SOL, ""));
+
+    // The cap is cumulative across a wallet's contributions
+    let a = launch.contributor(3 * SOL / 2);
+    assert_error(launch.contribute(&a, SOL / 2 + 1, ""), LaunchError::ContributorShareTooLarge);
+    assert_reaches_cpi(launch.contribute(&a, SOL / 2, ""));
+}
+
+#[test]
+fn contributions_close_before_the_deadline() {
+    let mut launch = Launch::new();
+    // A cutoff that already covers the whole window is refused at creation
+    let config = PoolConfig { contribution_cutoff_secs: DEADLINE - T0, ..Default::default() };
+    assert_error(launch.create_pool(config), LaunchError::ContributionWindowClosed);
+
+    launch.update(|pool| pool.contribution_cutoff_secs = 3_600);
+    let a = launch.bank.wallet(2 * SOL);
+    launch.bank.set_time(DEADLINE - 3_601);
+    assert_reaches_cpi(launch.contribute(&a, SOL, ""));
+    launch.bank.set_time(DEADLINE - 3_600);
+    assert_error(launch.contribute(&a, SOL, ""), LaunchError::ContributionWindowClosed);
+}
@@ -0,0 +1,53 @@
+//! Confirmation-vote delegation: one hop, from a live contributor to a live
+//! contributor.
+
+mod common;
+
+use common::*;
+use contracts::LaunchError;
+
+#[test]
+fn delegate_counts_its_delegators() {
+    let mut launch = Launch::new();
+    let (a, b, c) = (launch.contributor(SOL), launch.contributor(SOL), launch.contributor(SOL));
+    launch.delegate_vote(&a, &c).unwrap();
+    launch.delegate_vote(&b, &c).unwrap();
+
+    assert_eq!(launch.record(&a).delegate, c);
+    assert_eq!(launch.record(&c).delegator_count, 2);
+}
+
+#[test]
+fn delegate_that_delegated_is_rejected() {
+    let mut launch = Launch::new();
+    let (a, b, c) = (launch.contributor(SOL), launch.contributor(SOL), launch.contributor(SOL));
+    launch.delegate_vote(&b, &c).unwrap();
+    assert_error(launch.delegate_vote(&a, &b), LaunchError::DelegationConflict);
+}
+
+#[test]
+fn refunded_delegate_is_rejected() {
+    let mut launch = Launch::new();
+    let (a, b) = (launch.contributor(SOL), launch.contributor(SOL));
+    launch.bank.update(&launch.contribution(&b), |record: &mut contracts::ContributionRecord| {
+        record.refunded = true;
+    });
+    assert_error(launch.delegate_vote(&a, &b), LaunchError::DelegationConflict);
+    assert_eq!(launch.record(&b).delegator_count, 0);
+}
+
+#[test]
+fn contributor_with_delegators_cannot_delegate() {
+    let mut launch = Launch::new();
+    let (a, b, c) = (launch.contributor(SOL), launch.contributor(SOL), launch.contributor(SOL));
+    launch.delegate_vote(&a, &b).unwrap();
+    // b now carries a's weight; handing it on would make a chain
+    assert_error(launch.delegate_vote(&b, &c), LaunchError::DelegationConflict);
+}
+
+#[test]
+fn delegating_to_yourself_is_rejected() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    assert_error(launch.delegate_vote(&a, &a), LaunchError::DelegationConflict);
+}
@@ -0,0 +1,268 @@
+//! execute_distribution's accounts and fee split.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use contracts::{LaunchError, PoolConfig, PoolStatus};
+
+#[test]
+fn token_fee_account_must_belong_to_the_platform_wallet() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.create_mint();
+    let (winner, mint) = (launch.winner, launch.mint);
+    launch.update(|pool| {
+        pool.winner = winner;
+        pool.token_mint = mint;
+    });
+
+    // Right mint, wrong owner: the fee would go to whoever holds this account
+    let stranger = launch.bank.wallet(SOL);
+    let stranger_account = launch.token_account(&stranger);
+    launch.bank.create_token_account(stranger_account, launch.mint, stranger, 0);
+    assert_error(launch.execute_distribution_to(stranger_account), LaunchError::WrongPlatformWallet);
+
+    // The platform's own account gets past the accounts to the status check
+    assert_error(launch.execute_distribution(), LaunchError::NotConfirming);
+}
+
+fn set_platform_wallet(launch: &mut Launch, new_platform_wallet: Pubkey) -> TxResult {
+    let accounts = contracts::accounts::SetPlatformWallet {
+        pool: launch.pool,
+        multisig: launch.multisig,
+        signer: launch.signers[0],
+        new_platform_wallet,
+    };
+    launch.bank.process(ix(contracts::instruction::SetPlatformWallet {}, accounts))
+}
+
+#[test]
+fn rotated_platform_wallet_takes_the_fee() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.create_mint();
+    let (winner, mint, old_wallet) = (launch.winner, launch.mint, launch.platform_wallet);
+    launch.update(|pool| {
+        pool.winner = winner;
+        pool.token_mint = mint;
+    });
+    let old_account = launch.token_account(&old_wallet);
+    launch.bank.create_token_account(old_account, mint, old_wallet, 0);
+
+    let new_wallet = launch.bank.wallet(SOL);
+    set_platform_wallet(&mut launch, new_wallet).unwrap();
+    assert_eq!(launch.state().platform_wallet, new_wallet);
+
+    // The old treasury's account no longer matches
+    launch.platform_wallet = new_wallet;
+    assert_error(launch.execute_distribution_to(old_account), LaunchError::WrongPlatformWallet);
+    assert_error(launch.execute_distribution(), LaunchError::NotConfirming);
+}
+
+#[test]
+fn platform_wallet_is_locked_once_distributed() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.distribute();
+    let new_wallet = launch.bank.wallet(SOL);
+    assert_error(set_platform_wallet(&mut launch, new_wallet), LaunchError::PlatformWalletLocked);
+}
+
+#[test]
+fn distinct_approvers_are_required() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.min_approving_contributors = 2);
+    let whale = launch.contributor(8 * SOL);
+    let a = launch.contributor(SOL);
+    let b = launch.contributor(SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+
+    // One whale carries the weight but not the head count
+    launch.confirm_vote(&whale, true, &[]).unwrap();
+    launch.confirm_vote(&a, false, &[]).unwrap();
+    assert!(!launch.state().is_approved());
+    assert_error(launch.execute_distribution(), LaunchError::NotEnoughApprovers);
+
+    launch.confirm_vote(&b, true, &[]).unwrap();
+    assert!(launch.state().is_approved());
+    assert_reaches_cpi(launch.execute_distribution());
+}
+
+#[test]
+fn second_distribution_fails_cleanly() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.distribute();
+    let before = launch.state();
+    assert_error(launch.execute_distribution(), LaunchError::AlreadyDistributed);
+
+    // The flag holds even if status were ever set back to Confirming
+    launch.update(|pool| pool.status = PoolStatus::Confirming);
+    assert_error(launch.execute_distribution(), LaunchError::AlreadyDistributed);
+    assert_eq!(launch.state().current_lamports, before.current_lamports);
+}
+
+#[test]
+fn finalize_waits_for_the_soft_cap() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.soft_cap_lamports = 5 * SOL);
+    launch.contributor(4 * SOL);
+    launch.create_mint();
+    assert_error(launch.propose(), LaunchError::SoftCapNotReached);
+
+    launch.contributor(SOL);
+    launch.propose().unwrap();
+    assert!(launch.state().status == PoolStatus::Confirming);
+}
+
+#[test]
+fn finalize_needs_the_minimum_contributor_count() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.min_contributors = 5);
+    launch.contributor(TARGET);
+    launch.create_mint();
+    assert_error(launch.propose(), LaunchError::NotEnoughContributors);
+
+    for _ in 0..4 {
+        launch.contributor(SOL);
+    }
+    launch.propose().unwrap();
+}
+
+#[test]
+fn floor_keeps_every_split_above_zero() {
+    let mut launch = Launch::new();
+    let config = PoolConfig { min_distribution_lamports: 99, ..Default::default() };
+    assert_error(launch.create_pool(config), LaunchError::InvalidMinDistribution);
+
+    launch.update(|pool| pool.min_distribution_lamports = 100);
+    let a = launch.contributor(99);
+    launch.create_mint();
+    launch.propose().unwrap();
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    assert_error(launch.execute_distribution(), LaunchError::PoolTooSmallToDistribute);
+
+    // At the floor even a SOL-mode platform fee of 1% rounds to a whole lamport
+    launch.update(|pool| pool.current_lamports = 100);
+    let mut pool = launch.state();
+    pool.platform_fee_in_sol = true;
+    let split = pool.preview_distribution(T0).unwrap();
+    assert!(split.platform_sol > 0 && split.winner_sol > 0);
+    assert_reaches_cpi(launch.execute_distribution());
+}
+
+#[test]
+fn distribution_is_stamped_with_the_clock() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+    assert_eq!(launch.state().distributed_at, 0);
+
+    // execute_distribution records the split once the mint CPI returns
+    launch.bank.set_time(T0 + 3_600);
+    let now = launch.bank.now();
+    let mut pool = launch.state();
+    let split = pool.preview_distribution(now).unwrap();
+    pool.record_distribution(split.total_sol, split.winner_sol, now);
+    assert!(pool.status == PoolStatus::Distributing);
+    assert_eq!(pool.distributed_at, T0 + 3_600);
+    assert_eq!(pool.distribution_snapshot_lamports, SOL);
+}
+
+#[test]
+fn preview_matches_what_distribution_pays() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(6 * SOL);
+    let b = launch.contributor(4 * SOL);
+
+    // No mint or token accounts yet; the preview only reads the pool
+    let preview = launch.state().preview_distribution(launch.bank.now()).unwrap();
+    let total_tokens = launch.state().total_tokens().unwrap();
+    assert_eq!((preview.total_sol, preview.winner_sol, preview.platform_sol), (10 * SOL, SOL / 2, 0));
+    assert_eq!(preview.platform_tokens, total_tokens / 100);
+    assert_eq!(preview.contributor_tokens, total_tokens / 100 * 94);
+    assert!(!preview.executable);
+
+    launch.create_mint();
+    launch.propose().unwrap();
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    let preview = launch.state().preview_distribution(launch.bank.now()).unwrap();
+    assert!(preview.executable);
+
+    // Claims pay out exactly the previewed contributor pool
+    launch.distribute();
+    let mut pool = launch.state();
+    assert_eq!(pool.distribution_snapshot_lamports, preview.total_sol);
+    let a_share = pool.claim_amount(&launch.record(&a)).unwrap();
+    pool.allocated_count = 1;
+    pool.total_allocated_tokens = a_share;
+    let b_share = pool.claim_amount(&launch.record(&b)).unwrap();
+    assert_eq!(a_share + b_share, preview.contributor_tokens);
+    assert_eq!(pool.retained_tokens().unwrap(), total_tokens - preview.contributor_tokens - preview.platform_tokens);
+
+    // SOL fee mode moves the platform's cut from tokens to SOL
+    pool.platform_fee_in_sol = true;
+    pool.current_lamports = 10 * SOL;
+    let preview = pool.preview_distribution(launch.bank.now()).unwrap();
+    assert_eq!((preview.platform_sol, preview.platform_tokens), (SOL / 10, 0));
+    assert_eq!(preview.contributor_tokens, total_tokens / 100 * 95);
+}
+
+#[test]
+fn distribution_mints_into_the_pool_ata_only() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+    launch.confirm_vote(&a, true, &[]).unwrap();
+
+    let side_account = Pubkey::new_unique();
+    launch.bank.create_token_account(side_account, launch.mint, launch.pool, 0);
+    let platform_token_account = launch.token_account(&launch.platform_wallet);
+    launch.bank.create_token_account(platform_token_account, launch.mint, launch.platform_wallet, 0);
+    let result = launch.bank.process(ix(
+        contracts::instruction::ExecuteDistribution {},
+        contracts::accounts::ExecuteDistribution {
+            pool: launch.pool,
+            program_config: program_config(),
+            winner: launch.winner,
+            token_mint: launch.mint,
+            pool_token_account: side_account,
+            platform_token_account: Some(platform_token_account),
+            platform_wallet: launch.platform_wallet,
+            winner_escrow: None,
+            caller: launch.signers[0],
+            token_program: anchor_spl::token::ID,
+            system_program: anchor_lang::system_program::ID,
+        },
+    ));
+    assert_error(result, LaunchError::InvalidAssociatedAccount);
+    assert_reaches_cpi(launch.execute_distribution());
+}
+
+#[test]
+fn two_thirds_threshold_rejects_a_bare_majority() {
+    let mut launch = Launch::new();
+    let config = PoolConfig { approval_threshold_bps: 4999, ..Default::default() };
+    assert_error(launch.create_pool(config), LaunchError::InvalidApprovalThreshold);
+
+    launch.update(|pool| pool.approval_threshold_bps = 6667);
+    let a = launch.contributor(6 * SOL);
+    let b = launch.contributor(3 * SOL);
+    let c = launch.contributor(SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+
+    // 6 to 3 is a majority, but short of two thirds
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    launch.confirm_vote(&b, false, &[]).unwrap();
+    assert!(!launch.state().supermajority_met());
+    assert_error(launch.execute_distribution(), LaunchError::SupermajorityNotMet);
+
+    launch.confirm_vote(&c, true, &[]).unwrap();
+    assert!(launch.state().is_approved());
+    assert_reaches_cpi(launch.execute_distribution());
+}
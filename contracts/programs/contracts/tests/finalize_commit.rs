@@ -0,0 +1,71 @@
+//! Two-phase finalize: commit_finalize, then reveal_finalize against the hash.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use contracts::{finalize_param_hash, LaunchError, PoolStatus};
+
+fn reveal(launch: &mut Launch, winner: Pubkey, merkle_root: [u8; 32]) -> TxResult {
+    let accounts = contracts::accounts::ProposeFinalize { winner, ..launch.propose_finalize() };
+    launch.bank.process(ix(contracts::instruction::RevealFinalize { merkle_root }, accounts))
+}
+
+fn commit(launch: &mut Launch, param_hash: [u8; 32]) -> TxResult {
+    let accounts = launch.multisig_action();
+    launch.bank.process(ix(contracts::instruction::CommitFinalize { param_hash }, accounts))
+}
+
+#[test]
+fn reveal_must_match_the_commitment() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.require_finalize_commit = true);
+    launch.contributor(SOL);
+    launch.create_mint();
+    let (winner, root) = (launch.winner, [4; 32]);
+
+    let accounts = launch.propose_finalize();
+    assert_error(
+        launch.bank.process(ix(contracts::instruction::ProposeFinalize { merkle_root: root }, accounts)),
+        LaunchError::FinalizeCommitRequired,
+    );
+    assert_error(reveal(&mut launch, winner, root), LaunchError::FinalizeNotCommitted);
+
+    commit(&mut launch, finalize_param_hash(&winner, &root)).unwrap();
+    assert_error(reveal(&mut launch, winner, [5; 32]), LaunchError::FinalizeRevealMismatch);
+    let other = launch.bank.wallet(SOL);
+    assert_error(reveal(&mut launch, other, root), LaunchError::FinalizeRevealMismatch);
+
+    reveal(&mut launch, winner, root).unwrap();
+    let state = launch.state();
+    assert!(state.status == PoolStatus::Confirming);
+    assert_eq!(state.winner, winner);
+    assert_eq!(state.merkle_root, root);
+    assert_eq!(state.finalize_commitment, [0; 32]);
+    assert_eq!(state.proposal_epoch, 1);
+}
+
+#[test]
+fn pending_commitment_blocks_propose() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.create_mint();
+    let propose = |launch: &mut Launch| {
+        let accounts = launch.propose_finalize();
+        launch.bank.process(ix(contracts::instruction::ProposeFinalize { merkle_root: [4; 32] }, accounts))
+    };
+
+    commit(&mut launch, [9; 32]).unwrap();
+    assert_error(propose(&mut launch), LaunchError::FinalizeCommitPending);
+    // A zero hash withdraws the commitment
+    commit(&mut launch, [0; 32]).unwrap();
+    propose(&mut launch).unwrap();
+    assert!(launch.state().status == PoolStatus::Confirming);
+}
+
+#[test]
+fn finalize_param_hash_vector() {
+    let winner = Pubkey::new_from_array([3; 32]);
+    let hash: String = finalize_param_hash(&winner, &[4; 32]).iter().map(|b| format!("{b:02x}")).collect();
+    assert_eq!(hash, "15812c763262dabc33411aff2c78af2cfcf55d57327737349ab4a7321a3dca59");
+}
@@ -0,0 +1,38 @@
+//! The pool's metadata URI: set at creation, editable until distribution.
+
+mod common;
+
+use common::*;
+use contracts::{LaunchError, PoolConfig};
+
+fn update_metadata_uri(launch: &mut Launch, metadata_uri: &str) -> TxResult {
+    let accounts = launch.multisig_action();
+    let metadata_uri = metadata_uri.to_string();
+    launch.bank.process(ix(contracts::instruction::UpdateMetadataUri { metadata_uri }, accounts))
+}
+
+#[test]
+fn metadata_uri_is_bounded_at_creation() {
+    let mut launch = Launch::new();
+    let config = PoolConfig { metadata_uri: "x".repeat(201), ..Default::default() };
+    assert_error(launch.create_pool_with_id("chant-2", config), LaunchError::MetadataTooLong);
+    let config = PoolConfig { metadata_uri: "x".repeat(200), ..Default::default() };
+    assert_reaches_cpi(launch.create_pool_with_id("chant-2", config));
+}
+
+#[test]
+fn metadata_uri_updates_until_distribution() {
+    let mut launch = Launch::new();
+    update_metadata_uri(&mut launch, "https://example.org/launch.json").unwrap();
+    assert_eq!(launch.state().metadata_uri, "https://example.org/launch.json");
+
+    // The pool account is sized for the longest URI from the start
+    let longest = "x".repeat(200);
+    update_metadata_uri(&mut launch, &longest).unwrap();
+    assert_eq!(launch.state().metadata_uri, longest);
+    assert_error(update_metadata_uri(&mut launch, &"x".repeat(201)), LaunchError::MetadataTooLong);
+
+    launch.contributor(SOL);
+    launch.distribute();
+    assert_error(update_metadata_uri(&mut launch, ""), LaunchError::MetadataLocked);
+}
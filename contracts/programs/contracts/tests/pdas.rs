@@ -0,0 +1,61 @@
+//! The crate's PDA helpers against the seeds Anchor checks.
+
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::solana_program::system_program;
+use common::*;
+use contracts::{ConfirmationVoteRecord, ContributionRecord, LaunchPool};
+
+#[test]
+fn helpers_derive_the_addresses_anchor_checks() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+
+    let (pool, bump) = LaunchPool::pda(&launch.multisig, Launch::POOL_ID);
+    assert_eq!((pool, bump), (launch.pool, launch.state().bump));
+    let (contribution, bump) = ContributionRecord::pda(&pool, &a);
+    assert_eq!((contribution, bump), (launch.contribution(&a), launch.record(&a).bump));
+
+    // confirm_vote's seeds constraint accepts the helper's address
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    let epoch = launch.state().proposal_epoch;
+    let (vote, bump) = ConfirmationVoteRecord::pda(&pool, &a, epoch);
+    let record = launch.bank.get::<ConfirmationVoteRecord>(&vote);
+    assert!(record.has_voted);
+    assert_eq!(record.bump, bump);
+}
+
+#[test]
+fn other_epochs_fail_the_seeds_check() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+
+    let epoch = launch.state().proposal_epoch;
+    let (stale, bump) = ConfirmationVoteRecord::pda(&launch.pool, &a, epoch + 1);
+    let record = ConfirmationVoteRecord {
+        pool: launch.pool,
+        contributor: a,
+        approve: false,
+        weight: 0,
+        has_voted: false,
+        epoch: epoch + 1,
+        bump,
+    };
+    launch.bank.put(stale, contracts::ID, &record, ConfirmationVoteRecord::SPACE, 0);
+    let result = launch.bank.process(ix(
+        contracts::instruction::ConfirmVote { approve: true },
+        contracts::accounts::ConfirmVote {
+            pool: launch.pool,
+            contribution: launch.contribution(&a),
+            confirmation_vote: stale,
+            contributor: a,
+            system_program: system_program::ID,
+        },
+    ));
+    assert_error(result, ErrorCode::ConstraintSeeds);
+}
@@ -0,0 +1,54 @@
+//! The program-wide kill switch: contributions stop across pools, refunds don't.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use contracts::{LaunchError, ProgramConfig};
+
+/// The ProgramConfig initialize_program_config leaves, administered by `admin`.
+fn seed_program_config(launch: &mut Launch, admin: Pubkey) {
+    let (key, bump) = pda(&[b"program_config"]);
+    let config = ProgramConfig { admin, paused: false, bump };
+    launch.bank.put(key, contracts::ID, &config, ProgramConfig::SPACE, 0);
+}
+
+fn set_program_pause(launch: &mut Launch, paused: bool) -> TxResult {
+    let accounts = contracts::accounts::SetProgramPause {
+        program_config: program_config(),
+        multisig: launch.multisig,
+        signer: launch.signers[0],
+    };
+    launch.bank.process(ix(contracts::instruction::SetProgramPause { paused }, accounts))
+}
+
+#[test]
+fn paused_program_still_refunds() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(2 * SOL);
+    let late = launch.bank.wallet(2 * SOL);
+    let admin = launch.multisig;
+    seed_program_config(&mut launch, admin);
+    assert_error(set_program_pause(&mut launch, false), LaunchError::NotPaused);
+    set_program_pause(&mut launch, true).unwrap();
+    assert_error(set_program_pause(&mut launch, true), LaunchError::AlreadyPaused);
+
+    assert_error(launch.contribute(&late, SOL, ""), LaunchError::ProgramPaused);
+    launch.create_mint();
+    assert_error(launch.propose(), LaunchError::ProgramPaused);
+
+    launch.bank.set_time(DEADLINE + 1);
+    launch.refund(&a, false).unwrap();
+    assert!(launch.record(&a).refunded);
+
+    set_program_pause(&mut launch, false).unwrap();
+    launch.bank.set_time(T0);
+    assert_reaches_cpi(launch.contribute(&late, SOL, ""));
+}
+
+#[test]
+fn only_the_admin_multisig_flips_the_switch() {
+    let mut launch = Launch::new();
+    seed_program_config(&mut launch, Pubkey::new_unique());
+    assert_error(set_program_pause(&mut launch, true), LaunchError::WrongAuthority);
+}
@@ -0,0 +1,69 @@
+//! Contribution receipt NFTs. mint_contribution_receipt inits its mint before
+//! the handler runs, so the accounts are covered up to that CPI and the
+//! receipt's name and metadata instruction are checked on their own.
+
+mod common;
+
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address;
+use common::*;
+use contracts::{create_metadata_instruction, LaunchError, RECEIPT_SYMBOL};
+
+fn receipt_mint(launch: &Launch, contributor: &Pubkey) -> Pubkey {
+    pda(&[b"receipt", launch.contribution(contributor).as_ref()]).0
+}
+
+fn mint_receipt(launch: &mut Launch, contributor: &Pubkey, receipt_mint: Pubkey) -> TxResult {
+    launch.bank.process(ix(
+        contracts::instruction::MintContributionReceipt {},
+        contracts::accounts::MintContributionReceipt {
+            pool: launch.pool,
+            contribution: launch.contribution(contributor),
+            contributor: *contributor,
+            receipt_mint,
+            receipt_token_account: get_associated_token_address(contributor, &receipt_mint),
+            metadata: None,
+            token_metadata_program: None,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: anchor_lang::system_program::ID,
+        },
+    ))
+}
+
+#[test]
+fn receipt_mint_is_bound_to_the_record() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    let b = launch.contributor(2 * SOL);
+
+    // One receipt mint per record: another record's mint address is refused
+    let b_mint = receipt_mint(&launch, &b);
+    assert_error(mint_receipt(&mut launch, &a, b_mint), ErrorCode::ConstraintSeeds);
+
+    let a_mint = receipt_mint(&launch, &a);
+    assert_reaches_cpi(mint_receipt(&mut launch, &a, a_mint));
+}
+
+#[test]
+fn receipt_names_the_contributed_amount() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    let mut record = launch.record(&a);
+    assert_eq!(record.receipt_name().unwrap(), "1000000000 lamports");
+
+    // The largest possible amount still fits a Metaplex name
+    let receipt_mint = receipt_mint(&launch, &a);
+    let metadata = Pubkey::new_unique();
+    record.amount_lamports = u64::MAX;
+    let name = record.receipt_name().unwrap();
+    let uri = launch.state().metadata_uri;
+    create_metadata_instruction(metadata, receipt_mint, launch.pool, a, &name, RECEIPT_SYMBOL, &uri).unwrap();
+
+    record.refunded = true;
+    assert_eq!(record.receipt_name().unwrap_err(), LaunchError::AlreadyRefunded.into());
+    record.refunded = false;
+    record.amount_lamports = 0;
+    assert_eq!(record.receipt_name().unwrap_err(), LaunchError::NoContribution.into());
+}
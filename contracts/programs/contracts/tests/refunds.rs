@@ -0,0 +1,299 @@
+//! Refunds: cancelled pools, the deadline and its penalty, the pause-timeout
+//! safety valve, operator batches, and the reason each refund reports.
+
+mod common;
+
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::{Discriminator, Event};
+use common::*;
+use contracts::{ContributionRecord, ContributionRefunded, LaunchError, PoolConfig, PoolStatus, RefundReason};
+
+/// A pool paused at T0 whose pause has just outlasted max_pause_secs.
+fn pause_past_timeout(launch: &mut Launch) {
+    launch.pause().unwrap();
+    let max_pause_secs = launch.state().max_pause_secs;
+    launch.bank.set_time(T0 + max_pause_secs + 1);
+}
+
+#[test]
+fn pause_refund_opens_only_after_max_pause_secs() {
+    let mut launch = Launch::new();
+    // Past the deadline a Funding pool refunds anyway; keep it out of reach
+    launch.update(|pool| pool.deadline = T0 + 30 * 86_400);
+    let a = launch.contributor(2 * SOL);
+    assert_error(launch.refund(&a, false), LaunchError::RefundNotAvailable);
+
+    launch.pause().unwrap();
+    let max_pause_secs = launch.state().max_pause_secs;
+    launch.bank.set_time(T0 + max_pause_secs);
+    assert_error(launch.refund(&a, false), LaunchError::PauseNotExpired);
+
+    launch.bank.set_time(T0 + max_pause_secs + 1);
+    launch.refund(&a, false).unwrap();
+    assert!(launch.record(&a).refunded);
+}
+
+#[test]
+fn pause_refund_while_confirming_leaves_the_tallies() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(4 * SOL);
+    let b = launch.contributor(3 * SOL);
+    let c = launch.contributor(2 * SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+    launch.confirm_vote(&a, true, &[]).unwrap();
+    launch.confirm_vote(&b, false, &[]).unwrap();
+    launch.confirm_vote(&c, true, &[]).unwrap();
+    assert!(launch.state().is_approved());
+
+    pause_past_timeout(&mut launch);
+    launch.refund(&a, false).unwrap();
+    let state = launch.state();
+    assert!(state.status == PoolStatus::Confirming);
+    assert_eq!((state.approve_lamports, state.approve_count), (2 * SOL, 1));
+    assert_eq!((state.reject_lamports, state.reject_count), (3 * SOL, 1));
+    assert_eq!(state.total_eligible_lamports, 5 * SOL);
+    assert!(!state.is_approved());
+
+    // The refunded SOL no longer carries the vote once the pause lifts
+    launch.unpause().unwrap();
+    assert_error(launch.execute_distribution(), LaunchError::NotApproved);
+}
+
+#[test]
+fn pause_refund_while_confirming_rechecks_the_funding_floor() {
+    let mut launch = Launch::new();
+    launch.update(|pool| {
+        pool.soft_cap_lamports = 5 * SOL;
+        pool.min_contributors = 2;
+    });
+    let a = launch.contributor(4 * SOL);
+    let b = launch.contributor(2 * SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+    launch.confirm_vote(&b, true, &[]).unwrap();
+
+    // a never voted, so the tally is untouched, but the pool fell under the soft cap
+    pause_past_timeout(&mut launch);
+    launch.refund(&a, false).unwrap();
+    assert_eq!(launch.state().approve_lamports, 2 * SOL);
+    launch.unpause().unwrap();
+    assert_error(launch.execute_distribution(), LaunchError::SoftCapNotReached);
+}
+
+#[test]
+fn pause_refund_reopens_a_funded_pool() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.deadline = T0 + 30 * 86_400);
+    let a = launch.contributor(4 * SOL);
+    launch.contributor(6 * SOL);
+    launch.update(|pool| pool.status = PoolStatus::Funded);
+
+    pause_past_timeout(&mut launch);
+    launch.refund(&a, false).unwrap();
+    let state = launch.state();
+    assert!(state.status == PoolStatus::Funding);
+    assert_eq!(state.current_lamports, 6 * SOL);
+}
+
+#[test]
+fn refund_of_cancelled_pool_is_full_and_once() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(2 * SOL);
+    let b = launch.contributor(3 * SOL);
+    assert_error(launch.refund(&a, false), LaunchError::RefundNotAvailable);
+
+    launch.update(|pool| pool.refund_penalty_bps = 1000);
+    launch.cancel().unwrap();
+    // A multisig cancel is never penalized, so no platform wallet is needed
+    launch.refund(&a, false).unwrap();
+    assert_eq!(launch.bank.lamports(&a), 3 * SOL);
+    assert!(launch.record(&a).refunded);
+    assert_error(launch.refund(&a, false), LaunchError::AlreadyRefunded);
+
+    let state = launch.state();
+    assert_eq!(state.current_lamports, 3 * SOL);
+    assert_eq!(state.contributor_count, 1);
+    assert_eq!(state.cancelled_lamports, 5 * SOL);
+
+    launch.refund(&b, false).unwrap();
+    assert_eq!(launch.bank.lamports(&launch.pool), Bank::rent(contracts::LaunchPool::space(Launch::POOL_ID)));
+}
+
+fn claim(launch: &mut Launch, contributor: &Pubkey) -> TxResult {
+    let contributor_token_account = launch.token_account(contributor);
+    if !launch.bank.exists(&contributor_token_account) {
+        launch.bank.create_token_account(contributor_token_account, launch.mint, *contributor, 0);
+    }
+    launch.bank.process(ix(
+        contracts::instruction::Claim {},
+        contracts::accounts::Claim {
+            pool: launch.pool,
+            program_config: program_config(),
+            contribution: launch.contribution(contributor),
+            contributor: *contributor,
+            pool_token_account: launch.pool_token_account(),
+            contributor_token_account,
+            token_program: anchor_spl::token::ID,
+        },
+    ))
+}
+
+#[test]
+fn claimed_record_cannot_refund() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(2 * SOL);
+    launch.contributor(SOL);
+    launch.cancel().unwrap();
+    let record = launch.contribution(&a);
+    launch.bank.update(&record, |record: &mut ContributionRecord| record.claimed = true);
+    assert_error(launch.refund(&a, false), LaunchError::AlreadyClaimed);
+    assert_eq!(launch.state().current_lamports, 3 * SOL);
+}
+
+#[test]
+fn refunded_record_cannot_claim() {
+    let mut launch = Launch::new();
+    let a = launch.contributor(2 * SOL);
+    launch.contributor(SOL);
+    launch.distribute();
+    let record = launch.contribution(&a);
+    launch.bank.update(&record, |record: &mut ContributionRecord| record.refunded = true);
+    assert_error(claim(&mut launch, &a), LaunchError::AlreadyRefunded);
+
+    launch.bank.update(&record, |record: &mut ContributionRecord| record.refunded = false);
+    assert_reaches_cpi(claim(&mut launch, &a));
+}
+
+#[test]
+fn refund_after_deadline_withholds_the_penalty() {
+    let mut launch = Launch::new();
+    let config = PoolConfig { refund_penalty_bps: 1001, ..Default::default() };
+    assert_error(launch.create_pool(config), LaunchError::InvalidRefundPenalty);
+    launch.update(|pool| pool.refund_penalty_bps = 500);
+    let a = launch.contributor(2 * SOL);
+
+    launch.bank.set_time(DEADLINE);
+    assert_error(launch.refund(&a, true), LaunchError::RefundNotAvailable);
+
+    launch.bank.set_time(DEADLINE + 1);
+    assert_error(launch.refund(&a, false), LaunchError::WrongPlatformWallet);
+
+    let platform_before = launch.bank.lamports(&launch.platform_wallet);
+    launch.refund(&a, true).unwrap();
+    assert_eq!(launch.bank.lamports(&a), SOL + 2 * SOL - SOL / 10);
+    assert_eq!(launch.bank.lamports(&launch.platform_wallet), platform_before + SOL / 10);
+    assert_eq!(launch.state().current_lamports, 0);
+}
+
+fn refund_batch(launch: &mut Launch, pairs: &[(Pubkey, Pubkey)]) -> TxResult {
+    let mut ix = ix(
+        contracts::instruction::RefundBatch {},
+        contracts::accounts::RefundBatch {
+            pool: launch.pool,
+            multisig: launch.multisig,
+            signer: launch.signers[0],
+            platform_wallet: Some(launch.platform_wallet),
+        },
+    );
+    for (record, contributor) in pairs {
+        ix.accounts.push(AccountMeta::new(*record, false));
+        ix.accounts.push(AccountMeta::new(*contributor, false));
+    }
+    launch.bank.process(ix)
+}
+
+#[test]
+fn refund_batch_refunds_each_record_once() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.refund_penalty_bps = 1000);
+    let a = launch.contributor(SOL);
+    let b = launch.contributor(2 * SOL);
+    let c = launch.contributor(4 * SOL);
+    let pair = |launch: &Launch, contributor: Pubkey| (launch.contribution(&contributor), contributor);
+
+    let pairs = [pair(&launch, a)];
+    assert_error(refund_batch(&mut launch, &pairs), LaunchError::RefundNotAvailable);
+
+    // Cancelled by an expired confirmation: every refund pays the penalty
+    launch.update(|pool| {
+        pool.status = PoolStatus::Cancelled;
+        pool.cancelled_by_expiry = true;
+        pool.cancelled_lamports = pool.current_lamports;
+    });
+    launch.refund(&a, true).unwrap();
+    let pairs = [pair(&launch, b)];
+    refund_batch(&mut launch, &pairs).unwrap();
+    assert_eq!(launch.bank.lamports(&b), SOL + 2 * SOL - 2 * SOL / 10);
+
+    // Pairs only, and each record with its own wallet
+    let (c_record, _) = pair(&launch, c);
+    let mut odd = ix(
+        contracts::instruction::RefundBatch {},
+        contracts::accounts::RefundBatch {
+            pool: launch.pool,
+            multisig: launch.multisig,
+            signer: launch.signers[0],
+            platform_wallet: Some(launch.platform_wallet),
+        },
+    );
+    odd.accounts.push(AccountMeta::new(c_record, false));
+    assert_error(launch.bank.process(odd), LaunchError::InvalidBatch);
+    assert_error(refund_batch(&mut launch, &[(c_record, b)]), LaunchError::InvalidBatch);
+
+    let platform_before = launch.bank.lamports(&launch.platform_wallet);
+    // a and b are already settled, c is listed twice: only one refund goes out
+    let pairs = [pair(&launch, a), pair(&launch, b), pair(&launch, c), pair(&launch, c)];
+    refund_batch(&mut launch, &pairs).unwrap();
+    assert_eq!(launch.bank.lamports(&c), SOL + 4 * SOL - 4 * SOL / 10);
+    assert_eq!(launch.bank.lamports(&launch.platform_wallet), platform_before + 4 * SOL / 10);
+    assert!(launch.record(&c).refunded);
+
+    let state = launch.state();
+    assert_eq!(state.current_lamports, 0);
+    assert_eq!(state.contributor_count, 0);
+}
+
+#[test]
+fn refund_reason_names_the_branch_that_opened_it() {
+    let reason = |launch: &Launch| launch.state().refund_reason(launch.bank.now());
+
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    assert_eq!(reason(&launch).err(), Some(LaunchError::RefundNotAvailable.into()));
+    launch.cancel().unwrap();
+    assert!(reason(&launch).unwrap() == RefundReason::Cancelled);
+    launch.refund(&a, false).unwrap();
+    launch.update(|pool| pool.cancelled_by_expiry = true);
+    assert!(reason(&launch).unwrap() == RefundReason::ConfirmationExpired);
+
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.bank.set_time(DEADLINE + 1);
+    assert!(reason(&launch).unwrap() == RefundReason::DeadlinePassed);
+    launch.refund(&a, true).unwrap();
+
+    let mut launch = Launch::new();
+    let a = launch.contributor(SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+    pause_past_timeout(&mut launch);
+    assert!(reason(&launch).unwrap() == RefundReason::PauseTimeout);
+    launch.refund(&a, false).unwrap();
+
+    // Only expiry and deadline refunds carry the penalty
+    assert!(RefundReason::ConfirmationExpired.is_penalized() && RefundReason::DeadlinePassed.is_penalized());
+    assert!(!RefundReason::Cancelled.is_penalized() && !RefundReason::PauseTimeout.is_penalized());
+
+    // refund can't run to its emit! here; the reason is the event's last byte
+    let event = ContributionRefunded {
+        pool: launch.pool,
+        contributor: a,
+        amount_lamports: SOL,
+        penalty_lamports: 0,
+        reason: RefundReason::PauseTimeout,
+    };
+    let data = event.data();
+    assert_eq!(data[..8], *ContributionRefunded::DISCRIMINATOR);
+    assert_eq!(data[8..].last(), Some(&(RefundReason::PauseTimeout as u8)));
+}
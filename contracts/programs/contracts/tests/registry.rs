@@ -0,0 +1,39 @@
+//! Multisigs and their pools: unique pool ids, the registry counter, and the
+//! signer-set view. create_pool creates the pool over a CPI, so the counter is
+//! covered through Multisig::record_pool, which it calls afterwards.
+
+mod common;
+
+use common::*;
+use contracts::{LaunchError, Multisig, PoolConfig};
+
+#[test]
+fn multisig_counts_its_pools() {
+    let launch = Launch::new();
+    let mut multisig: Multisig = launch.bank.get(&launch.multisig);
+    assert_eq!((multisig.pool_count, multisig.last_pool_id.as_str()), (1, Launch::POOL_ID));
+
+    multisig.record_pool("chant-2").unwrap();
+    multisig.record_pool("chant-3").unwrap();
+    assert_eq!((multisig.pool_count, multisig.last_pool_id.as_str()), (3, "chant-3"));
+
+    multisig.pool_count = u64::MAX;
+    assert_eq!(multisig.record_pool("chant-4").unwrap_err(), LaunchError::MathOverflow.into());
+}
+
+#[test]
+fn pool_id_is_used_once_per_multisig() {
+    let mut launch = Launch::new();
+    let result = launch.create_pool_with_id(Launch::POOL_ID, PoolConfig::default());
+    assert_error(result, LaunchError::PoolIdAlreadyUsed);
+    assert_reaches_cpi(launch.create_pool_with_id("chant-2", PoolConfig::default()));
+}
+
+#[test]
+fn multisig_info_reports_the_signer_set() {
+    let launch = Launch::new();
+    let multisig: Multisig = launch.bank.get(&launch.multisig);
+    let info = multisig.info();
+    assert_eq!(info.signers, launch.signers);
+    assert_eq!((info.threshold, info.nonce), (2, 0));
+}
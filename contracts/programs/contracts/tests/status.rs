@@ -0,0 +1,43 @@
+//! Funding vs Funded: a full pool stops taking contributions but can still be finalized.
+
+mod common;
+
+use common::*;
+use contracts::{LaunchError, PoolStatus};
+
+#[test]
+fn funded_pool_takes_no_more_contributions() {
+    let mut launch = Launch::new();
+    launch.contributor(TARGET);
+    launch.update(|pool| pool.status = PoolStatus::Funded);
+    let late = launch.bank.wallet(2 * SOL);
+    assert_error(launch.contribute(&late, SOL, ""), LaunchError::PoolNotFunding);
+
+    launch.create_mint();
+    launch.propose().unwrap();
+    assert!(launch.state().status == PoolStatus::Confirming);
+}
+
+#[test]
+fn funding_pool_finalizes_once_over_the_soft_cap() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.soft_cap_lamports = 2 * SOL);
+    launch.contributor(3 * SOL);
+    assert!(launch.state().status == PoolStatus::Funding);
+    launch.create_mint();
+    launch.propose().unwrap();
+}
+
+#[test]
+fn rejected_proposal_reopens_as_funded_only_when_full() {
+    let mut pool = Launch::new().state();
+    pool.status = PoolStatus::Confirming;
+    pool.current_lamports = TARGET;
+    pool.reset_for_refinalize();
+    assert!(pool.status == PoolStatus::Funded);
+
+    pool.status = PoolStatus::Confirming;
+    pool.current_lamports = TARGET - 1;
+    pool.reset_for_refinalize();
+    assert!(pool.status == PoolStatus::Funding);
+}
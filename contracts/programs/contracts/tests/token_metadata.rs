@@ -0,0 +1,75 @@
+//! Metaplex metadata for the launch mint. The Token Metadata program isn't
+//! loaded here, so create_token_metadata runs up to its CPI and the
+//! instruction it sends is checked on its own.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::system_program;
+use common::*;
+use contracts::{create_metadata_instruction, LaunchError, TOKEN_METADATA_PROGRAM_ID};
+
+fn metadata_address(mint: &Pubkey) -> Pubkey {
+    let seeds: &[&[u8]] = &[b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref()];
+    Pubkey::find_program_address(seeds, &TOKEN_METADATA_PROGRAM_ID).0
+}
+
+fn create_token_metadata(launch: &mut Launch, name: &str, symbol: &str, uri: &str) -> TxResult {
+    launch.bank.process(ix(
+        contracts::instruction::CreateTokenMetadata {
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            uri: uri.to_string(),
+        },
+        contracts::accounts::CreateTokenMetadata {
+            pool: launch.pool,
+            multisig: launch.multisig,
+            signer: launch.signers[0],
+            token_mint: launch.mint,
+            metadata: metadata_address(&launch.mint),
+            token_metadata_program: TOKEN_METADATA_PROGRAM_ID,
+            system_program: system_program::ID,
+        },
+    ))
+}
+
+#[test]
+fn metadata_is_attached_once_distributed() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.create_mint();
+    let mint = launch.mint;
+    launch.update(|pool| pool.token_mint = mint);
+    assert_error(create_token_metadata(&mut launch, "Chant", "CHNT", ""), LaunchError::PoolNotDistributing);
+
+    launch.distribute();
+    assert_error(create_token_metadata(&mut launch, &"n".repeat(33), "CHNT", ""), LaunchError::TokenMetadataTooLong);
+    assert_reaches_cpi(create_token_metadata(&mut launch, "Chant", "CHNT", "https://example.org/t.json"));
+}
+
+#[test]
+fn metadata_instruction_names_the_pool_as_authority() {
+    let (mint, pool, payer) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let metadata = metadata_address(&mint);
+    let ix = create_metadata_instruction(metadata, mint, pool, payer, "Chant", "CHNT", "uri").unwrap();
+    assert_eq!(ix.program_id, TOKEN_METADATA_PROGRAM_ID);
+
+    let keys: Vec<(Pubkey, bool, bool)> = ix.accounts.iter().map(|m| (m.pubkey, m.is_signer, m.is_writable)).collect();
+    assert_eq!(
+        keys,
+        [
+            (metadata, false, true),
+            (mint, false, false),
+            (pool, true, false),
+            (payer, true, true),
+            (pool, true, false),
+            (system_program::ID, false, false),
+        ]
+    );
+
+    // CreateMetadataAccountV3 (33), then DataV2 with no creators, collection or uses
+    assert_eq!(ix.data[0], 33);
+    let (name, symbol, uri, fee) = <(String, String, String, u16)>::deserialize(&mut &ix.data[1..]).unwrap();
+    assert_eq!((name.as_str(), symbol.as_str(), uri.as_str(), fee), ("Chant", "CHNT", "uri", 0));
+    assert_eq!(&ix.data[ix.data.len() - 5..], &[0, 0, 0, 1, 0]);
+}
@@ -0,0 +1,208 @@
+//! The winner's side of a launch: who may win, the bond, the escrowed SOL, and
+//! declining the win.
+
+mod common;
+
+use anchor_lang::prelude::Pubkey;
+use common::*;
+use contracts::{LaunchError, PoolConfig, PoolStatus, WinnerBond};
+
+fn slash(launch: &mut Launch) -> TxResult {
+    let accounts = contracts::accounts::SlashWinnerBond {
+        pool: launch.pool,
+        multisig: launch.multisig,
+        signer: launch.signers[1],
+        bond: launch.bond(),
+        platform_wallet: launch.platform_wallet,
+    };
+    launch.bank.process(ix(contracts::instruction::SlashWinnerBond {}, accounts))
+}
+
+fn release_tranche(launch: &mut Launch, tranche_bps: u16) -> TxResult {
+    launch.bank.process(ix(
+        contracts::instruction::ReleaseWinnerTranche { tranche_bps },
+        contracts::accounts::ReleaseWinnerTranche {
+            pool: launch.pool,
+            multisig: launch.multisig,
+            signer: launch.signers[2],
+            winner_escrow: launch.winner_escrow(),
+            winner: launch.winner,
+        },
+    ))
+}
+
+#[test]
+fn bond_is_slashed_to_the_platform_after_the_deadline() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.deposit_bond(SOL);
+    let bond = launch.bond();
+    let bond_lamports = launch.bank.lamports(&bond);
+    let deadline = launch.bank.get::<WinnerBond>(&bond).deadline;
+
+    // Past its deadline, but the pool hasn't distributed to this winner yet
+    launch.bank.set_time(deadline + 1);
+    assert_error(slash(&mut launch), LaunchError::PoolNotDistributing);
+
+    launch.distribute();
+    launch.bank.set_time(deadline);
+    assert_error(slash(&mut launch), LaunchError::MilestoneDeadlineActive);
+
+    launch.bank.set_time(deadline + 1);
+    let platform_before = launch.bank.lamports(&launch.platform_wallet);
+    slash(&mut launch).unwrap();
+    assert_eq!(launch.bank.lamports(&launch.platform_wallet), platform_before + bond_lamports);
+    assert!(!launch.bank.exists(&bond));
+    assert!(!launch.state().winner_bond_deposited);
+}
+
+#[test]
+fn bond_is_released_to_the_winner_after_the_milestone() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.deposit_bond(SOL);
+    launch.distribute();
+    let bond = launch.bond();
+    let bond_lamports = launch.bank.lamports(&bond);
+    let release = |launch: &mut Launch| {
+        launch.bank.process(ix(
+            contracts::instruction::ReleaseWinnerBond {},
+            contracts::accounts::ReleaseWinnerBond { pool: launch.pool, bond, winner: launch.winner },
+        ))
+    };
+    assert_error(release(&mut launch), LaunchError::BondNotReleasable);
+
+    launch.record_milestone(true).unwrap();
+    assert!(launch.bank.get::<WinnerBond>(&bond).milestone_met);
+    // Too late to slash a bond whose milestone was met
+    launch.bank.set_time(T0 + 365 * 86_400);
+    assert_error(slash(&mut launch), LaunchError::MilestoneMet);
+
+    let winner_before = launch.bank.lamports(&launch.winner);
+    release(&mut launch).unwrap();
+    assert_eq!(launch.bank.lamports(&launch.winner), winner_before + bond_lamports);
+    assert!(!launch.bank.exists(&bond));
+}
+
+#[test]
+fn milestone_period_has_a_floor() {
+    let mut launch = Launch::new();
+    let config = PoolConfig { milestone_period_secs: 604_799, ..Default::default() };
+    assert_error(launch.create_pool(config), LaunchError::MilestonePeriodTooShort);
+}
+
+#[test]
+fn each_tranche_needs_its_own_milestone() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.escrow_winner_sol = true);
+    launch.contributor(10 * SOL);
+    launch.distribute();
+    let escrowed = launch.state().winner_escrow_lamports;
+    assert_eq!(escrowed, SOL / 2);
+    let winner_before = launch.bank.lamports(&launch.winner);
+
+    assert_error(release_tranche(&mut launch, 5000), LaunchError::MilestoneNotRecorded);
+    launch.record_milestone(false).unwrap();
+    assert_error(release_tranche(&mut launch, 0), LaunchError::InvalidAmount);
+    release_tranche(&mut launch, 5000).unwrap();
+    assert_eq!(launch.bank.lamports(&launch.winner), winner_before + escrowed / 2);
+    assert_error(release_tranche(&mut launch, 5000), LaunchError::MilestoneNotRecorded);
+
+    launch.record_milestone(false).unwrap();
+    assert_error(release_tranche(&mut launch, 6000), LaunchError::WinnerOverRelease);
+    release_tranche(&mut launch, 5000).unwrap();
+    assert_eq!(launch.bank.lamports(&launch.winner), winner_before + escrowed);
+    let escrow = launch.winner_escrow();
+    assert_eq!(launch.bank.lamports(&escrow), Bank::rent(contracts::WinnerEscrow::SPACE));
+
+    let state = launch.state();
+    assert_eq!(state.winner_released_lamports, escrowed);
+    assert_eq!((state.milestone_count, state.tranche_count), (2, 2));
+    launch.record_milestone(false).unwrap();
+    assert_error(release_tranche(&mut launch, 1), LaunchError::WinnerOverRelease);
+}
+
+#[test]
+fn milestone_without_bond_needs_an_escrow_pool() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.distribute();
+    assert_error(launch.record_milestone(false), LaunchError::WinnerBondRequired);
+}
+
+#[test]
+fn tranche_arithmetic_cannot_wrap() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.escrow_winner_sol = true);
+    launch.contributor(10 * SOL);
+    launch.distribute();
+    launch.record_milestone(false).unwrap();
+    launch.update(|pool| pool.winner_released_lamports = u64::MAX);
+    assert_error(release_tranche(&mut launch, 5000), LaunchError::MathOverflow);
+}
+
+fn decline_win(launch: &mut Launch, winner: Pubkey) -> TxResult {
+    let accounts = contracts::accounts::DeclineWin { pool: launch.pool, winner };
+    launch.bank.process(ix(contracts::instruction::DeclineWin {}, accounts))
+}
+
+#[test]
+fn declined_winner_is_replaced() {
+    let mut launch = Launch::new();
+    launch.update(|pool| pool.max_refinalizations = 1);
+    launch.contributor(SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+
+    let stranger = launch.bank.wallet(SOL);
+    assert_error(decline_win(&mut launch, stranger), LaunchError::WrongWinner);
+    let declined = launch.winner;
+    decline_win(&mut launch, declined).unwrap();
+    let state = launch.state();
+    assert!(state.status == PoolStatus::Funding);
+    assert_eq!(state.declined_winner, declined);
+    // With no winner named, distribution has nowhere to send the winner's share
+    assert_eq!(state.winner, Pubkey::default());
+    assert_error(launch.execute_distribution(), LaunchError::WrongWinner);
+
+    // The same wallet can't be named again; a replacement can
+    assert_error(launch.propose(), LaunchError::WinnerAlreadyDeclined);
+    launch.winner = launch.bank.wallet(SOL);
+    launch.propose().unwrap();
+    assert_eq!(launch.state().winner, launch.winner);
+}
+
+#[test]
+fn decline_without_reproposals_left_cancels() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.create_mint();
+    launch.propose().unwrap();
+    let winner = launch.winner;
+    decline_win(&mut launch, winner).unwrap();
+    let state = launch.state();
+    assert!(state.status == PoolStatus::Cancelled);
+    assert_eq!(state.cancelled_lamports, SOL);
+}
+
+#[test]
+fn signer_winner_is_refused_when_forbidden() {
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.create_mint();
+
+    // Off by default: a signer may win
+    launch.winner = launch.signers[0];
+    launch.propose().unwrap();
+
+    let mut launch = Launch::new();
+    launch.contributor(SOL);
+    launch.create_mint();
+    launch.update(|pool| pool.forbid_signer_winner = true);
+    let winner = launch.winner;
+    launch.winner = launch.signers[2];
+    assert_error(launch.propose(), LaunchError::WinnerIsSigner);
+    launch.winner = winner;
+    launch.propose().unwrap();
+    assert_eq!(launch.state().winner, winner);
+}
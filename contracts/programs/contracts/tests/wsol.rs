@@ -0,0 +1,93 @@
+//! Contributing from a wrapped SOL account, run in-process (see common).
+//! The unwrap itself is a token CPI, so these cover the checks in front of it.
+
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::spl_token::native_mint;
+use common::*;
+use contracts::LaunchError;
+
+fn contribute_wsol(
+    launch: &mut Launch,
+    contributor: Pubkey,
+    wsol_account: Pubkey,
+    amount_lamports: u64,
+    with_token_program: bool,
+) -> TxResult {
+    launch.bank.process(ix(
+        contracts::instruction::Contribute {
+            amount_lamports,
+            memo: String::new(),
+            referrer: None,
+            allowlist_proof: vec![],
+        },
+        contracts::accounts::Contribute {
+            pool: launch.pool,
+            program_config: program_config(),
+            contribution: launch.contribution(&contributor),
+            contributor,
+            referral_stats: None,
+            wsol_account: Some(wsol_account),
+            token_program: with_token_program.then_some(anchor_spl::token::ID),
+            system_program: anchor_lang::system_program::ID,
+        },
+    ))
+}
+
+#[test]
+fn wsol_balance_must_equal_the_contribution() {
+    let mut launch = Launch::new();
+    // An existing record, so the instruction needs no account creation
+    let contributor = launch.contributor(SOL);
+    let wsol = Pubkey::new_unique();
+    launch.bank.create_token_account(wsol, native_mint::ID, contributor, 2 * SOL);
+
+    assert_error(
+        contribute_wsol(&mut launch, contributor, wsol, SOL, true),
+        LaunchError::WsolAmountMismatch,
+    );
+    assert_error(
+        contribute_wsol(&mut launch, contributor, wsol, 3 * SOL, true),
+        LaunchError::WsolAmountMismatch,
+    );
+    // Nothing moved
+    assert_eq!(launch.state().current_lamports, SOL);
+    assert_eq!(launch.bank.token_balance(&wsol), 2 * SOL);
+}
+
+#[test]
+fn wsol_account_must_be_the_contributors_native_account() {
+    let mut launch = Launch::new();
+    let contributor = launch.contributor(SOL);
+
+    let other_mint = Pubkey::new_unique();
+    let not_wsol = Pubkey::new_unique();
+    launch.bank.create_token_account(not_wsol, other_mint, contributor, SOL);
+    assert_error(
+        contribute_wsol(&mut launch, contributor, not_wsol, SOL, true),
+        LaunchError::InvalidTokenAccount,
+    );
+
+    let someone_else = launch.bank.wallet(SOL);
+    let their_wsol = Pubkey::new_unique();
+    launch.bank.create_token_account(their_wsol, native_mint::ID, someone_else, SOL);
+    assert_error(
+        contribute_wsol(&mut launch, contributor, their_wsol, SOL, true),
+        LaunchError::InvalidTokenAccount,
+    );
+}
+
+#[test]
+fn wsol_needs_the_token_program() {
+    let mut launch = Launch::new();
+    let contributor = launch.contributor(SOL);
+    let wsol = Pubkey::new_unique();
+    launch.bank.create_token_account(wsol, native_mint::ID, contributor, SOL);
+
+    assert_error(
+        contribute_wsol(&mut launch, contributor, wsol, SOL, false),
+        LaunchError::InvalidTokenAccount,
+    );
+    assert_eq!(launch.record(&contributor).amount_lamports, SOL);
+}